@@ -0,0 +1,79 @@
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use std::hint::black_box;
+
+const SMALL_PROMPT: &str = r#"<speak>Your balance is <say-as interpret-as="currency">$12.50</say-as>.<break time="300ms"/>Anything else?</speak>"#;
+
+const NESTING_DEPTH: usize = 200;
+
+const CHAPTER_SIZE: usize = 10 * 1024 * 1024;
+
+// `depth` levels of alternating prosody and emphasis elements around a single sentence
+fn deeply_nested(depth: usize) -> String {
+    let mut ssml = String::from("<speak>");
+    for level in 0..depth {
+        if level % 2 == 0 {
+            ssml.push_str(r#"<prosody rate="slow" pitch="+5%">"#);
+        } else {
+            ssml.push_str(r#"<emphasis level="moderate">"#);
+        }
+    }
+    ssml.push_str("<s>Deep inside.</s>");
+    for level in (0..depth).rev() {
+        if level % 2 == 0 {
+            ssml.push_str("</prosody>");
+        } else {
+            ssml.push_str("</emphasis>");
+        }
+    }
+    ssml.push_str("</speak>");
+    ssml
+}
+
+// An audiobook chapter of roughly `size` bytes
+fn audiobook_chapter(size: usize) -> String {
+    let paragraph = r#"
+    <p>
+        <s>It was a bright cold day in April, and the clocks were striking thirteen.</s>
+        <s>Winston Smith, his chin nuzzled into his breast, slipped quickly through the glass doors of <sub alias="Victory Mansions">V. Mansions</sub>.</s>
+        <s><prosody rate="95%" pitch="-2st">The hallway smelt of boiled cabbage &amp; old rag mats.</prosody></s>
+        <break time="750ms"/>
+        <s>On each landing, <emphasis level="strong">opposite the lift-shaft</emphasis>, the poster gazed from the wall.</s>
+        <mark name="paragraph-end"/>
+    </p>"#;
+
+    let mut ssml = String::with_capacity(size + paragraph.len() + 128);
+    ssml.push_str(r#"<?xml version="1.0"?><speak version="1.1" xml:lang="en-GB"><voice name="en-GB-Narrator">"#);
+    while ssml.len() < size {
+        ssml.push_str(paragraph);
+    }
+    ssml.push_str("</voice></speak>");
+    ssml
+}
+
+fn bench_parse(c: &mut Criterion) {
+    let inputs = [
+        ("small_prompt", SMALL_PROMPT.to_string(), 100),
+        ("deeply_nested", deeply_nested(NESTING_DEPTH), 100),
+        ("audiobook_chapter", audiobook_chapter(CHAPTER_SIZE), 10),
+    ];
+
+    let mut group = c.benchmark_group("parse");
+    for (name, input, sample_size) in &inputs {
+        let _ = group
+            .throughput(Throughput::Bytes(input.len() as u64))
+            .sample_size(*sample_size);
+
+        let _ = group.bench_with_input(BenchmarkId::new("from_str", name), input, |b, input| {
+            b.iter(|| serde_ssml::from_str(black_box(input)).unwrap())
+        });
+        let _ = group.bench_with_input(
+            BenchmarkId::new("borrowed::from_str", name),
+            input,
+            |b, input| b.iter(|| serde_ssml::borrowed::from_str(black_box(input)).unwrap()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_parse);
+criterion_main!(benches);
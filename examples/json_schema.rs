@@ -0,0 +1,7 @@
+//! Prints the JSON Schema of the compact representation.
+//!
+//! Regenerate `schema/ssml-compact.schema.json` with `make schema`.
+
+fn main() {
+    print!("{}", serde_ssml::compact::json_schema());
+}
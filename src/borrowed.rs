@@ -0,0 +1,353 @@
+//! Zero-copy variant of the SSML document model.
+//!
+//! [`SSML`] and [`SsmlElement`] mirror their owned counterparts in the crate root, but every
+//! text run and attribute value is a [`Cow<'a, str>`] that points into the parsed input unless
+//! entity decoding (e.g. `&amp;`) forced an allocation.
+//!
+//! # Example
+//!
+//! ```rust
+//! use std::borrow::Cow;
+//! use serde_ssml::borrowed::{self, SsmlElement};
+//!
+//! let input = r#"<speak><voice name="en-US-Jenny">Tom &amp; Jerry</voice></speak>"#;
+//! let ssml = borrowed::from_str(input).unwrap();
+//!
+//! if let SsmlElement::Speak { children, .. } = &ssml.elements[0] {
+//!     if let SsmlElement::Voice { name, children } = &children[0] {
+//!         // Attribute values without entities borrow from the input...
+//!         assert!(matches!(name, Cow::Borrowed("en-US-Jenny")));
+//!         // ...while decoded text is owned.
+//!         assert!(matches!(&children[0], SsmlElement::Text(Cow::Owned(text)) if text == "Tom & Jerry"));
+//!     }
+//! }
+//!
+//! // Convert to the owned representation when the input goes out of scope.
+//! let owned: serde_ssml::SSML = ssml.into_owned();
+//! assert_eq!(owned, serde_ssml::from_str(input).unwrap());
+//! ```
+
+use std::{borrow::Cow, time::Duration};
+
+use crate::{BreakStrength, ElementKind, ParseError, ParseOptions, Parser};
+
+/// Borrowed counterpart of [`crate::SSML`].
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SSML<'a> {
+    /// Top-level SSML elements
+    pub elements: Vec<SsmlElement<'a>>,
+}
+
+impl SSML<'_> {
+    /// Converts the document into the owned [`crate::SSML`] representation.
+    pub fn into_owned(self) -> crate::SSML {
+        crate::SSML {
+            elements: into_owned_children(self.elements),
+        }
+    }
+}
+
+impl From<SSML<'_>> for crate::SSML {
+    fn from(ssml: SSML<'_>) -> Self {
+        ssml.into_owned()
+    }
+}
+
+/// Borrowed counterpart of [`crate::SsmlElement`].
+///
+/// See the owned enum for the meaning of each variant and attribute.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SsmlElement<'a> {
+    /// Borrowed counterpart of [`crate::SsmlElement::Voice`].
+    Voice {
+        /// The name or identifier of the voice to be used.
+        name: Cow<'a, str>,
+        /// The child elements to be spoken using the specified voice.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Speak`].
+    Speak {
+        /// The version of the SSML specification being used.
+        version: Option<Cow<'a, str>>,
+        /// The XML namespace URI for the SSML standard.
+        xmlns: Option<Cow<'a, str>>,
+        /// The language of the spoken content.
+        lang: Option<Cow<'a, str>>,
+        /// The child elements contained within the speak block.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Paragraph`].
+    Paragraph {
+        /// The child elements within the paragraph.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Sentence`].
+    Sentence {
+        /// The child elements within the sentence.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Phoneme`].
+    Phoneme {
+        /// The phonetic alphabet used for pronunciation.
+        alphabet: Cow<'a, str>,
+        /// The phonetic representation of the text.
+        ph: Cow<'a, str>,
+        /// The text or elements to be pronounced phonetically.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::SayAs`].
+    SayAs {
+        /// The type of content to be interpreted.
+        interpret_as: Cow<'a, str>,
+        /// Optional format specification for the content.
+        format: Cow<'a, str>,
+        /// Additional interpretation details.
+        detail: Cow<'a, str>,
+        /// The content to be interpreted.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Sub`].
+    Sub {
+        /// The replacement text or pronunciation.
+        alias: Cow<'a, str>,
+        /// The original text to be substituted.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Prosody`].
+    Prosody {
+        /// Speech rate modification.
+        rate: Cow<'a, str>,
+        /// Pitch modification.
+        pitch: Cow<'a, str>,
+        /// Advanced pitch contour specification.
+        contour: Cow<'a, str>,
+        /// Pitch variation range.
+        range: Cow<'a, str>,
+        /// Volume level modification.
+        volume: Cow<'a, str>,
+        /// The elements affected by prosody settings.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Emphasis`].
+    Emphasis {
+        /// Emphasis intensity level.
+        level: Cow<'a, str>,
+        /// The text or elements to be emphasized.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Break`].
+    Break {
+        /// Duration of the break.
+        time: Option<Duration>,
+        /// Relative strength of the break.
+        strength: Option<BreakStrength>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Mark`].
+    Mark {
+        /// Unique identifier for the mark.
+        name: Cow<'a, str>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Audio`].
+    Audio {
+        /// Source URI of the audio file.
+        src: Cow<'a, str>,
+        /// Fallback text or description.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Desc`].
+    Desc {
+        /// Descriptive text or elements.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::LexiconUri`].
+    LexiconUri {
+        /// Location of the lexicon resource.
+        uri: Cow<'a, str>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Lang`].
+    Lang {
+        /// Language code for the enclosed content.
+        xml_lang: Cow<'a, str>,
+        /// Text or elements in the specified language.
+        children: Vec<SsmlElement<'a>>,
+    },
+
+    /// Borrowed counterpart of [`crate::SsmlElement::Text`].
+    Text(Cow<'a, str>),
+}
+
+impl SsmlElement<'_> {
+    /// Converts the element and all of its descendants into the owned
+    /// [`crate::SsmlElement`] representation.
+    pub fn into_owned(self) -> crate::SsmlElement {
+        use crate::SsmlElement as Owned;
+
+        match self {
+            SsmlElement::Voice { name, children } => Owned::Voice {
+                name: name.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Speak {
+                version,
+                xmlns,
+                lang,
+                children,
+            } => Owned::Speak {
+                version: version.map(Cow::into_owned),
+                xmlns: xmlns.map(Cow::into_owned),
+                lang: lang.map(Cow::into_owned),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Paragraph { children } => Owned::Paragraph {
+                children: into_owned_children(children),
+            },
+            SsmlElement::Sentence { children } => Owned::Sentence {
+                children: into_owned_children(children),
+            },
+            SsmlElement::Phoneme {
+                alphabet,
+                ph,
+                children,
+            } => Owned::Phoneme {
+                alphabet: alphabet.into_owned(),
+                ph: ph.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::SayAs {
+                interpret_as,
+                format,
+                detail,
+                children,
+            } => Owned::SayAs {
+                interpret_as: interpret_as.into_owned(),
+                format: format.into_owned(),
+                detail: detail.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Sub { alias, children } => Owned::Sub {
+                alias: alias.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Prosody {
+                rate,
+                pitch,
+                contour,
+                range,
+                volume,
+                children,
+            } => Owned::Prosody {
+                rate: rate.into_owned(),
+                pitch: pitch.into_owned(),
+                contour: contour.into_owned(),
+                range: range.into_owned(),
+                volume: volume.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Emphasis { level, children } => Owned::Emphasis {
+                level: level.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Break { time, strength } => Owned::Break { time, strength },
+            SsmlElement::Mark { name } => Owned::Mark {
+                name: name.into_owned(),
+            },
+            SsmlElement::Audio { src, children } => Owned::Audio {
+                src: src.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Desc { children } => Owned::Desc {
+                children: into_owned_children(children),
+            },
+            SsmlElement::LexiconUri { uri } => Owned::LexiconUri {
+                uri: uri.into_owned(),
+            },
+            SsmlElement::Lang { xml_lang, children } => Owned::Lang {
+                xml_lang: xml_lang.into_owned(),
+                children: into_owned_children(children),
+            },
+            SsmlElement::Text(text) => Owned::Text(text.into_owned()),
+        }
+    }
+
+    /// Returns the child elements of a container element, or `None` for leaf elements.
+    pub(crate) fn children_mut(&mut self) -> Option<&mut Vec<Self>> {
+        match self {
+            SsmlElement::Voice { children, .. }
+            | SsmlElement::Speak { children, .. }
+            | SsmlElement::Paragraph { children }
+            | SsmlElement::Sentence { children }
+            | SsmlElement::Phoneme { children, .. }
+            | SsmlElement::SayAs { children, .. }
+            | SsmlElement::Sub { children, .. }
+            | SsmlElement::Prosody { children, .. }
+            | SsmlElement::Emphasis { children, .. }
+            | SsmlElement::Audio { children, .. }
+            | SsmlElement::Desc { children }
+            | SsmlElement::Lang { children, .. } => Some(children),
+            SsmlElement::Break { .. }
+            | SsmlElement::Mark { .. }
+            | SsmlElement::LexiconUri { .. }
+            | SsmlElement::Text(_) => None,
+        }
+    }
+}
+
+impl From<SsmlElement<'_>> for crate::SsmlElement {
+    fn from(element: SsmlElement<'_>) -> Self {
+        element.into_owned()
+    }
+}
+
+fn into_owned_children(children: Vec<SsmlElement<'_>>) -> Vec<crate::SsmlElement> {
+    children.into_iter().map(SsmlElement::into_owned).collect()
+}
+
+/// Parses an SSML string into a [`SSML`] tree that borrows from `input`.
+///
+/// Accepts the same grammar as [`crate::from_str`]; the result is equal to it after
+/// [`SSML::into_owned`].
+///
+/// # Examples
+///
+/// ```rust
+/// let ssml = serde_ssml::borrowed::from_str("<speak>Hello</speak>").unwrap();
+/// assert_eq!(ssml.elements.len(), 1);
+/// ```
+pub fn from_str(input: &str) -> Result<SSML<'_>, ParseError> {
+    Parser::new().parse_borrowed(input)
+}
+
+/// Parses an SSML string into a borrowed [`SSML`] tree, enforcing the resource limits in
+/// `options`.
+///
+/// See [`crate::from_str_with_options`].
+pub fn from_str_with_options<'a>(
+    input: &'a str,
+    options: &ParseOptions,
+) -> Result<SSML<'a>, ParseError> {
+    Parser::new().limits(*options).parse_borrowed(input)
+}
+
+/// Parses inline SSML content into elements that borrow from `input`.
+///
+/// See [`crate::from_fragment`].
+pub fn from_fragment(
+    input: &str,
+    context: ElementKind,
+) -> Result<Vec<SsmlElement<'_>>, ParseError> {
+    Parser::new().parse_fragment_borrowed(input, context)
+}
@@ -0,0 +1,475 @@
+//! Fluent builders for constructing documents.
+//!
+//! [`Speak`] builds a whole document. Content is added with one method per element. Block-level
+//! containers such as `<voice>` and `<p>` take a closure that fills a nested builder, while
+//! `<s>` and `<emphasis>` take text, or a closure in their `_with` variants:
+//!
+//! ```rust
+//! use serde_ssml::builder::{EmphasisLevel, Speak};
+//!
+//! let ssml = Speak::new()
+//!     .lang("en-US")
+//!     .voice("Joanna", |v| {
+//!         v.p(|p| {
+//!             p.s("Hello")
+//!                 .break_ms(300)
+//!                 .emphasis(EmphasisLevel::Strong, "world")
+//!         })
+//!     })
+//!     .build();
+//!
+//! assert_eq!(
+//!     ssml.to_string(),
+//!     r#"<speak xml:lang="en-US"><voice name="Joanna"><p><s>Hello</s><break time="300ms"/><emphasis level="strong">world</emphasis></p></voice></speak>"#
+//! );
+//! ```
+//!
+//! Each builder only has methods for the content SSML allows in its context, so invalid
+//! nesting does not compile. [`Block`] is the content of `<voice>`, `<prosody>` and `<lang>`
+//! at block level, [`Paragraph`] the content of `<p>`, and [`Inline`] the content of `<s>`,
+//! `<emphasis>` and elements nested in them. `<say-as>`, `<phoneme>`, `<sub>` and `<desc>`
+//! only contain text:
+//!
+//! ```rust,compile_fail
+//! use serde_ssml::builder::Speak;
+//!
+//! // A paragraph cannot appear inside a sentence
+//! let ssml = Speak::new().s_with(|s| s.p(|p| p.s("Nope"))).build();
+//! ```
+
+use std::time::Duration;
+
+use crate::{BreakStrength, SSML, SsmlElement};
+
+fn text_children(text: String) -> Vec<SsmlElement> {
+    if text.is_empty() {
+        Vec::new()
+    } else {
+        vec![SsmlElement::Text(text)]
+    }
+}
+
+/// The level of an `<emphasis>` element.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum EmphasisLevel {
+    /// `strong`
+    Strong,
+    /// `moderate`, the default level
+    #[default]
+    Moderate,
+    /// `none`, preventing emphasis the engine would otherwise add
+    None,
+    /// `reduced`
+    Reduced,
+}
+
+impl EmphasisLevel {
+    /// Returns the SSML attribute value.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EmphasisLevel::Strong => "strong",
+            EmphasisLevel::Moderate => "moderate",
+            EmphasisLevel::None => "none",
+            EmphasisLevel::Reduced => "reduced",
+        }
+    }
+}
+
+/// The attributes of a `<prosody>` element; unset attributes are omitted.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::builder::{Prosody, Speak};
+///
+/// let ssml = Speak::new()
+///     .prosody(Prosody::new().rate("slow").volume("soft"), |b| b.text("Quietly"))
+///     .build();
+///
+/// assert_eq!(
+///     ssml.to_string(),
+///     r#"<speak><prosody rate="slow" volume="soft">Quietly</prosody></speak>"#
+/// );
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Prosody {
+    rate: String,
+    pitch: String,
+    contour: String,
+    range: String,
+    volume: String,
+}
+
+impl Prosody {
+    /// Creates prosody attributes with nothing set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the speaking rate, e.g. `slow` or `80%`.
+    pub fn rate(mut self, rate: impl Into<String>) -> Self {
+        self.rate = rate.into();
+        self
+    }
+
+    /// Sets the baseline pitch, e.g. `high` or `+10%`.
+    pub fn pitch(mut self, pitch: impl Into<String>) -> Self {
+        self.pitch = pitch.into();
+        self
+    }
+
+    /// Sets the pitch contour, e.g. `(0%,+20Hz) (100%,-10Hz)`.
+    pub fn contour(mut self, contour: impl Into<String>) -> Self {
+        self.contour = contour.into();
+        self
+    }
+
+    /// Sets the pitch range, e.g. `x-low`.
+    pub fn range(mut self, range: impl Into<String>) -> Self {
+        self.range = range.into();
+        self
+    }
+
+    /// Sets the volume, e.g. `soft` or `+6dB`.
+    pub fn volume(mut self, volume: impl Into<String>) -> Self {
+        self.volume = volume.into();
+        self
+    }
+
+    fn into_element(self, children: Vec<SsmlElement>) -> SsmlElement {
+        SsmlElement::Prosody {
+            rate: self.rate,
+            pitch: self.pitch,
+            contour: self.contour,
+            range: self.range,
+            volume: self.volume,
+            children,
+        }
+    }
+}
+
+/// Builds a `<speak>` document.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Speak {
+    version: Option<String>,
+    lang: Option<String>,
+    children: Vec<SsmlElement>,
+}
+
+/// Builds block-level content, as found in `<voice>`, `<prosody>` and `<lang>` outside of
+/// paragraphs and sentences.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Block {
+    children: Vec<SsmlElement>,
+}
+
+/// Builds the content of a `<p>` element.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Paragraph {
+    children: Vec<SsmlElement>,
+}
+
+/// Builds inline content, as found in `<s>` and `<emphasis>`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Inline {
+    children: Vec<SsmlElement>,
+}
+
+/// Builds the content of an `<audio>` element: a `<desc>` and fallback content.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Audio {
+    children: Vec<SsmlElement>,
+}
+
+// Methods shared by all builders; `$nested` is the builder for `<voice>`, `<prosody>` and
+// `<lang>` content in this context
+macro_rules! content_methods {
+    ($builder:ty, $nested:ty) => {
+        impl $builder {
+            fn push(mut self, element: SsmlElement) -> Self {
+                self.children.push(element);
+                self
+            }
+
+            /// Appends text.
+            pub fn text(self, text: impl Into<String>) -> Self {
+                self.push(SsmlElement::Text(text.into()))
+            }
+
+            /// Appends a `<break>` of the given length in milliseconds.
+            pub fn break_ms(self, milliseconds: u64) -> Self {
+                self.break_time(Duration::from_millis(milliseconds))
+            }
+
+            /// Appends a `<break>` of the given length.
+            pub fn break_time(self, time: Duration) -> Self {
+                self.push(SsmlElement::Break {
+                    time: Some(time),
+                    strength: None,
+                })
+            }
+
+            /// Appends a `<break>` of the given strength.
+            pub fn break_strength(self, strength: BreakStrength) -> Self {
+                self.push(SsmlElement::Break {
+                    time: None,
+                    strength: Some(strength),
+                })
+            }
+
+            /// Appends a `<mark>`.
+            pub fn mark(self, name: impl Into<String>) -> Self {
+                self.push(SsmlElement::Mark { name: name.into() })
+            }
+
+            /// Appends an `<emphasis>` element containing text.
+            pub fn emphasis(self, level: EmphasisLevel, text: impl Into<String>) -> Self {
+                self.push(SsmlElement::Emphasis {
+                    level: level.as_str().to_string(),
+                    children: text_children(text.into()),
+                })
+            }
+
+            /// Appends an `<emphasis>` element with the content added by `content`.
+            pub fn emphasis_with(
+                self,
+                level: EmphasisLevel,
+                content: impl FnOnce(Inline) -> Inline,
+            ) -> Self {
+                self.push(SsmlElement::Emphasis {
+                    level: level.as_str().to_string(),
+                    children: content(Inline::default()).children,
+                })
+            }
+
+            /// Appends a `<say-as>` element.
+            pub fn say_as(self, interpret_as: impl Into<String>, text: impl Into<String>) -> Self {
+                self.push(SsmlElement::SayAs {
+                    interpret_as: interpret_as.into(),
+                    format: String::new(),
+                    detail: String::new(),
+                    children: text_children(text.into()),
+                })
+            }
+
+            /// Appends a `<say-as>` element with a `format`, e.g. `mdy` for dates.
+            pub fn say_as_format(
+                self,
+                interpret_as: impl Into<String>,
+                format: impl Into<String>,
+                text: impl Into<String>,
+            ) -> Self {
+                self.push(SsmlElement::SayAs {
+                    interpret_as: interpret_as.into(),
+                    format: format.into(),
+                    detail: String::new(),
+                    children: text_children(text.into()),
+                })
+            }
+
+            /// Appends a `<phoneme>` element.
+            pub fn phoneme(
+                self,
+                alphabet: impl Into<String>,
+                ph: impl Into<String>,
+                text: impl Into<String>,
+            ) -> Self {
+                self.push(SsmlElement::Phoneme {
+                    alphabet: alphabet.into(),
+                    ph: ph.into(),
+                    children: text_children(text.into()),
+                })
+            }
+
+            /// Appends a `<sub>` element.
+            pub fn sub(self, alias: impl Into<String>, text: impl Into<String>) -> Self {
+                self.push(SsmlElement::Sub {
+                    alias: alias.into(),
+                    children: text_children(text.into()),
+                })
+            }
+
+            /// Appends an `<audio>` element.
+            pub fn audio(
+                self,
+                src: impl Into<String>,
+                content: impl FnOnce(Audio) -> Audio,
+            ) -> Self {
+                self.push(SsmlElement::Audio {
+                    src: src.into(),
+                    children: content(Default::default()).children,
+                })
+            }
+
+            /// Appends a `<voice>` element.
+            pub fn voice(
+                self,
+                name: impl Into<String>,
+                content: impl FnOnce($nested) -> $nested,
+            ) -> Self {
+                self.push(SsmlElement::Voice {
+                    name: name.into(),
+                    children: content(Default::default()).children,
+                })
+            }
+
+            /// Appends a `<prosody>` element.
+            pub fn prosody(
+                self,
+                prosody: Prosody,
+                content: impl FnOnce($nested) -> $nested,
+            ) -> Self {
+                self.push(prosody.into_element(content(Default::default()).children))
+            }
+
+            /// Appends a `<lang>` element.
+            pub fn in_lang(
+                self,
+                lang: impl Into<String>,
+                content: impl FnOnce($nested) -> $nested,
+            ) -> Self {
+                self.push(SsmlElement::Lang {
+                    xml_lang: lang.into(),
+                    children: content(Default::default()).children,
+                })
+            }
+        }
+    };
+}
+
+// Methods for builders whose content may contain sentences
+macro_rules! sentence_methods {
+    ($builder:ty) => {
+        impl $builder {
+            /// Appends an `<s>` element containing text.
+            pub fn s(self, text: impl Into<String>) -> Self {
+                self.push(SsmlElement::Sentence {
+                    children: text_children(text.into()),
+                })
+            }
+
+            /// Appends an `<s>` element with the content added by `content`.
+            pub fn s_with(self, content: impl FnOnce(Inline) -> Inline) -> Self {
+                self.push(SsmlElement::Sentence {
+                    children: content(Inline::default()).children,
+                })
+            }
+        }
+    };
+}
+
+// Methods for builders whose content may contain paragraphs
+macro_rules! paragraph_methods {
+    ($builder:ty) => {
+        impl $builder {
+            /// Appends a `<p>` element.
+            pub fn p(self, content: impl FnOnce(Paragraph) -> Paragraph) -> Self {
+                self.push(SsmlElement::Paragraph {
+                    children: content(Default::default()).children,
+                })
+            }
+        }
+    };
+}
+
+content_methods!(Speak, Block);
+sentence_methods!(Speak);
+paragraph_methods!(Speak);
+
+content_methods!(Block, Block);
+sentence_methods!(Block);
+paragraph_methods!(Block);
+
+content_methods!(Paragraph, Paragraph);
+sentence_methods!(Paragraph);
+
+content_methods!(Inline, Inline);
+
+content_methods!(Audio, Inline);
+
+impl Speak {
+    /// Creates an empty document builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the `version` attribute, e.g. `1.1`.
+    pub fn version(mut self, version: impl Into<String>) -> Self {
+        self.version = Some(version.into());
+        self
+    }
+
+    /// Sets the `xml:lang` attribute of the document.
+    pub fn lang(mut self, lang: impl Into<String>) -> Self {
+        self.lang = Some(lang.into());
+        self
+    }
+
+    /// Appends a `<lexicon>` reference.
+    pub fn lexicon(self, uri: impl Into<String>) -> Self {
+        self.push(SsmlElement::LexiconUri { uri: uri.into() })
+    }
+
+    /// Returns the document, with a single `<speak>` root.
+    pub fn build(self) -> SSML {
+        SSML {
+            elements: vec![SsmlElement::Speak {
+                version: self.version,
+                xmlns: None,
+                lang: self.lang,
+                children: self.children,
+            }],
+        }
+    }
+}
+
+impl From<Speak> for SSML {
+    fn from(speak: Speak) -> Self {
+        speak.build()
+    }
+}
+
+impl Block {
+    /// Creates an empty builder, e.g. for a fragment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nodes added so far.
+    pub fn build(self) -> Vec<SsmlElement> {
+        self.children
+    }
+}
+
+impl Paragraph {
+    /// Creates an empty builder, e.g. for a fragment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nodes added so far.
+    pub fn build(self) -> Vec<SsmlElement> {
+        self.children
+    }
+}
+
+impl Inline {
+    /// Creates an empty builder, e.g. for a fragment.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the nodes added so far.
+    pub fn build(self) -> Vec<SsmlElement> {
+        self.children
+    }
+}
+
+impl Audio {
+    /// Appends a `<desc>` element describing the audio.
+    pub fn desc(self, text: impl Into<String>) -> Self {
+        self.push(SsmlElement::Desc {
+            children: text_children(text.into()),
+        })
+    }
+}
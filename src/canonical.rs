@@ -0,0 +1,149 @@
+use std::borrow::Cow;
+
+use crate::{
+    AttributeOrder, BreakStrength, SSML, SerializeOptions, SsmlElement,
+    parser::collapse_whitespace, ser,
+};
+
+/// Returns an equivalent document in canonical form.
+///
+/// Two documents that only differ in ways a speech engine ignores have equal canonical forms:
+///
+/// - adjacent text nodes are merged, and every run of whitespace becomes a single space
+/// - whitespace at the start and end of `<speak>`, `<p>`, `<s>` and the document is removed,
+///   as are text nodes left empty
+/// - `<emphasis level="moderate">` drops the default level
+/// - `<break strength="medium"/>` without a `time` drops the default strength
+///
+/// Durations are stored as [`std::time::Duration`], so `1s` and `1000ms` are already equal
+/// after parsing, and [`crate::to_string_canonical`] sorts attributes by name.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{SSML, SsmlElement, canonicalize};
+///
+/// let ssml = SSML {
+///     elements: vec![SsmlElement::Sentence {
+///         children: vec![
+///             SsmlElement::Text(" Hello ".to_string()),
+///             SsmlElement::Text("\n  world ".to_string()),
+///         ],
+///     }],
+/// };
+///
+/// assert_eq!(
+///     canonicalize(&ssml).elements,
+///     vec![SsmlElement::Sentence {
+///         children: vec![SsmlElement::Text("Hello world".to_string())],
+///     }]
+/// );
+/// ```
+pub fn canonicalize(ssml: &SSML) -> SSML {
+    let mut ssml = ssml.clone();
+    canonicalize_children(&mut ssml.elements, true);
+    ssml
+}
+
+impl SSML {
+    /// Returns a hash of the document's canonical form, suitable as a cache key.
+    ///
+    /// Equivalent documents (see [`canonicalize`]) hash equally. The value is the 64-bit
+    /// FNV-1a hash of [`crate::to_string_canonical`], so it is stable across platforms and
+    /// program runs, unlike [`std::hash::Hash`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let a = serde_ssml::from_str(r#"<speak><break time="2s"/></speak>"#).unwrap();
+    /// let b = serde_ssml::from_str(r#"<speak> <break time="2000ms"/> </speak>"#).unwrap();
+    ///
+    /// assert_eq!(a.content_hash(), b.content_hash());
+    /// ```
+    pub fn content_hash(&self) -> u64 {
+        fnv1a(to_ssml_canonical(self).as_bytes())
+    }
+}
+
+/// Serializes the canonical form of the document with sorted attributes
+pub(crate) fn to_ssml_canonical(ssml: &SSML) -> String {
+    let options = SerializeOptions::new().attribute_order(AttributeOrder::Alphabetical);
+    ser::to_ssml(&canonicalize(ssml), &options)
+}
+
+fn canonicalize_children(children: &mut Vec<SsmlElement>, trim: bool) {
+    let mut canonical: Vec<SsmlElement> = Vec::with_capacity(children.len());
+    for child in children.drain(..) {
+        if let SsmlElement::Text(text) = &child
+            && let Some(SsmlElement::Text(previous)) = canonical.last_mut()
+        {
+            previous.push_str(text);
+            continue;
+        }
+        canonical.push(child);
+    }
+
+    let last = canonical.len().saturating_sub(1);
+    for (index, child) in canonical.iter_mut().enumerate() {
+        let SsmlElement::Text(text) = child else {
+            canonicalize_element(child);
+            continue;
+        };
+
+        let mut collapsed: &str = &collapse_whitespace(Cow::Borrowed(text.as_str()));
+        if trim && index == 0 {
+            collapsed = collapsed.trim_start();
+        }
+        if trim && index == last {
+            collapsed = collapsed.trim_end();
+        }
+        if collapsed != text {
+            *text = collapsed.to_string();
+        }
+    }
+
+    canonical.retain(|child| !matches!(child, SsmlElement::Text(text) if text.is_empty()));
+    *children = canonical;
+}
+
+fn canonicalize_element(element: &mut SsmlElement) {
+    match element {
+        SsmlElement::Emphasis { level, .. } if level == "moderate" => level.clear(),
+        SsmlElement::Break {
+            time: None,
+            strength,
+        } if *strength == Some(BreakStrength::Medium) => *strength = None,
+        _ => {}
+    }
+
+    let trim = matches!(
+        element,
+        SsmlElement::Speak { .. } | SsmlElement::Paragraph { .. } | SsmlElement::Sentence { .. }
+    );
+    if let Some(children) = element.children_mut() {
+        canonicalize_children(children, trim);
+    }
+}
+
+// 64-bit FNV-1a, chosen for being trivial to reproduce outside of Rust
+fn fnv1a(bytes: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    bytes.iter().fold(OFFSET_BASIS, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(PRIME)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fnv1a() {
+        // Reference values from the FNV specification
+        assert_eq!(fnv1a(b""), 0xcbf2_9ce4_8422_2325);
+        assert_eq!(fnv1a(b"a"), 0xaf63_dc4c_8601_ec8c);
+        assert_eq!(fnv1a(b"foobar"), 0x8594_4171_f739_67e8);
+    }
+}
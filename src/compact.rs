@@ -0,0 +1,434 @@
+//! Compact JSON-friendly representation of SSML documents.
+//!
+//! The derived [`Serialize`] and [`Deserialize`] implementations of [`SSML`] and
+//! [`SsmlElement`] mirror the Rust types (`{"type": "Speak", "data": {...}}`). Wrapping a
+//! document in [`Compact`] switches to a shape that mirrors the markup instead:
+//!
+//! - a document is an array of nodes
+//! - text is a bare string
+//! - an element is an object with its tag name as the only key, holding its attributes under
+//!   their SSML names and its content under `"children"` (omitted when empty)
+//!
+//! Attribute values are strings exactly as they appear in markup, e.g. `"time": "500ms"`.
+//! [`json_schema`] describes the format; the crate ships it as
+//! `schema/ssml-compact.schema.json`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serde_ssml::compact::Compact;
+//!
+//! let ssml = serde_ssml::from_str(
+//!     r#"<speak xml:lang="en-US">Hello <break time="500ms"/> world</speak>"#,
+//! )
+//! .unwrap();
+//!
+//! let json = serde_json::to_string(&Compact(&ssml)).unwrap();
+//! assert_eq!(
+//!     json,
+//!     r#"[{"speak":{"xml:lang":"en-US","children":["Hello",{"break":{"time":"500ms"}},"world"]}}]"#
+//! );
+//!
+//! let Compact(parsed) = serde_json::from_str(&json).unwrap();
+//! assert_eq!(parsed, ssml);
+//! ```
+//!
+//! Use the module with `#[serde(with = "serde_ssml::compact")]` for fields of type [`SSML`]:
+//!
+//! ```rust
+//! #[derive(serde::Serialize, serde::Deserialize)]
+//! struct Message {
+//!     id: u64,
+//!     #[serde(with = "serde_ssml::compact")]
+//!     ssml: serde_ssml::SSML,
+//! }
+//! ```
+
+use std::fmt::{self, Write};
+
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, MapAccess, SeqAccess, Visitor},
+    ser::{SerializeMap, SerializeSeq},
+};
+
+use crate::{BreakStrength, ElementKind, SSML, SerializeOptions, SsmlElement, se, ser};
+
+/// Key holding the content of an element.
+const CHILDREN: &str = "children";
+
+/// Wrapper selecting the compact representation of a document.
+///
+/// Serializes both `Compact(SSML)` and `Compact(&SSML)`; deserializes `Compact(SSML)`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Compact<T>(pub T);
+
+impl Serialize for Compact<SSML> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl Serialize for Compact<&SSML> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(self.0, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Compact<SSML> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Compact)
+    }
+}
+
+/// Serializes a document in the compact representation, for `#[serde(with)]`.
+pub fn serialize<S: Serializer>(ssml: &SSML, serializer: S) -> Result<S::Ok, S::Error> {
+    Nodes(&ssml.elements).serialize(serializer)
+}
+
+/// Deserializes a document from the compact representation, for `#[serde(with)]`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<SSML, D::Error> {
+    let nodes: Vec<Node> = Deserialize::deserialize(deserializer)?;
+    Ok(SSML {
+        elements: nodes.into_iter().map(|Node(node)| node).collect(),
+    })
+}
+
+struct Nodes<'a>(&'a [SsmlElement]);
+
+impl Serialize for Nodes<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for node in self.0 {
+            seq.serialize_element(&NodeRef(node))?;
+        }
+        seq.end()
+    }
+}
+
+struct NodeRef<'a>(&'a SsmlElement);
+
+impl Serialize for NodeRef<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.0 {
+            SsmlElement::Text(text) => serializer.serialize_str(text),
+            element => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry(element.kind().tag_name(), &Body(element))?;
+                map.end()
+            }
+        }
+    }
+}
+
+// Attributes and children of an element
+struct Body<'a>(&'a SsmlElement);
+
+impl Serialize for Body<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let attributes = ser::attributes(self.0, &SerializeOptions::default());
+        let children = ser::children(self.0).unwrap_or_default();
+
+        let len = attributes.len() + usize::from(!children.is_empty());
+        let mut map = serializer.serialize_map(Some(len))?;
+        for (name, value) in &attributes {
+            map.serialize_entry(name, value)?;
+        }
+        if !children.is_empty() {
+            map.serialize_entry(CHILDREN, &Nodes(children))?;
+        }
+        map.end()
+    }
+}
+
+struct Node(SsmlElement);
+
+impl<'de> Deserialize<'de> for Node {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(NodeVisitor)
+    }
+}
+
+struct NodeVisitor;
+
+impl<'de> Visitor<'de> for NodeVisitor {
+    type Value = Node;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string or an object with a single SSML element name as key")
+    }
+
+    fn visit_str<E: de::Error>(self, text: &str) -> Result<Node, E> {
+        Ok(Node(SsmlElement::Text(text.to_string())))
+    }
+
+    fn visit_string<E: de::Error>(self, text: String) -> Result<Node, E> {
+        Ok(Node(SsmlElement::Text(text)))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Node, A::Error> {
+        let Some(name) = map.next_key::<String>()? else {
+            return Err(de::Error::invalid_length(0, &self));
+        };
+        let ElementBody {
+            attributes,
+            children,
+        } = map.next_value()?;
+        if map.next_key::<de::IgnoredAny>()?.is_some() {
+            return Err(de::Error::invalid_length(2, &self));
+        }
+
+        let children = children.into_iter().map(|Node(node)| node).collect();
+        se::build(&name, attributes, children)
+            .map(Node)
+            .map_err(de::Error::custom)
+    }
+}
+
+struct ElementBody {
+    attributes: Vec<(String, String)>,
+    children: Vec<Node>,
+}
+
+impl<'de> Deserialize<'de> for ElementBody {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_map(ElementBodyVisitor)
+    }
+}
+
+struct ElementBodyVisitor;
+
+impl<'de> Visitor<'de> for ElementBodyVisitor {
+    type Value = ElementBody;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an object of attributes and children")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<ElementBody, A::Error> {
+        let mut body = ElementBody {
+            attributes: Vec::new(),
+            children: Vec::new(),
+        };
+        while let Some(key) = map.next_key::<String>()? {
+            if key == CHILDREN {
+                body.children = map.next_value::<NodeList>()?.0;
+            } else {
+                let value = map.next_value()?;
+                body.attributes.push((key, value));
+            }
+        }
+        Ok(body)
+    }
+}
+
+struct NodeList(Vec<Node>);
+
+impl<'de> Deserialize<'de> for NodeList {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_seq(NodeListVisitor)
+    }
+}
+
+struct NodeListVisitor;
+
+impl<'de> Visitor<'de> for NodeListVisitor {
+    type Value = NodeList;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("an array of SSML nodes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<NodeList, A::Error> {
+        let mut nodes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(node) = seq.next_element()? {
+            nodes.push(node);
+        }
+        Ok(NodeList(nodes))
+    }
+}
+
+/// Returns a JSON Schema (draft 2020-12) describing the compact representation.
+///
+/// The schema is generated from [`ElementKind`], so it lists exactly the elements and
+/// attributes the document model supports.
+///
+/// # Examples
+///
+/// ```rust
+/// let schema: serde_json::Value =
+///     serde_json::from_str(&serde_ssml::compact::json_schema()).unwrap();
+/// assert_eq!(schema["type"], "array");
+/// ```
+pub fn json_schema() -> String {
+    let node_ref = || Json::object([("$ref", Json::from("#/$defs/node"))]);
+    let string = || Json::object([("type", Json::from("string"))]);
+
+    let elements = ElementKind::ALL
+        .into_iter()
+        .filter(|kind| *kind != ElementKind::Text);
+
+    let mut definitions = vec![(
+        "node".to_string(),
+        Json::object([(
+            "oneOf",
+            Json::Array(
+                std::iter::once(string())
+                    .chain(elements.clone().map(|kind| {
+                        Json::object([("$ref", Json::from(format!("#/$defs/{}", kind.tag_name())))])
+                    }))
+                    .collect(),
+            ),
+        )]),
+    )];
+
+    for kind in elements {
+        let mut properties: Vec<(String, Json)> = kind
+            .attributes()
+            .iter()
+            .map(|attribute| {
+                let schema = match (kind, *attribute) {
+                    (ElementKind::Break, "strength") => Json::object([(
+                        "enum",
+                        Json::Array(
+                            [
+                                BreakStrength::None,
+                                BreakStrength::XWeak,
+                                BreakStrength::Weak,
+                                BreakStrength::Medium,
+                                BreakStrength::Strong,
+                                BreakStrength::XStrong,
+                            ]
+                            .into_iter()
+                            .map(|strength| Json::from(strength.to_ssml()))
+                            .collect(),
+                        ),
+                    )]),
+                    _ => string(),
+                };
+                (attribute.to_string(), schema)
+            })
+            .collect();
+        if kind.is_container() {
+            properties.push((
+                CHILDREN.to_string(),
+                Json::object([("type", Json::from("array")), ("items", node_ref())]),
+            ));
+        }
+
+        let body = Json::object([
+            ("type", Json::from("object")),
+            ("properties", Json::Object(properties)),
+            ("additionalProperties", Json::Bool(false)),
+        ]);
+        let element = Json::object([
+            ("type", Json::from("object")),
+            ("properties", Json::object([(kind.tag_name(), body)])),
+            ("required", Json::Array(vec![Json::from(kind.tag_name())])),
+            ("additionalProperties", Json::Bool(false)),
+        ]);
+        definitions.push((kind.tag_name().to_string(), element));
+    }
+
+    let schema = Json::object([
+        (
+            "$schema",
+            Json::from("https://json-schema.org/draft/2020-12/schema"),
+        ),
+        (
+            "title",
+            Json::from("SSML document (compact representation)"),
+        ),
+        ("type", Json::from("array")),
+        ("items", node_ref()),
+        ("$defs", Json::Object(definitions)),
+    ]);
+
+    let mut output = String::new();
+    schema.write(&mut output, 0);
+    output.push('\n');
+    output
+}
+
+// Minimal JSON tree for writing the schema without a JSON dependency
+enum Json {
+    Bool(bool),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn object<const N: usize>(entries: [(&str, Json); N]) -> Json {
+        Json::Object(
+            entries
+                .into_iter()
+                .map(|(key, value)| (key.to_string(), value))
+                .collect(),
+        )
+    }
+
+    fn write(&self, output: &mut String, depth: usize) {
+        let indent = |output: &mut String, depth: usize| {
+            output.push('\n');
+            output.extend(std::iter::repeat_n("  ", depth));
+        };
+
+        match self {
+            Json::Bool(value) => {
+                let _ = write!(output, "{value}");
+            }
+            Json::String(value) => write_string(output, value),
+            Json::Array(items) => {
+                output.push('[');
+                for (index, item) in items.iter().enumerate() {
+                    if index > 0 {
+                        output.push(',');
+                    }
+                    indent(output, depth + 1);
+                    item.write(output, depth + 1);
+                }
+                indent(output, depth);
+                output.push(']');
+            }
+            Json::Object(entries) => {
+                output.push('{');
+                for (index, (key, value)) in entries.iter().enumerate() {
+                    if index > 0 {
+                        output.push(',');
+                    }
+                    indent(output, depth + 1);
+                    write_string(output, key);
+                    output.push_str(": ");
+                    value.write(output, depth + 1);
+                }
+                indent(output, depth);
+                output.push('}');
+            }
+        }
+    }
+}
+
+impl From<&str> for Json {
+    fn from(value: &str) -> Self {
+        Json::String(value.to_string())
+    }
+}
+
+impl From<String> for Json {
+    fn from(value: String) -> Self {
+        Json::String(value)
+    }
+}
+
+// Schema strings never need more than quote and backslash escapes
+fn write_string(output: &mut String, value: &str) {
+    output.push('"');
+    for c in value.chars() {
+        if matches!(c, '"' | '\\') {
+            output.push('\\');
+        }
+        output.push(c);
+    }
+    output.push('"');
+}
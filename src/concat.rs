@@ -0,0 +1,259 @@
+//! Combining documents under a single `<speak>` root.
+//!
+//! Each `<speak>` root of a combined document contributes its content. Content whose root
+//! declares a different language than the document it ends up in is wrapped in `<lang>`, so
+//! it keeps being read in its own language; `<voice>` only selects a voice by name here, so
+//! it cannot carry the language instead. `<lexicon>` references may only appear directly
+//! in `<speak>`, so they are moved to the root of the combined document. The combined root
+//! keeps the `xml:lang` of the first document, the first namespace declared and the latest
+//! `version`. Content without a declared language is never placed under the language of
+//! another document: if there is any, the root declares no language and every other
+//! document's content is wrapped in `<lang>`.
+
+use std::mem;
+
+use crate::{InvalidPath, NodePath, SSML, SsmlElement, path};
+
+impl SSML {
+    /// Appends the content of `other` to this document, reconciling their `<speak>` roots.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SSML;
+    ///
+    /// let mut greeting: SSML = r#"<speak xml:lang="en-US"><s>Hello</s></speak>"#.parse().unwrap();
+    /// let french: SSML = r#"<speak xml:lang="fr-FR"><s>Bonjour</s></speak>"#.parse().unwrap();
+    /// greeting.append(french);
+    ///
+    /// assert_eq!(
+    ///     greeting.to_string(),
+    ///     r#"<speak xml:lang="en-US"><s>Hello</s><lang xml:lang="fr-FR"><s>Bonjour</s></lang></speak>"#
+    /// );
+    /// ```
+    pub fn append(&mut self, other: SSML) {
+        *self = SSML::concat([mem::take(self), other]);
+    }
+
+    /// Combines documents in order under a single `<speak>` root.
+    ///
+    /// Top-level nodes outside of a `<speak>` element are treated as content without a
+    /// declared language. The result has no elements if none of the documents has any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SSML;
+    ///
+    /// let english: SSML = "<speak><s>Hello</s></speak>".parse().unwrap();
+    /// let french: SSML = r#"<speak xml:lang="fr-FR"><s>Bonjour</s></speak>"#.parse().unwrap();
+    ///
+    /// assert_eq!(
+    ///     SSML::concat([english, french]).to_string(),
+    ///     r#"<speak><s>Hello</s><lang xml:lang="fr-FR"><s>Bonjour</s></lang></speak>"#
+    /// );
+    /// ```
+    pub fn concat<I>(documents: I) -> SSML
+    where
+        I: IntoIterator<Item = SSML>,
+    {
+        let pieces: Vec<Piece> = documents.into_iter().flat_map(pieces).collect();
+        if pieces.is_empty() {
+            return SSML::default();
+        }
+
+        let mut root = Root::default();
+        for piece in &pieces {
+            root.update(piece);
+        }
+        // Content without a language must not end up under the language of another piece
+        if pieces
+            .iter()
+            .all(|piece| piece.lang.is_some() || piece.content.is_empty())
+        {
+            root.lang.clone_from(&pieces[0].lang);
+        }
+        let lang = root.lang.clone();
+
+        let mut lexicons = Vec::new();
+        let mut content = Vec::new();
+        for mut piece in pieces {
+            lexicons.append(&mut piece.lexicons);
+            content.extend(piece.content_in(lang.as_deref()));
+        }
+        lexicons.append(&mut content);
+
+        SSML {
+            elements: vec![SsmlElement::Speak {
+                version: root.version,
+                xmlns: root.xmlns,
+                lang: root.lang,
+                children: lexicons,
+            }],
+        }
+    }
+
+    /// Inserts the content of `fragment` at `path`, shifting later siblings.
+    ///
+    /// Content is wrapped in `<lang>` if the root of `fragment` declares another language
+    /// than the one in effect at `path`, and `<lexicon>` references are moved to the
+    /// `<speak>` root of this document. The root also takes the `version` of `fragment` if
+    /// that is later.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{NodePath, SSML};
+    ///
+    /// let mut ssml: SSML = r#"<speak xml:lang="en-US"><p><s>One</s><s>Three</s></p></speak>"#
+    ///     .parse()
+    ///     .unwrap();
+    /// let fragment: SSML = r#"<speak xml:lang="de-DE"><s>Zwei</s></speak>"#.parse().unwrap();
+    /// ssml.splice_into(&NodePath::from([0, 0, 1]), fragment).unwrap();
+    ///
+    /// assert_eq!(
+    ///     ssml.to_string(),
+    ///     r#"<speak xml:lang="en-US"><p><s>One</s><lang xml:lang="de-DE"><s>Zwei</s></lang><s>Three</s></p></speak>"#
+    /// );
+    /// ```
+    pub fn splice_into(&mut self, path: &NodePath, fragment: SSML) -> Result<(), InvalidPath> {
+        let lang = self.lang_at(path);
+        let pieces = pieces(fragment);
+
+        let mut root = Root::default();
+        let mut lexicons = Vec::new();
+        let mut content = Vec::new();
+        for mut piece in pieces {
+            root.update(&piece);
+            lexicons.append(&mut piece.lexicons);
+            content.extend(piece.content_in(lang.as_deref()));
+        }
+
+        match path::siblings_mut(&mut self.elements, path.indices()) {
+            Some((index, siblings)) if index <= siblings.len() => {
+                let _ = siblings.splice(index..index, content);
+            }
+            _ => return Err(InvalidPath),
+        }
+
+        match self.elements.first_mut() {
+            Some(SsmlElement::Speak {
+                version, children, ..
+            }) => {
+                if root.version > *version {
+                    *version = root.version;
+                }
+                let position = children
+                    .iter()
+                    .take_while(|child| matches!(child, SsmlElement::LexiconUri { .. }))
+                    .count();
+                let _ = children.splice(position..position, lexicons);
+            }
+            _ => {
+                let _ = self.elements.splice(0..0, lexicons);
+            }
+        }
+        Ok(())
+    }
+
+    // Returns the language in effect for a node inserted at `path`
+    fn lang_at(&self, path: &NodePath) -> Option<String> {
+        let mut lang = None;
+        let mut nodes = self.elements.as_slice();
+        let ancestors = path
+            .indices()
+            .split_last()
+            .map_or(&[][..], |(_, init)| init);
+        for &index in ancestors {
+            let Some(node) = nodes.get(index) else {
+                break;
+            };
+            match node {
+                SsmlElement::Speak { lang: Some(l), .. } => lang = Some(l.clone()),
+                SsmlElement::Lang { xml_lang, .. } => lang = Some(xml_lang.clone()),
+                _ => {}
+            }
+            nodes = node.children();
+        }
+        lang
+    }
+}
+
+// The attributes of the combined `<speak>` root
+#[derive(Default)]
+struct Root {
+    version: Option<String>,
+    xmlns: Option<String>,
+    lang: Option<String>,
+}
+
+impl Root {
+    fn update(&mut self, piece: &Piece) {
+        if piece.version > self.version {
+            self.version.clone_from(&piece.version);
+        }
+        if self.xmlns.is_none() {
+            self.xmlns.clone_from(&piece.xmlns);
+        }
+    }
+}
+
+// The content of one `<speak>` root, or of a run of top-level nodes outside of one
+#[derive(Default)]
+struct Piece {
+    version: Option<String>,
+    xmlns: Option<String>,
+    lang: Option<String>,
+    lexicons: Vec<SsmlElement>,
+    content: Vec<SsmlElement>,
+}
+
+impl Piece {
+    // Returns the content as it must appear in a context with language `lang`
+    fn content_in(self, lang: Option<&str>) -> Vec<SsmlElement> {
+        match self.lang {
+            Some(own) if !self.content.is_empty() && lang != Some(own.as_str()) => {
+                vec![SsmlElement::Lang {
+                    xml_lang: own,
+                    children: self.content,
+                }]
+            }
+            _ => self.content,
+        }
+    }
+}
+
+fn pieces(ssml: SSML) -> Vec<Piece> {
+    let mut pieces: Vec<Piece> = Vec::new();
+    let mut loose = Piece::default();
+    for element in ssml.elements {
+        match element {
+            SsmlElement::Speak {
+                version,
+                xmlns,
+                lang,
+                children,
+            } => {
+                if !loose.content.is_empty() || !loose.lexicons.is_empty() {
+                    pieces.push(mem::take(&mut loose));
+                }
+                let (lexicons, content) = children
+                    .into_iter()
+                    .partition(|child| matches!(child, SsmlElement::LexiconUri { .. }));
+                pieces.push(Piece {
+                    version,
+                    xmlns,
+                    lang,
+                    lexicons,
+                    content,
+                });
+            }
+            SsmlElement::LexiconUri { .. } => loose.lexicons.push(element),
+            element => loose.content.push(element),
+        }
+    }
+    if !loose.content.is_empty() || !loose.lexicons.is_empty() {
+        pieces.push(loose);
+    }
+    pieces
+}
@@ -0,0 +1,117 @@
+//! Standard formatting and parsing traits for the document model.
+
+use std::{fmt, slice, str::FromStr};
+
+use crate::{Error, ErrorKind, ParseError, Parser, SSML, SerializeOptions, SsmlElement, ser};
+
+fn display_options(f: &fmt::Formatter<'_>) -> SerializeOptions {
+    if f.alternate() {
+        SerializeOptions::new().indent(ser::DEFAULT_INDENT)
+    } else {
+        SerializeOptions::new()
+    }
+}
+
+/// Writes the document as SSML markup, like [`crate::to_string`].
+///
+/// The alternate form (`{:#}`) pretty-prints like [`crate::to_string_pretty`].
+///
+/// # Examples
+///
+/// ```rust
+/// let ssml: serde_ssml::SSML = "<speak><p><s>Hello</s></p></speak>".parse().unwrap();
+///
+/// assert_eq!(ssml.to_string(), "<speak><p><s>Hello</s></p></speak>");
+/// assert_eq!(
+///     format!("{ssml:#}"),
+///     "<speak>\n  <p>\n    <s>Hello</s>\n  </p>\n</speak>"
+/// );
+/// ```
+impl fmt::Display for SSML {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&ser::to_ssml(self, &display_options(f)))
+    }
+}
+
+/// Writes the element and its descendants as SSML markup.
+///
+/// The alternate form (`{:#}`) pretty-prints like [`crate::to_string_pretty`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::SsmlElement;
+///
+/// let element = SsmlElement::Emphasis {
+///     level: "strong".to_string(),
+///     children: vec![SsmlElement::Text("Tom & Jerry".to_string())],
+/// };
+///
+/// assert_eq!(
+///     element.to_string(),
+///     r#"<emphasis level="strong">Tom &amp; Jerry</emphasis>"#
+/// );
+/// ```
+impl fmt::Display for SsmlElement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&ser::to_ssml_fragment(
+            slice::from_ref(self),
+            &display_options(f),
+        ))
+    }
+}
+
+/// Parses a document with the default [`Parser`], like [`crate::from_str`].
+impl FromStr for SSML {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Parser::new().parse(s)
+    }
+}
+
+/// Parses markup holding exactly one top-level element or text node.
+///
+/// Anything else fails with [`ErrorKind::ExpectedSingleElement`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{ErrorKind, SsmlElement};
+///
+/// let element: SsmlElement = r#"<mark name="intro"/>"#.parse().unwrap();
+/// assert_eq!(element, SsmlElement::Mark { name: "intro".to_string() });
+///
+/// let errors = "<s>One</s><s>Two</s>".parse::<SsmlElement>().unwrap_err();
+/// assert_eq!(errors[0].kind(), &ErrorKind::ExpectedSingleElement { found: 2 });
+/// ```
+impl FromStr for SsmlElement {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut elements = Parser::new().parse(s)?.elements;
+        if elements.len() != 1 {
+            let kind = ErrorKind::ExpectedSingleElement {
+                found: elements.len(),
+            };
+            return Err(vec![Error::new(kind, 0..s.len())]);
+        }
+        Ok(elements.remove(0))
+    }
+}
+
+impl TryFrom<&str> for SSML {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl TryFrom<&str> for SsmlElement {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
@@ -0,0 +1,588 @@
+//! Serde deserializer reading user-defined types from SSML markup.
+//!
+//! This is the counterpart of [`crate::se`] and uses the same conventions: `@name` fields read
+//! attributes, `$text` reads the text content, `$value` reads all children in order (as enum
+//! variants named after their element, or `$text` for text), and every other field reads the
+//! child elements with its name. Missing `Option` fields become `None`.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! #[serde(rename = "speak")]
+//! struct Prompt {
+//!     #[serde(rename = "@xml:lang")]
+//!     lang: String,
+//!     #[serde(rename = "s")]
+//!     sentences: Vec<String>,
+//!     #[serde(rename = "mark")]
+//!     marks: Vec<Mark>,
+//! }
+//!
+//! #[derive(Debug, PartialEq, Deserialize)]
+//! struct Mark {
+//!     #[serde(rename = "@name")]
+//!     name: String,
+//! }
+//!
+//! let prompt: Prompt = serde_ssml::de::from_str(
+//!     r#"<speak xml:lang="en-US"><s>Hello.</s><mark name="middle"/><s>Goodbye.</s></speak>"#,
+//! )
+//! .unwrap();
+//!
+//! assert_eq!(prompt.lang, "en-US");
+//! assert_eq!(prompt.sentences, ["Hello.", "Goodbye."]);
+//! assert_eq!(prompt.marks, [Mark { name: "middle".to_string() }]);
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use serde::{
+    Deserialize,
+    de::{
+        self, DeserializeOwned, DeserializeSeed, EnumAccess, IntoDeserializer, MapAccess,
+        Unexpected, VariantAccess, Visitor,
+        value::{BorrowedStrDeserializer, SeqDeserializer},
+    },
+    forward_to_deserialize_any,
+};
+
+use crate::{SSML, SerializeOptions, SsmlElement, ser};
+
+/// Parses `input` and deserializes a `T` from it.
+///
+/// See the [module documentation](self) for how elements map to types.
+pub fn from_str<T: DeserializeOwned>(input: &str) -> Result<T, DeError> {
+    let ssml = crate::from_str(input).map_err(|mut errors| DeError::Parse(errors.remove(0)))?;
+    from_ssml(&ssml)
+}
+
+/// Deserializes a `T` from a parsed document.
+///
+/// A struct is read from the single top-level element; a sequence reads every top-level node.
+pub fn from_ssml<'de, T: Deserialize<'de>>(ssml: &'de SSML) -> Result<T, DeError> {
+    T::deserialize(Deserializer::from_ssml(ssml))
+}
+
+/// An error raised while deserializing a value from SSML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DeError {
+    /// The input is not well-formed SSML.
+    Parse(crate::Error),
+
+    /// The document does not match the shape of the type, as reported by its [`Deserialize`]
+    /// implementation.
+    Custom(String),
+}
+
+impl fmt::Display for DeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DeError::Parse(error) => write!(f, "{error}"),
+            DeError::Custom(message) => f.write_str(message),
+        }
+    }
+}
+
+impl std::error::Error for DeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DeError::Parse(error) => Some(error),
+            DeError::Custom(_) => None,
+        }
+    }
+}
+
+impl de::Error for DeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        DeError::Custom(message.to_string())
+    }
+}
+
+/// A serde [`Deserializer`](de::Deserializer) reading from a list of sibling SSML nodes.
+///
+/// Sequences read every node; any other type expects exactly one node.
+#[derive(Debug, Clone)]
+pub struct Deserializer<'de> {
+    nodes: Vec<&'de SsmlElement>,
+}
+
+impl<'de> Deserializer<'de> {
+    /// Creates a deserializer reading the top-level nodes of `ssml`.
+    pub fn from_ssml(ssml: &'de SSML) -> Self {
+        Self {
+            nodes: ssml.elements.iter().collect(),
+        }
+    }
+
+    /// Creates a deserializer reading a single element.
+    pub fn from_element(element: &'de SsmlElement) -> Self {
+        Self {
+            nodes: vec![element],
+        }
+    }
+
+    fn single(self) -> Result<NodeDeserializer<'de>, DeError> {
+        match self.nodes[..] {
+            [node] => Ok(NodeDeserializer { node }),
+            _ => Err(DeError::Custom(format!(
+                "expected a single element, found {} nodes",
+                self.nodes.len()
+            ))),
+        }
+    }
+}
+
+macro_rules! forward_to_single {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+            self.single()?.$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for Deserializer<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.nodes.len() {
+            1 => self.single()?.deserialize_any(visitor),
+            _ => self.deserialize_seq(visitor),
+        }
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        if self.nodes.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let nodes = self.nodes.into_iter().map(|node| NodeDeserializer { node });
+        visitor.visit_seq(SeqDeserializer::new(nodes))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.single()?.deserialize_unit_struct(name, visitor)
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.single()?.deserialize_struct(name, fields, visitor)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.single()?.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_single! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf deserialize_unit
+        deserialize_map deserialize_identifier
+    }
+}
+
+// Reads a single element or text node
+#[derive(Debug, Clone, Copy)]
+struct NodeDeserializer<'de> {
+    node: &'de SsmlElement,
+}
+
+impl<'de> NodeDeserializer<'de> {
+    fn name(self) -> &'static str {
+        match self.node {
+            SsmlElement::Text(_) => "$text",
+            element => element.kind().tag_name(),
+        }
+    }
+
+    // Text of a text node, or the concatenated text children of an element
+    fn text(self) -> Cow<'de, str> {
+        let children = match self.node {
+            SsmlElement::Text(text) => return Cow::Borrowed(text),
+            element => ser::children(element).unwrap_or_default(),
+        };
+        let mut texts = children.iter().filter_map(|child| match child {
+            SsmlElement::Text(text) => Some(text.as_str()),
+            _ => None,
+        });
+        match (texts.next(), texts.next()) {
+            (None, _) => Cow::Borrowed(""),
+            (Some(text), None) => Cow::Borrowed(text),
+            (Some(first), Some(second)) => {
+                Cow::Owned([first, second].into_iter().chain(texts).collect())
+            }
+        }
+    }
+
+    fn value(self) -> ValueDeserializer<'de> {
+        ValueDeserializer { value: self.text() }
+    }
+
+    fn map(self, fields: &[&str]) -> ElementMap<'de> {
+        let SsmlElement::Text(_) = self.node else {
+            return ElementMap::new(self.node, fields);
+        };
+        ElementMap {
+            entries: vec![(Cow::Borrowed("$text"), Entry::Value(self.text()))].into_iter(),
+            pending: None,
+        }
+    }
+}
+
+impl<'de> IntoDeserializer<'de, DeError> for NodeDeserializer<'de> {
+    type Deserializer = Self;
+
+    fn into_deserializer(self) -> Self {
+        self
+    }
+}
+
+macro_rules! forward_to_value {
+    ($($method:ident)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+            self.value().$method(visitor)
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for NodeDeserializer<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let SsmlElement::Text(_) = self.node else {
+            let structured = !ser::attributes(self.node, &SerializeOptions::default()).is_empty()
+                || ser::children(self.node).is_some_and(|children| {
+                    children
+                        .iter()
+                        .any(|child| !matches!(child, SsmlElement::Text(_)))
+                });
+            return match (structured, self.text()) {
+                (true, _) => visitor.visit_map(self.map(&[])),
+                (false, text) if text.is_empty() => visitor.visit_unit(),
+                (false, _) => self.value().deserialize_any(visitor),
+            };
+        };
+        self.value().deserialize_any(visitor)
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_unit_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_seq<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        let children = ser::children(self.node).unwrap_or_default();
+        let nodes = children.iter().map(|node| NodeDeserializer { node });
+        visitor.visit_seq(SeqDeserializer::new(nodes))
+    }
+
+    fn deserialize_tuple<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_tuple_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: usize,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        self.deserialize_seq(visitor)
+    }
+
+    fn deserialize_map<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_map(self.map(&[]))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_map(self.map(fields))
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_enum(self)
+    }
+
+    fn deserialize_identifier<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_borrowed_str(self.name())
+    }
+
+    fn deserialize_ignored_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    forward_to_value! {
+        deserialize_bool deserialize_i8 deserialize_i16 deserialize_i32 deserialize_i64
+        deserialize_i128 deserialize_u8 deserialize_u16 deserialize_u32 deserialize_u64
+        deserialize_u128 deserialize_f32 deserialize_f64 deserialize_char deserialize_str
+        deserialize_string deserialize_bytes deserialize_byte_buf
+    }
+}
+
+// Enum variants are named after the element, or `$text` for text nodes
+impl<'de> EnumAccess<'de> for NodeDeserializer<'de> {
+    type Error = DeError;
+    type Variant = Self;
+
+    fn variant_seed<S: DeserializeSeed<'de>>(
+        self,
+        seed: S,
+    ) -> Result<(S::Value, Self::Variant), DeError> {
+        let variant = seed.deserialize(BorrowedStrDeserializer::new(self.name()))?;
+        Ok((variant, self))
+    }
+}
+
+impl<'de> VariantAccess<'de> for NodeDeserializer<'de> {
+    type Error = DeError;
+
+    fn unit_variant(self) -> Result<(), DeError> {
+        Ok(())
+    }
+
+    fn newtype_variant_seed<S: DeserializeSeed<'de>>(self, seed: S) -> Result<S::Value, DeError> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: Visitor<'de>>(self, _: usize, visitor: V) -> Result<V::Value, DeError> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: Visitor<'de>>(
+        self,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_map(self.map(fields))
+    }
+}
+
+// A field of an element: an attribute or text value, or a group of child nodes
+#[derive(Debug)]
+enum Entry<'de> {
+    Value(Cow<'de, str>),
+    Nodes(Vec<&'de SsmlElement>),
+}
+
+// Presents an element as a map from `@attribute`, `$text`, `$value` and child tag names
+#[derive(Debug)]
+struct ElementMap<'de> {
+    entries: std::vec::IntoIter<(Cow<'de, str>, Entry<'de>)>,
+    pending: Option<Entry<'de>>,
+}
+
+impl<'de> ElementMap<'de> {
+    fn new(element: &'de SsmlElement, fields: &[&str]) -> Self {
+        let mut entries: Vec<(Cow<'de, str>, Entry<'de>)> =
+            ser::attributes(element, &SerializeOptions::default())
+                .into_iter()
+                .map(|(name, value)| (Cow::Owned(format!("@{name}")), Entry::Value(value)))
+                .collect();
+
+        let children = ser::children(element).unwrap_or_default();
+        if fields.contains(&"$value") {
+            entries.push((
+                Cow::Borrowed("$value"),
+                Entry::Nodes(children.iter().collect()),
+            ));
+        } else {
+            let text = NodeDeserializer { node: element }.text();
+            if !text.is_empty() {
+                entries.push((Cow::Borrowed("$text"), Entry::Value(text)));
+            }
+
+            let mut groups: Vec<(&'static str, Vec<&'de SsmlElement>)> = Vec::new();
+            for child in children {
+                if let SsmlElement::Text(_) = child {
+                    continue;
+                }
+                let name = child.kind().tag_name();
+                match groups.iter_mut().find(|(group, _)| *group == name) {
+                    Some((_, nodes)) => nodes.push(child),
+                    None => groups.push((name, vec![child])),
+                }
+            }
+            entries.extend(
+                groups
+                    .into_iter()
+                    .map(|(name, nodes)| (Cow::Borrowed(name), Entry::Nodes(nodes))),
+            );
+        }
+
+        Self {
+            entries: entries.into_iter(),
+            pending: None,
+        }
+    }
+}
+
+impl<'de> MapAccess<'de> for ElementMap<'de> {
+    type Error = DeError;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, DeError> {
+        let Some((key, entry)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.pending = Some(entry);
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<S: DeserializeSeed<'de>>(&mut self, seed: S) -> Result<S::Value, DeError> {
+        match self.pending.take() {
+            Some(Entry::Value(value)) => seed.deserialize(ValueDeserializer { value }),
+            Some(Entry::Nodes(nodes)) => seed.deserialize(Deserializer { nodes }),
+            None => Err(DeError::Custom("value requested before key".to_string())),
+        }
+    }
+}
+
+// Reads scalars from an attribute value or text content
+#[derive(Debug)]
+struct ValueDeserializer<'de> {
+    value: Cow<'de, str>,
+}
+
+macro_rules! deserialize_parse {
+    ($($method:ident => $visit:ident,)*) => {$(
+        fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+            match self.value.trim().parse() {
+                Ok(value) => visitor.$visit(value),
+                Err(_) => Err(de::Error::invalid_value(Unexpected::Str(&self.value), &visitor)),
+            }
+        }
+    )*};
+}
+
+impl<'de> de::Deserializer<'de> for ValueDeserializer<'de> {
+    type Error = DeError;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        match self.value {
+            Cow::Borrowed(value) => visitor.visit_borrowed_str(value),
+            Cow::Owned(value) => visitor.visit_string(value),
+        }
+    }
+
+    deserialize_parse! {
+        deserialize_bool => visit_bool,
+        deserialize_i8 => visit_i8,
+        deserialize_i16 => visit_i16,
+        deserialize_i32 => visit_i32,
+        deserialize_i64 => visit_i64,
+        deserialize_i128 => visit_i128,
+        deserialize_u8 => visit_u8,
+        deserialize_u16 => visit_u16,
+        deserialize_u32 => visit_u32,
+        deserialize_u64 => visit_u64,
+        deserialize_u128 => visit_u128,
+        deserialize_f32 => visit_f32,
+        deserialize_f64 => visit_f64,
+        deserialize_char => visit_char,
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_some(self)
+    }
+
+    fn deserialize_unit<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, DeError> {
+        visitor.visit_unit()
+    }
+
+    fn deserialize_newtype_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_enum<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        _: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, DeError> {
+        visitor.visit_enum(self.value.into_deserializer())
+    }
+
+    forward_to_deserialize_any! {
+        str string bytes byte_buf unit_struct seq tuple tuple_struct map struct identifier
+        ignored_any
+    }
+}
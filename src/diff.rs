@@ -0,0 +1,382 @@
+//! Structural differences between two documents.
+
+use std::fmt;
+
+use crate::{NodePath, SSML, SerializeOptions, SsmlElement, ser};
+
+/// A single difference between two documents, found by [`diff`].
+///
+/// Paths of removed nodes and the `from` path of moved nodes refer to the old document; all
+/// other paths refer to the new document.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Edit {
+    /// A node present only in the new document.
+    Insert {
+        /// Path of the node in the new document.
+        path: NodePath,
+        /// The inserted node.
+        node: SsmlElement,
+    },
+    /// A node present only in the old document.
+    Remove {
+        /// Path of the node in the old document.
+        path: NodePath,
+        /// The removed node.
+        node: SsmlElement,
+    },
+    /// A node that appears unchanged at another position.
+    Move {
+        /// Path of the node in the old document.
+        from: NodePath,
+        /// Path of the node in the new document.
+        to: NodePath,
+        /// The moved node.
+        node: SsmlElement,
+    },
+    /// An attribute that was added, removed or changed.
+    Attribute {
+        /// Path of the element in the new document.
+        path: NodePath,
+        /// Name of the attribute.
+        name: &'static str,
+        /// The old value, or `None` if the attribute was added.
+        old: Option<String>,
+        /// The new value, or `None` if the attribute was removed.
+        new: Option<String>,
+    },
+    /// A text node whose text changed.
+    Text {
+        /// Path of the text node in the new document.
+        path: NodePath,
+        /// The old text.
+        old: String,
+        /// The new text.
+        new: String,
+    },
+}
+
+/// The differences between two documents, created by [`diff`].
+///
+/// The [`Display`](fmt::Display) implementation renders one edit per line, prefixed with
+/// `+` for insertions, `-` for removals, `>` for moves and `~` for changes.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Diff {
+    edits: Vec<Edit>,
+}
+
+impl Diff {
+    /// Returns the edits in document order.
+    pub fn edits(&self) -> &[Edit] {
+        &self.edits
+    }
+
+    /// Returns whether the documents are equal.
+    pub fn is_empty(&self) -> bool {
+        self.edits.is_empty()
+    }
+
+    /// Returns the edits in document order.
+    pub fn into_edits(self) -> Vec<Edit> {
+        self.edits
+    }
+}
+
+impl IntoIterator for Diff {
+    type Item = Edit;
+    type IntoIter = std::vec::IntoIter<Edit>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.edits.into_iter()
+    }
+}
+
+impl fmt::Display for Edit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn value(value: &Option<String>) -> String {
+            value
+                .as_ref()
+                .map_or_else(|| "(none)".to_string(), |value| format!("{value:?}"))
+        }
+
+        match self {
+            Edit::Insert { path, node } => write!(f, "+ {path} {node}"),
+            Edit::Remove { path, node } => write!(f, "- {path} {node}"),
+            Edit::Move { from, to, node } => write!(f, "> {from} -> {to} {node}"),
+            Edit::Attribute {
+                path,
+                name,
+                old,
+                new,
+            } => write!(f, "~ {path} @{name}: {} -> {}", value(old), value(new)),
+            Edit::Text { path, old, new } => write!(f, "~ {path} {old:?} -> {new:?}"),
+        }
+    }
+}
+
+impl fmt::Display for Diff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no changes");
+        }
+        for (index, edit) in self.edits.iter().enumerate() {
+            if index > 0 {
+                f.write_str("\n")?;
+            }
+            write!(f, "{edit}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the structural differences between two documents.
+///
+/// Unchanged nodes are matched first. Between them, nodes of the same kind are paired up
+/// and compared attribute by attribute and child by child, and the remaining nodes are
+/// reported as removed or inserted, or as moved if an equal node was removed elsewhere.
+///
+/// # Examples
+///
+/// ```rust
+/// let old = serde_ssml::from_str(
+///     r#"<speak><s>Helo</s><prosody rate="slow">Later</prosody><break time="1s"/></speak>"#,
+/// )
+/// .unwrap();
+/// let new = serde_ssml::from_str(
+///     r#"<speak><break time="1s"/><s>Hello</s><prosody rate="fast">Later</prosody></speak>"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(
+///     serde_ssml::diff(&old, &new).to_string(),
+///     "> /0/2 -> /0/0 <break time=\"1000ms\"/>\n\
+///      ~ /0/1/0 \"Helo\" -> \"Hello\"\n\
+///      ~ /0/2 @rate: \"slow\" -> \"fast\""
+/// );
+/// ```
+pub fn diff(old: &SSML, new: &SSML) -> Diff {
+    let mut edits = Vec::new();
+    diff_nodes(
+        &old.elements,
+        &new.elements,
+        &NodePath::root(),
+        &NodePath::root(),
+        &mut edits,
+    );
+    Diff {
+        edits: detect_moves(edits),
+    }
+}
+
+fn diff_nodes(
+    old: &[SsmlElement],
+    new: &[SsmlElement],
+    old_path: &NodePath,
+    new_path: &NodePath,
+    edits: &mut Vec<Edit>,
+) {
+    for pair in align(old, new) {
+        match pair {
+            Aligned::Both(i, j) => diff_node(
+                &old[i],
+                &new[j],
+                &old_path.child(i),
+                &new_path.child(j),
+                edits,
+            ),
+            Aligned::Old(i) => edits.push(Edit::Remove {
+                path: old_path.child(i),
+                node: old[i].clone(),
+            }),
+            Aligned::New(j) => edits.push(Edit::Insert {
+                path: new_path.child(j),
+                node: new[j].clone(),
+            }),
+        }
+    }
+}
+
+// Compares two nodes of the same kind
+fn diff_node(
+    old: &SsmlElement,
+    new: &SsmlElement,
+    old_path: &NodePath,
+    new_path: &NodePath,
+    edits: &mut Vec<Edit>,
+) {
+    if old == new {
+        return;
+    }
+    if let (SsmlElement::Text(old), SsmlElement::Text(new)) = (old, new) {
+        edits.push(Edit::Text {
+            path: new_path.clone(),
+            old: old.clone(),
+            new: new.clone(),
+        });
+        return;
+    }
+
+    for (name, old, new) in attribute_changes(old, new) {
+        edits.push(Edit::Attribute {
+            path: new_path.clone(),
+            name,
+            old,
+            new,
+        });
+    }
+    diff_nodes(old.children(), new.children(), old_path, new_path, edits);
+}
+
+// Returns the attributes that differ between two elements of the same kind
+pub(crate) fn attribute_changes(
+    old: &SsmlElement,
+    new: &SsmlElement,
+) -> Vec<(&'static str, Option<String>, Option<String>)> {
+    new.kind()
+        .attributes()
+        .iter()
+        .filter_map(|&name| {
+            let (old, new) = (attribute(old, name), attribute(new, name));
+            (old != new).then_some((name, old, new))
+        })
+        .collect()
+}
+
+// Returns the value of an attribute as written in markup, if it is set
+pub(crate) fn attribute(element: &SsmlElement, name: &str) -> Option<String> {
+    ser::attributes(element, &SerializeOptions::default())
+        .into_iter()
+        .find(|(key, _)| *key == name)
+        .map(|(_, value)| value.into_owned())
+}
+
+// Replaces removals and insertions of equal nodes with moves, listed where the first of the
+// two was
+fn detect_moves(edits: Vec<Edit>) -> Vec<Edit> {
+    let mut edits: Vec<Option<Edit>> = edits.into_iter().map(Some).collect();
+    for index in 0..edits.len() {
+        let Some(Edit::Remove { node, .. }) = &edits[index] else {
+            continue;
+        };
+        let Some(insert) = edits.iter().position(
+            |edit| matches!(edit, Some(Edit::Insert { node: inserted, .. }) if inserted == node),
+        ) else {
+            continue;
+        };
+        if let (Some(Edit::Remove { path: from, node }), Some(Edit::Insert { path: to, .. })) =
+            (edits[index].take(), edits[insert].take())
+        {
+            edits[index.min(insert)] = Some(Edit::Move { from, to, node });
+        }
+    }
+    edits.into_iter().flatten().collect()
+}
+
+/// How a node of one sequence relates to the other, see [`align`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Aligned {
+    /// Nodes at these indices of the old and new sequence correspond.
+    Both(usize, usize),
+    /// The node at this index only appears in the old sequence.
+    Old(usize),
+    /// The node at this index only appears in the new sequence.
+    New(usize),
+}
+
+// Aligns two sequences of nodes in order, pairing nodes of the same kind. Equal nodes are
+// preferred, but not at the cost of pairing fewer nodes overall, which keeps a moved node
+// from displacing the nodes around it.
+//
+// Equal leading and trailing nodes are paired directly. Between them, time and memory are
+// quadratic in the number of nodes, and every pair of nodes of the same kind that differ is
+// compared in full, so long sequences with many changes scattered through them are slow.
+pub(crate) fn align(old: &[SsmlElement], new: &[SsmlElement]) -> Vec<Aligned> {
+    let prefix = old.iter().zip(new).take_while(|(a, b)| a == b).count();
+    let (old_rest, new_rest) = (&old[prefix..], &new[prefix..]);
+    let suffix = old_rest
+        .iter()
+        .rev()
+        .zip(new_rest.iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let (old_len, new_len) = (old_rest.len() - suffix, new_rest.len() - suffix);
+
+    let mut aligned: Vec<Aligned> = (0..prefix).map(|i| Aligned::Both(i, i)).collect();
+    aligned.extend(
+        align_changed(&old_rest[..old_len], &new_rest[..new_len])
+            .into_iter()
+            .map(|pair| match pair {
+                Aligned::Both(i, j) => Aligned::Both(prefix + i, prefix + j),
+                Aligned::Old(i) => Aligned::Old(prefix + i),
+                Aligned::New(j) => Aligned::New(prefix + j),
+            }),
+    );
+    aligned.extend((0..suffix).map(|k| Aligned::Both(prefix + old_len + k, prefix + new_len + k)));
+    aligned
+}
+
+// Aligns two sequences of nodes that differ at both ends
+fn align_changed(old: &[SsmlElement], new: &[SsmlElement]) -> Vec<Aligned> {
+    let score = |a: &SsmlElement, b: &SsmlElement| {
+        if a == b {
+            3
+        } else if a.kind() != b.kind() {
+            0
+        } else if is_close(a, b) {
+            2
+        } else {
+            1
+        }
+    };
+
+    // scores[i][j] is the best total score aligning old[i..] with new[j..]
+    let width = new.len() + 1;
+    let mut scores = vec![0usize; (old.len() + 1) * width];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            let skip = scores[(i + 1) * width + j].max(scores[i * width + j + 1]);
+            scores[i * width + j] = match score(&old[i], &new[j]) {
+                0 => skip,
+                pair => skip.max(scores[(i + 1) * width + j + 1] + pair),
+            };
+        }
+    }
+
+    let mut aligned = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        let pair = score(&old[i], &new[j]);
+        if pair > 0 && scores[i * width + j] == scores[(i + 1) * width + j + 1] + pair {
+            aligned.push(Aligned::Both(i, j));
+            i += 1;
+            j += 1;
+        } else if scores[i * width + j] == scores[(i + 1) * width + j] {
+            aligned.push(Aligned::Old(i));
+            i += 1;
+        } else {
+            aligned.push(Aligned::New(j));
+            j += 1;
+        }
+    }
+    aligned.extend((i..old.len()).map(Aligned::Old));
+    aligned.extend((j..new.len()).map(Aligned::New));
+    aligned
+}
+
+// Whether two different nodes of the same kind look like edits of each other: text, elements
+// whose attributes changed but not their content, and elements with the same attributes and
+// either the same text, a child in common or only text
+fn is_close(a: &SsmlElement, b: &SsmlElement) -> bool {
+    let (a_children, b_children) = (a.children(), b.children());
+    let only_text = |children: &[SsmlElement]| {
+        children
+            .iter()
+            .all(|child| matches!(child, SsmlElement::Text(_)))
+    };
+
+    matches!(a, SsmlElement::Text(_))
+        || a_children == b_children
+        || attribute_changes(a, b).is_empty()
+            && (a.texts().eq(b.texts())
+                || a_children.iter().any(|child| b_children.contains(child))
+                || only_text(a_children) && only_text(b_children))
+}
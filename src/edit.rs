@@ -0,0 +1,163 @@
+//! Structural edits that remove, replace or unwrap nodes anywhere in a document.
+
+use std::mem;
+
+use crate::{SSML, SsmlElement};
+
+impl SSML {
+    /// Removes every node for which `keep` returns `false`, together with its subtree.
+    ///
+    /// Nodes are visited in document order, and the descendants of a removed node are not
+    /// visited. Text on both sides of a removed node is joined as it is, so parse with
+    /// [`Whitespace::Preserve`] or [`Whitespace::Collapse`] to keep the whitespace around it.
+    ///
+    /// [`Whitespace::Preserve`]: crate::Whitespace::Preserve
+    /// [`Whitespace::Collapse`]: crate::Whitespace::Collapse
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{Parser, SsmlElement, Whitespace};
+    ///
+    /// let mut ssml = Parser::new()
+    ///     .whitespace(Whitespace::Collapse)
+    ///     .parse(r#"<speak>Hello <audio src="chime.wav"><desc>Chime</desc></audio>world</speak>"#)
+    ///     .unwrap();
+    /// ssml.retain(|element| !matches!(element, SsmlElement::Audio { .. }));
+    ///
+    /// assert_eq!(ssml.to_string(), "<speak>Hello world</speak>");
+    /// ```
+    pub fn retain<F>(&mut self, mut keep: F)
+    where
+        F: FnMut(&SsmlElement) -> bool,
+    {
+        retain(&mut self.elements, &mut keep);
+    }
+
+    /// Replaces every node with the nodes returned by `f`, which may be none, the node
+    /// itself, or any number of other nodes.
+    ///
+    /// The tree is rebuilt bottom-up: `f` receives each node after its children have been
+    /// replaced, and the nodes it returns are not passed to `f` again.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SsmlElement;
+    ///
+    /// let mut ssml = serde_ssml::from_str(r#"<speak><mark name="a"/>Hi</speak>"#).unwrap();
+    /// ssml.flat_map(|element| match element {
+    ///     SsmlElement::Mark { name } => vec![
+    ///         SsmlElement::Mark { name: format!("{name}-start") },
+    ///         SsmlElement::Mark { name: format!("{name}-end") },
+    ///     ],
+    ///     element => vec![element],
+    /// });
+    ///
+    /// assert_eq!(
+    ///     ssml.to_string(),
+    ///     r#"<speak><mark name="a-start"/><mark name="a-end"/>Hi</speak>"#
+    /// );
+    /// ```
+    pub fn flat_map<F, I>(&mut self, mut f: F)
+    where
+        F: FnMut(SsmlElement) -> I,
+        I: IntoIterator<Item = SsmlElement>,
+    {
+        flat_map(&mut self.elements, &mut f);
+    }
+
+    /// Replaces every element for which `unwrap` returns `true` with its children.
+    ///
+    /// Matching elements without children, such as `<break>`, are removed. Text nodes are
+    /// content rather than markup and are never passed to `unwrap`. Nested matches are all
+    /// unwrapped. Text inside and around an unwrapped element is joined as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{ElementKind, Parser, Whitespace};
+    ///
+    /// let mut ssml = Parser::new()
+    ///     .whitespace(Whitespace::Collapse)
+    ///     .parse(
+    ///         r#"<speak><prosody rate="slow"><s>Slowly <emphasis>now</emphasis></s></prosody></speak>"#,
+    ///     )
+    ///     .unwrap();
+    /// ssml.unwrap_where(|element| {
+    ///     matches!(element.kind(), ElementKind::Prosody | ElementKind::Emphasis)
+    /// });
+    ///
+    /// assert_eq!(ssml.to_string(), "<speak><s>Slowly now</s></speak>");
+    /// ```
+    pub fn unwrap_where<F>(&mut self, mut unwrap: F)
+    where
+        F: FnMut(&SsmlElement) -> bool,
+    {
+        unwrap_where(&mut self.elements, &mut unwrap);
+    }
+}
+
+fn retain<F>(nodes: &mut Vec<SsmlElement>, keep: &mut F)
+where
+    F: FnMut(&SsmlElement) -> bool,
+{
+    let mut removed = false;
+    for mut node in mem::take(nodes) {
+        if !keep(&node) {
+            removed = true;
+            continue;
+        }
+        if let Some(children) = node.children_mut() {
+            retain(children, keep);
+        }
+        push(nodes, node, removed);
+        removed = false;
+    }
+}
+
+fn flat_map<F, I>(nodes: &mut Vec<SsmlElement>, f: &mut F)
+where
+    F: FnMut(SsmlElement) -> I,
+    I: IntoIterator<Item = SsmlElement>,
+{
+    for mut node in mem::take(nodes) {
+        if let Some(children) = node.children_mut() {
+            flat_map(children, f);
+        }
+        nodes.extend(f(node));
+    }
+}
+
+fn unwrap_where<F>(nodes: &mut Vec<SsmlElement>, unwrap: &mut F)
+where
+    F: FnMut(&SsmlElement) -> bool,
+{
+    let mut unwrapped = false;
+    for mut node in mem::take(nodes) {
+        if let Some(children) = node.children_mut() {
+            unwrap_where(children, unwrap);
+        }
+        if matches!(node, SsmlElement::Text(_)) || !unwrap(&node) {
+            push(nodes, node, unwrapped);
+            unwrapped = false;
+        } else {
+            let children = node.children_mut().map(mem::take).unwrap_or_default();
+            for child in children {
+                push(nodes, child, true);
+            }
+            unwrapped = true;
+        }
+    }
+}
+
+// Appends a node, joining it to preceding text if both are text and `join` is set, e.g.
+// because markup between them was removed
+fn push(nodes: &mut Vec<SsmlElement>, node: SsmlElement, join: bool) {
+    match (nodes.last_mut(), node) {
+        (Some(SsmlElement::Text(last)), SsmlElement::Text(text)) if join => {
+            last.push_str(&text);
+        }
+        (_, node) => nodes.push(node),
+    }
+}
@@ -0,0 +1,215 @@
+use std::{fmt, ops::Range};
+
+use crate::ElementKind;
+
+/// An error encountered while parsing SSML.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::ErrorKind;
+///
+/// let errors = serde_ssml::from_str("<speak>Unclosed tag").unwrap_err();
+/// assert_eq!(errors[0].kind(), &ErrorKind::UnclosedElement("speak".to_string()));
+/// assert_eq!(errors[0].span(), 0..1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    kind: ErrorKind,
+    span: Range<usize>,
+}
+
+/// The reason an SSML document failed to parse.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ErrorKind {
+    /// The input ended in the middle of a tag or attribute.
+    UnexpectedEof {
+        /// The character that was expected, if a specific one was.
+        expected: Option<char>,
+    },
+
+    /// A character other than the expected one was found.
+    UnexpectedChar {
+        /// The character that was expected, if a specific one was.
+        expected: Option<char>,
+        /// The character that was found.
+        found: char,
+    },
+
+    /// An element name that is not part of the supported SSML vocabulary.
+    UnknownElement(String),
+
+    /// A closing tag that does not match the innermost open element.
+    MismatchedClosingTag {
+        /// Name of the innermost open element.
+        expected: String,
+        /// Name found in the closing tag.
+        found: String,
+    },
+
+    /// A closing tag without a matching open element.
+    UnexpectedClosingTag(String),
+
+    /// An element that was still open at the end of the input.
+    UnclosedElement(String),
+
+    /// Content inside an element that cannot have children (`<break>`, `<mark>`, `<lexicon>`).
+    UnexpectedContent(String),
+
+    /// The input exceeds one of the configured [`crate::ParseOptions`] limits.
+    LimitExceeded(Limit),
+
+    /// An attribute value the document model cannot represent, reported in strict mode.
+    InvalidAttribute {
+        /// Name of the element carrying the attribute.
+        element: String,
+        /// Name of the attribute.
+        attribute: String,
+        /// The rejected value.
+        value: String,
+    },
+
+    /// An element missing a required attribute, reported in strict mode.
+    MissingAttribute {
+        /// Name of the element.
+        element: String,
+        /// Name of the missing attribute.
+        attribute: String,
+    },
+
+    /// The document is not a single `<speak>` element, see [`crate::Parser::require_root`].
+    InvalidRoot,
+
+    /// A `<speak>` element without the SSML namespace, see [`crate::Namespaces::Require`].
+    MissingNamespace,
+
+    /// An element in a fragment that SSML does not allow inside its parent, see
+    /// [`crate::Parser::parse_fragment`].
+    InvalidChild {
+        /// Kind of the parent element, or of the fragment context for top-level nodes.
+        parent: ElementKind,
+        /// Kind of the rejected element.
+        child: ElementKind,
+    },
+
+    /// Input parsed as a single [`crate::SsmlElement`] held a different number of top-level
+    /// nodes.
+    ExpectedSingleElement {
+        /// Number of top-level nodes found.
+        found: usize,
+    },
+}
+
+/// A resource limit from [`crate::ParseOptions`], carrying its configured maximum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Limit {
+    /// Maximum nesting depth of elements.
+    Depth(usize),
+    /// Maximum number of elements in the document.
+    Elements(usize),
+    /// Maximum length of the input in bytes.
+    InputLength(usize),
+    /// Maximum length of a single text run in bytes.
+    TextLength(usize),
+    /// Maximum number of attributes on a single element.
+    Attributes(usize),
+}
+
+impl Error {
+    pub(crate) fn new(kind: ErrorKind, span: Range<usize>) -> Self {
+        Self { kind, span }
+    }
+
+    /// Returns the reason the input failed to parse.
+    pub fn kind(&self) -> &ErrorKind {
+        &self.kind
+    }
+
+    /// Returns the byte range of the input where the error was detected.
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.kind, self.span.start)
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ErrorKind::UnexpectedEof {
+                expected: Some(expected),
+            } => write!(f, "unexpected end of input, expected '{expected}'"),
+            ErrorKind::UnexpectedEof { expected: None } => write!(f, "unexpected end of input"),
+            ErrorKind::UnexpectedChar {
+                expected: Some(expected),
+                found,
+            } => write!(f, "expected '{expected}', found '{found}'"),
+            ErrorKind::UnexpectedChar {
+                expected: None,
+                found,
+            } => write!(f, "unexpected '{found}'"),
+            ErrorKind::UnknownElement(name) => write!(f, "unknown element <{name}>"),
+            ErrorKind::MismatchedClosingTag { expected, found } => {
+                write!(f, "expected </{expected}>, found </{found}>")
+            }
+            ErrorKind::UnexpectedClosingTag(name) => write!(f, "unexpected closing tag </{name}>"),
+            ErrorKind::UnclosedElement(name) => write!(f, "unclosed element <{name}>"),
+            ErrorKind::UnexpectedContent(name) => {
+                write!(f, "element <{name}> cannot have content")
+            }
+            ErrorKind::LimitExceeded(limit) => write!(f, "{limit}"),
+            ErrorKind::InvalidAttribute {
+                element,
+                attribute,
+                value,
+            } => write!(
+                f,
+                "invalid value \"{value}\" for <{element}> attribute {attribute}"
+            ),
+            ErrorKind::MissingAttribute { element, attribute } => {
+                write!(
+                    f,
+                    "element <{element}> is missing required attribute {attribute}"
+                )
+            }
+            ErrorKind::InvalidRoot => write!(f, "expected a single <speak> root element"),
+            ErrorKind::MissingNamespace => {
+                write!(f, "<speak> does not declare the SSML namespace")
+            }
+            ErrorKind::InvalidChild { parent, child } => write!(
+                f,
+                "<{}> is not allowed inside <{}>",
+                child.tag_name(),
+                parent.tag_name()
+            ),
+            ErrorKind::ExpectedSingleElement { found } => {
+                write!(f, "expected a single element, found {found} nodes")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Limit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Limit::Depth(max) => write!(f, "nesting depth exceeds the limit of {max}"),
+            Limit::Elements(max) => write!(f, "element count exceeds the limit of {max}"),
+            Limit::InputLength(max) => write!(f, "input length exceeds the limit of {max} bytes"),
+            Limit::TextLength(max) => write!(f, "text length exceeds the limit of {max} bytes"),
+            Limit::Attributes(max) => write!(f, "attribute count exceeds the limit of {max}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+/// Represents a parse error, containing the list of errors encountered.
+///
+/// Parsing stops at the first error, so the list holds exactly one entry.
+pub type ParseError = Vec<Error>;
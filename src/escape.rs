@@ -0,0 +1,135 @@
+use std::borrow::Cow;
+
+/// Decodes XML character and entity references (`&amp;`, `&#233;`, `&#x1F600;`, ...).
+///
+/// Returns the input unchanged (borrowed) when it contains no `&`. Unknown or malformed
+/// references are kept verbatim rather than rejected.
+pub(crate) fn unescape(input: &str) -> Cow<'_, str> {
+    if !input.contains('&') {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len());
+    let mut rest = input;
+
+    while let Some(amp) = rest.find('&') {
+        output.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        // A reference cannot contain `&`, so the search for `;` stops at the next one; this
+        // keeps decoding linear in the input length
+        let decoded = rest[1..]
+            .find(['&', ';'])
+            .map(|end| end + 1)
+            .filter(|&end| rest.as_bytes()[end] == b';')
+            .and_then(|semi| decode_reference(&rest[1..semi]).map(|c| (c, semi)));
+
+        match decoded {
+            Some((c, semi)) => {
+                output.push(c);
+                rest = &rest[semi + 1..];
+            }
+            None => {
+                output.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+
+    output.push_str(rest);
+    Cow::Owned(output)
+}
+
+fn decode_reference(reference: &str) -> Option<char> {
+    match reference {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        _ => {
+            let code = reference.strip_prefix('#')?;
+            let code = match code.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16).ok()?,
+                None => code.parse().ok()?,
+            };
+            char::from_u32(code)
+        }
+    }
+}
+
+/// Escapes text content so it can be embedded between tags.
+pub(crate) fn escape_text(input: &str) -> Cow<'_, str> {
+    escape(input, |c| matches!(c, '&' | '<' | '>'))
+}
+
+/// Escapes an attribute value so it can be embedded in `quote` characters (`"` or `'`).
+pub(crate) fn escape_attr(input: &str, quote: char) -> Cow<'_, str> {
+    escape(input, |c| matches!(c, '&' | '<') || c == quote)
+}
+
+fn escape(input: &str, needs_escape: impl Fn(char) -> bool) -> Cow<'_, str> {
+    if !input.contains(&needs_escape) {
+        return Cow::Borrowed(input);
+    }
+
+    let mut output = String::with_capacity(input.len() + 8);
+    for c in input.chars() {
+        match c {
+            '&' if needs_escape(c) => output.push_str("&amp;"),
+            '<' if needs_escape(c) => output.push_str("&lt;"),
+            '>' if needs_escape(c) => output.push_str("&gt;"),
+            '"' if needs_escape(c) => output.push_str("&quot;"),
+            '\'' if needs_escape(c) => output.push_str("&apos;"),
+            _ => output.push(c),
+        }
+    }
+    Cow::Owned(output)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unescape_borrows_plain_text() {
+        assert!(matches!(
+            unescape("plain text"),
+            Cow::Borrowed("plain text")
+        ));
+    }
+
+    #[test]
+    fn test_unescape_references() {
+        assert_eq!(unescape("Tom &amp; Jerry"), "Tom & Jerry");
+        assert_eq!(unescape("&lt;speak&gt;"), "<speak>");
+        assert_eq!(unescape("&quot;hi&apos;"), "\"hi'");
+        assert_eq!(unescape("caf&#233; &#x1F600;"), "café 😀");
+    }
+
+    #[test]
+    fn test_unescape_keeps_unknown_references() {
+        assert_eq!(unescape("AT&T"), "AT&T");
+        assert_eq!(unescape("&nbsp; &#xZZ;"), "&nbsp; &#xZZ;");
+        assert_eq!(unescape("&&amp;"), "&&");
+    }
+
+    #[test]
+    fn test_unescape_many_ampersands() {
+        let input = "&".repeat(1_000_000);
+        let start = std::time::Instant::now();
+        assert_eq!(unescape(&input), input);
+        assert!(start.elapsed() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape_text("a < b & c > d"), "a &lt; b &amp; c &gt; d");
+        assert_eq!(
+            escape_attr("say \"hi\" & <bye>", '"'),
+            "say &quot;hi&quot; &amp; &lt;bye>"
+        );
+        assert_eq!(escape_attr("it's \"ok\"", '\''), "it&apos;s \"ok\"");
+        assert!(matches!(escape_text("plain"), Cow::Borrowed("plain")));
+    }
+}
@@ -1,4 +1,4 @@
-use crate::SsmlElement;
+use crate::{ElementKind, SsmlElement};
 
 /// Generic visitor function for finding and modifying SSML elements
 ///
@@ -24,32 +24,11 @@ where
         modified = true;
     }
 
-    // Recursively process child elements
-    match element {
-        SsmlElement::Voice { children, .. }
-        | SsmlElement::Speak { children, .. }
-        | SsmlElement::Paragraph { children, .. }
-        | SsmlElement::Sentence { children, .. }
-        | SsmlElement::Phoneme { children, .. }
-        | SsmlElement::SayAs { children, .. }
-        | SsmlElement::Sub { children, .. }
-        | SsmlElement::Prosody { children, .. }
-        | SsmlElement::Emphasis { children, .. }
-        | SsmlElement::Audio { children, .. }
-        | SsmlElement::Desc { children, .. }
-        | SsmlElement::Lang { children, .. } => {
-            // Process each child element
-            for child in children.iter_mut() {
-                if visit_mut(child, predicate, modifier) {
-                    modified = true;
-                }
-            }
+    // Recursively process child elements; elements without children have none to process
+    for child in element.children_mut().into_iter().flatten() {
+        if visit_mut(child, predicate, modifier) {
+            modified = true;
         }
-        // Elements without children don't need processing
-        SsmlElement::Break { .. }
-        | SsmlElement::Mark { .. }
-        | SsmlElement::LexiconUri { .. }
-        | SsmlElement::Text(_) => {}
     }
 
     modified
@@ -64,40 +43,75 @@ where
 /// # Arguments
 ///
 /// * `ssml` - The SSML document to modify
-/// * `element_type` - The type of element to find (e.g., "Voice", "Break", "Emphasis")
+/// * `element_type` - The type of element to find, either as variant name (e.g. "Voice",
+///   "SayAs") or as SSML tag name (e.g. "voice", "say-as"), see [`ElementKind`]'s `FromStr`
 /// * `modifier` - Function to apply to matching elements
 ///
 /// # Returns
 ///
-/// * `bool` - Whether any elements were modified
+/// * `bool` - Whether any elements were modified; always `false` for an unknown type name,
+///   so prefer [`find_and_modify_kind`] when the type is known at compile time
 pub fn find_and_modify_elements<F>(ssml: &mut crate::SSML, element_type: &str, modifier: F) -> bool
+where
+    F: Fn(&mut SsmlElement),
+{
+    match element_type.parse() {
+        Ok(kind) => find_and_modify_kind(ssml, kind, modifier),
+        Err(()) => false,
+    }
+}
+
+/// Find all elements of the given kind and apply a modification
+///
+/// # Arguments
+///
+/// * `ssml` - The SSML document to modify
+/// * `kind` - The kind of element to find
+/// * `modifier` - Function to apply to matching elements
+///
+/// # Returns
+///
+/// * `bool` - Whether any elements were modified
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{ElementKind, SsmlElement};
+///
+/// let mut ssml = serde_ssml::from_str("<speak>Hi <break/> there</speak>").unwrap();
+/// let modified = serde_ssml::find_and_modify_kind(&mut ssml, ElementKind::Break, |element| {
+///     *element = SsmlElement::Mark { name: "pause".to_string() };
+/// });
+///
+/// assert!(modified);
+/// assert_eq!(serde_ssml::to_string(&ssml), r#"<speak>Hi<mark name="pause"/>there</speak>"#);
+/// ```
+pub fn find_and_modify_kind<F>(ssml: &mut crate::SSML, kind: ElementKind, modifier: F) -> bool
+where
+    F: Fn(&mut SsmlElement),
+{
+    find_and_modify_kinds(ssml, &[kind], modifier)
+}
+
+/// Find all elements of any of the given kinds and apply a modification
+///
+/// # Arguments
+///
+/// * `ssml` - The SSML document to modify
+/// * `kinds` - The kinds of element to find
+/// * `modifier` - Function to apply to matching elements
+///
+/// # Returns
+///
+/// * `bool` - Whether any elements were modified
+pub fn find_and_modify_kinds<F>(ssml: &mut crate::SSML, kinds: &[ElementKind], modifier: F) -> bool
 where
     F: Fn(&mut SsmlElement),
 {
     let mut modified = false;
 
-    // Create a predicate that checks for the specified element type
-    let predicate = |element: &SsmlElement| {
-        matches!(
-            (element_type, element),
-            ("Voice", SsmlElement::Voice { .. })
-                | ("Speak", SsmlElement::Speak { .. })
-                | ("Paragraph", SsmlElement::Paragraph { .. })
-                | ("Sentence", SsmlElement::Sentence { .. })
-                | ("Phoneme", SsmlElement::Phoneme { .. })
-                | ("SayAs", SsmlElement::SayAs { .. })
-                | ("Sub", SsmlElement::Sub { .. })
-                | ("Prosody", SsmlElement::Prosody { .. })
-                | ("Emphasis", SsmlElement::Emphasis { .. })
-                | ("Break", SsmlElement::Break { .. })
-                | ("Mark", SsmlElement::Mark { .. })
-                | ("Audio", SsmlElement::Audio { .. })
-                | ("Desc", SsmlElement::Desc { .. })
-                | ("LexiconUri", SsmlElement::LexiconUri { .. })
-                | ("Lang", SsmlElement::Lang { .. })
-                | ("Text", SsmlElement::Text(_))
-        )
-    };
+    // Create a predicate that checks for the specified element kinds
+    let predicate = |element: &SsmlElement| kinds.contains(&element.kind());
 
     // Apply to all top-level elements
     for element in ssml.elements.iter_mut() {
@@ -157,4 +171,44 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_find_by_kind_and_name() {
+        let markup =
+            "<speak><s>One</s><p><s>Two</s></p><say-as interpret-as=\"date\">3</say-as></speak>";
+        let clear = |element: &mut SsmlElement| {
+            if let Some(children) = element.children_mut() {
+                children.clear();
+            }
+        };
+
+        let mut ssml = crate::from_str(markup).unwrap();
+        assert!(find_and_modify_kind(
+            &mut ssml,
+            ElementKind::Sentence,
+            clear
+        ));
+        assert_eq!(
+            crate::to_string(&ssml),
+            "<speak><s></s><p><s></s></p><say-as interpret-as=\"date\">3</say-as></speak>"
+        );
+
+        for name in ["SayAs", "say-as"] {
+            let mut ssml = crate::from_str(markup).unwrap();
+            assert!(find_and_modify_elements(&mut ssml, name, clear));
+            assert!(!crate::to_string(&ssml).contains(">3<"));
+        }
+
+        let mut ssml = crate::from_str(markup).unwrap();
+        assert!(!find_and_modify_elements(&mut ssml, "sayas", clear));
+        assert!(find_and_modify_kinds(
+            &mut ssml,
+            &[ElementKind::Paragraph, ElementKind::SayAs],
+            clear
+        ));
+        assert_eq!(
+            crate::to_string(&ssml),
+            "<speak><s>One</s><p></p><say-as interpret-as=\"date\"></say-as></speak>"
+        );
+    }
 }
@@ -0,0 +1,414 @@
+//! Iterators over the nodes of a document.
+//!
+//! Depth-first iterators visit nodes in document order (pre-order), breadth-first iterators
+//! visit them level by level. Depths count from `0` for the nodes the walk starts from, i.e.
+//! the top-level nodes of an [`SSML`] document or the children of an [`SsmlElement`]. Paths
+//! are the child indices leading from those nodes to the yielded one. [`Ancestors`] and
+//! [`Siblings`] walk outwards and sideways from the node at a [`NodePath`].
+//!
+//! Only [`TextsMut`] and [`SiblingsMut`] have mutable counterparts among the walks, as the
+//! others would hand out an element and its descendants at the same time; use
+//! [`crate::visit::VisitMut`] to modify elements during a traversal.
+
+use std::{collections::VecDeque, iter::Enumerate, slice};
+
+use crate::{NodePath, SSML, SsmlElement, path};
+
+/// Depth-first iterator over nodes, created by [`SSML::descendants`] and
+/// [`SsmlElement::descendants`].
+#[derive(Debug, Clone)]
+pub struct Descendants<'a>(DepthFirst<'a>);
+
+impl<'a> Iterator for Descendants<'a> {
+    type Item = &'a SsmlElement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(|(_, element)| element)
+    }
+}
+
+/// Depth-first iterator over nodes and their depth, created by [`SSML::dfs`] and
+/// [`SsmlElement::dfs`].
+#[derive(Debug, Clone)]
+pub struct DepthFirst<'a> {
+    stack: Vec<Enumerate<slice::Iter<'a, SsmlElement>>>,
+    path: Vec<usize>,
+}
+
+impl<'a> DepthFirst<'a> {
+    fn new(nodes: &'a [SsmlElement]) -> Self {
+        DepthFirst {
+            stack: vec![nodes.iter().enumerate()],
+            path: Vec::new(),
+        }
+    }
+
+    /// Returns the path of the node returned by the last call to `next`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let ssml = serde_ssml::from_str("<speak><p><s>Hi</s></p></speak>").unwrap();
+    /// let mut nodes = ssml.dfs();
+    ///
+    /// let _ = nodes.nth(2);
+    /// assert_eq!(nodes.path(), [0, 0, 0]);
+    /// ```
+    pub fn path(&self) -> &[usize] {
+        &self.path
+    }
+}
+
+impl<'a> Iterator for DepthFirst<'a> {
+    type Item = (usize, &'a SsmlElement);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let depth = self.stack.len().checked_sub(1)?;
+            let Some((index, element)) = self.stack[depth].next() else {
+                let _ = self.stack.pop();
+                continue;
+            };
+
+            self.path.truncate(depth);
+            self.path.push(index);
+            let children = element.children();
+            if !children.is_empty() {
+                self.stack.push(children.iter().enumerate());
+            }
+            return Some((depth, element));
+        }
+    }
+}
+
+/// Depth-first iterator over nodes and their path, created by [`SSML::dfs_paths`] and
+/// [`SsmlElement::dfs_paths`].
+#[derive(Debug, Clone)]
+pub struct DepthFirstPaths<'a>(DepthFirst<'a>);
+
+impl<'a> Iterator for DepthFirstPaths<'a> {
+    type Item = (NodePath, &'a SsmlElement);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (_, element) = self.0.next()?;
+        Some((NodePath::from(self.0.path()), element))
+    }
+}
+
+/// Breadth-first iterator over nodes and their depth, created by [`SSML::bfs`] and
+/// [`SsmlElement::bfs`].
+#[derive(Debug, Clone)]
+pub struct BreadthFirst<'a> {
+    queue: VecDeque<(usize, &'a SsmlElement)>,
+}
+
+impl<'a> BreadthFirst<'a> {
+    fn new(nodes: &'a [SsmlElement]) -> Self {
+        BreadthFirst {
+            queue: nodes.iter().map(|element| (0, element)).collect(),
+        }
+    }
+}
+
+impl<'a> Iterator for BreadthFirst<'a> {
+    type Item = (usize, &'a SsmlElement);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (depth, element) = self.queue.pop_front()?;
+        self.queue
+            .extend(element.children().iter().map(|child| (depth + 1, child)));
+        Some((depth, element))
+    }
+}
+
+/// Iterator over text content in document order, created by [`SSML::texts`] and
+/// [`SsmlElement::texts`].
+#[derive(Debug, Clone)]
+pub struct Texts<'a>(DepthFirst<'a>);
+
+impl<'a> Iterator for Texts<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.find_map(|(_, element)| match element {
+            SsmlElement::Text(text) => Some(text.as_str()),
+            _ => None,
+        })
+    }
+}
+
+/// Mutable iterator over text content in document order, created by [`SSML::texts_mut`] and
+/// [`SsmlElement::texts_mut`].
+#[derive(Debug)]
+pub struct TextsMut<'a> {
+    stack: Vec<slice::IterMut<'a, SsmlElement>>,
+}
+
+impl<'a> Iterator for TextsMut<'a> {
+    type Item = &'a mut String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(element) = self.stack.last_mut()?.next() else {
+                let _ = self.stack.pop();
+                continue;
+            };
+
+            match element {
+                SsmlElement::Text(text) => return Some(text),
+                element => {
+                    if let Some(children) = element.children_mut() {
+                        self.stack.push(children.iter_mut());
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Iterator over the ancestors of a node, nearest first, created by [`SSML::ancestors`].
+#[derive(Debug, Clone)]
+pub struct Ancestors<'a> {
+    chain: Vec<&'a SsmlElement>,
+}
+
+impl<'a> Iterator for Ancestors<'a> {
+    type Item = &'a SsmlElement;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chain.pop()
+    }
+}
+
+/// Iterator over the other children of a node's parent and their index, created by
+/// [`SSML::siblings`].
+#[derive(Debug, Clone)]
+pub struct Siblings<'a> {
+    nodes: Enumerate<slice::Iter<'a, SsmlElement>>,
+    index: usize,
+}
+
+impl<'a> Iterator for Siblings<'a> {
+    type Item = (usize, &'a SsmlElement);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.find(|(index, _)| *index != self.index)
+    }
+}
+
+/// Mutable iterator over the other children of a node's parent and their index, created by
+/// [`SSML::siblings_mut`].
+#[derive(Debug)]
+pub struct SiblingsMut<'a> {
+    nodes: Enumerate<slice::IterMut<'a, SsmlElement>>,
+    index: usize,
+}
+
+impl<'a> Iterator for SiblingsMut<'a> {
+    type Item = (usize, &'a mut SsmlElement);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nodes.find(|(index, _)| *index != self.index)
+    }
+}
+
+impl SSML {
+    /// Returns an iterator over all nodes of the document in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SsmlElement;
+    ///
+    /// let ssml = serde_ssml::from_str(
+    ///     r#"<speak><s>A <emphasis level="strong">big</emphasis> deal</s></speak>"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let emphasized = ssml
+    ///     .descendants()
+    ///     .find(|element| matches!(element, SsmlElement::Emphasis { .. }))
+    ///     .map(|element| element.texts().collect::<String>());
+    /// assert_eq!(emphasized.as_deref(), Some("big"));
+    /// ```
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants(self.dfs())
+    }
+
+    /// Returns a depth-first iterator over all nodes of the document and their depth.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::ElementKind;
+    ///
+    /// let ssml = serde_ssml::from_str("<speak><p><s>Hi</s></p></speak>").unwrap();
+    /// let nodes: Vec<_> = ssml.dfs().map(|(depth, element)| (depth, element.kind())).collect();
+    ///
+    /// assert_eq!(
+    ///     nodes,
+    ///     [
+    ///         (0, ElementKind::Speak),
+    ///         (1, ElementKind::Paragraph),
+    ///         (2, ElementKind::Sentence),
+    ///         (3, ElementKind::Text),
+    ///     ]
+    /// );
+    /// ```
+    pub fn dfs(&self) -> DepthFirst<'_> {
+        DepthFirst::new(&self.elements)
+    }
+
+    /// Returns a depth-first iterator over all nodes of the document and their path.
+    pub fn dfs_paths(&self) -> DepthFirstPaths<'_> {
+        DepthFirstPaths(self.dfs())
+    }
+
+    /// Returns a breadth-first iterator over all nodes of the document and their depth.
+    pub fn bfs(&self) -> BreadthFirst<'_> {
+        BreadthFirst::new(&self.elements)
+    }
+
+    /// Returns an iterator over the text content of the document in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let ssml = serde_ssml::from_str(r#"<speak>Hello <break/> world</speak>"#).unwrap();
+    ///
+    /// assert_eq!(ssml.texts().collect::<Vec<_>>(), ["Hello", "world"]);
+    /// ```
+    pub fn texts(&self) -> Texts<'_> {
+        Texts(self.dfs())
+    }
+
+    /// Returns a mutable iterator over the text content of the document in document order.
+    pub fn texts_mut(&mut self) -> TextsMut<'_> {
+        TextsMut {
+            stack: vec![self.elements.iter_mut()],
+        }
+    }
+
+    /// Returns an iterator over the elements containing the node at `path`, from its parent
+    /// up to the top-level node. It is empty if there is no node at `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{ElementKind, NodePath};
+    ///
+    /// let ssml = serde_ssml::from_str("<speak><p><s>Hi</s></p></speak>").unwrap();
+    /// let kinds: Vec<_> = ssml
+    ///     .ancestors(&NodePath::from([0, 0, 0, 0]))
+    ///     .map(|element| element.kind())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     kinds,
+    ///     [ElementKind::Sentence, ElementKind::Paragraph, ElementKind::Speak]
+    /// );
+    /// ```
+    pub fn ancestors(&self, path: &NodePath) -> Ancestors<'_> {
+        let mut chain = Vec::new();
+        let mut nodes = self.elements.as_slice();
+        for &index in path.indices() {
+            let Some(node) = nodes.get(index) else {
+                chain.clear();
+                break;
+            };
+            chain.push(node);
+            nodes = node.children();
+        }
+        let _ = chain.pop();
+        Ancestors { chain }
+    }
+
+    /// Returns an iterator over the other children of the parent of the node at `path` and
+    /// their index, in document order. It is empty if there is no node at `path`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{ElementKind, NodePath};
+    ///
+    /// let ssml = serde_ssml::from_str("<speak><s>One</s><break/><s>Two</s></speak>").unwrap();
+    /// let siblings: Vec<_> = ssml
+    ///     .siblings(&NodePath::from([0, 1]))
+    ///     .map(|(index, element)| (index, element.kind()))
+    ///     .collect();
+    ///
+    /// assert_eq!(siblings, [(0, ElementKind::Sentence), (2, ElementKind::Sentence)]);
+    /// ```
+    pub fn siblings(&self, path: &NodePath) -> Siblings<'_> {
+        let nodes = match path.indices() {
+            [index] if *index < self.elements.len() => self.elements.as_slice(),
+            [.., index] => match self.parent_of(path) {
+                Some(parent) if *index < parent.children().len() => parent.children(),
+                _ => &[],
+            },
+            [] => &[],
+        };
+        Siblings {
+            nodes: nodes.iter().enumerate(),
+            index: path.indices().last().copied().unwrap_or_default(),
+        }
+    }
+
+    /// Returns a mutable iterator over the other children of the parent of the node at
+    /// `path` and their index, in document order. It is empty if there is no node at `path`.
+    pub fn siblings_mut(&mut self, path: &NodePath) -> SiblingsMut<'_> {
+        let (index, nodes) = match path::siblings_mut(&mut self.elements, path.indices()) {
+            Some((index, nodes)) if index < nodes.len() => (index, nodes.as_mut_slice()),
+            _ => (0, &mut [][..]),
+        };
+        SiblingsMut {
+            nodes: nodes.iter_mut().enumerate(),
+            index,
+        }
+    }
+}
+
+impl SsmlElement {
+    /// Returns the child elements, or an empty slice for elements without children.
+    pub fn children(&self) -> &[SsmlElement] {
+        crate::ser::children(self).unwrap_or_default()
+    }
+
+    /// Returns an iterator over the descendants of this element in document order, excluding
+    /// the element itself.
+    pub fn descendants(&self) -> Descendants<'_> {
+        Descendants(self.dfs())
+    }
+
+    /// Returns a depth-first iterator over the descendants of this element and their depth,
+    /// starting at `0` for the children.
+    pub fn dfs(&self) -> DepthFirst<'_> {
+        DepthFirst::new(self.children())
+    }
+
+    /// Returns a depth-first iterator over the descendants of this element and their path
+    /// relative to this element.
+    pub fn dfs_paths(&self) -> DepthFirstPaths<'_> {
+        DepthFirstPaths(self.dfs())
+    }
+
+    /// Returns a breadth-first iterator over the descendants of this element and their
+    /// depth, starting at `0` for the children.
+    pub fn bfs(&self) -> BreadthFirst<'_> {
+        BreadthFirst::new(self.children())
+    }
+
+    /// Returns an iterator over the text content of this element in document order,
+    /// including the element itself if it is text.
+    pub fn texts(&self) -> Texts<'_> {
+        Texts(DepthFirst::new(slice::from_ref(self)))
+    }
+
+    /// Returns a mutable iterator over the text content of this element in document order,
+    /// including the element itself if it is text.
+    pub fn texts_mut(&mut self) -> TextsMut<'_> {
+        TextsMut {
+            stack: vec![slice::from_mut(self).iter_mut()],
+        }
+    }
+}
@@ -0,0 +1,356 @@
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SsmlElement, borrowed};
+
+/// The type of an [`SsmlElement`], without its attributes or children.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{ElementKind, SsmlElement};
+///
+/// let element = SsmlElement::Sentence { children: vec![] };
+/// assert_eq!(element.kind(), ElementKind::Sentence);
+/// assert_eq!(ElementKind::Sentence.tag_name(), "s");
+/// assert!(ElementKind::Paragraph.can_contain(ElementKind::Sentence));
+/// assert!(!ElementKind::Sentence.can_contain(ElementKind::Paragraph));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum ElementKind {
+    /// [`SsmlElement::Voice`]
+    Voice,
+    /// [`SsmlElement::Speak`]
+    Speak,
+    /// [`SsmlElement::Paragraph`]
+    Paragraph,
+    /// [`SsmlElement::Sentence`]
+    Sentence,
+    /// [`SsmlElement::Phoneme`]
+    Phoneme,
+    /// [`SsmlElement::SayAs`]
+    SayAs,
+    /// [`SsmlElement::Sub`]
+    Sub,
+    /// [`SsmlElement::Prosody`]
+    Prosody,
+    /// [`SsmlElement::Emphasis`]
+    Emphasis,
+    /// [`SsmlElement::Break`]
+    Break,
+    /// [`SsmlElement::Mark`]
+    Mark,
+    /// [`SsmlElement::Audio`]
+    Audio,
+    /// [`SsmlElement::Desc`]
+    Desc,
+    /// [`SsmlElement::LexiconUri`]
+    LexiconUri,
+    /// [`SsmlElement::Lang`]
+    Lang,
+    /// [`SsmlElement::Text`]
+    Text,
+}
+
+impl ElementKind {
+    /// Every element kind, in declaration order.
+    pub const ALL: [ElementKind; 16] = [
+        ElementKind::Voice,
+        ElementKind::Speak,
+        ElementKind::Paragraph,
+        ElementKind::Sentence,
+        ElementKind::Phoneme,
+        ElementKind::SayAs,
+        ElementKind::Sub,
+        ElementKind::Prosody,
+        ElementKind::Emphasis,
+        ElementKind::Break,
+        ElementKind::Mark,
+        ElementKind::Audio,
+        ElementKind::Desc,
+        ElementKind::LexiconUri,
+        ElementKind::Lang,
+        ElementKind::Text,
+    ];
+
+    /// Returns the SSML tag name of the element, or `#text` for text content.
+    pub fn tag_name(self) -> &'static str {
+        match self {
+            ElementKind::Voice => "voice",
+            ElementKind::Speak => "speak",
+            ElementKind::Paragraph => "p",
+            ElementKind::Sentence => "s",
+            ElementKind::Phoneme => "phoneme",
+            ElementKind::SayAs => "say-as",
+            ElementKind::Sub => "sub",
+            ElementKind::Prosody => "prosody",
+            ElementKind::Emphasis => "emphasis",
+            ElementKind::Break => "break",
+            ElementKind::Mark => "mark",
+            ElementKind::Audio => "audio",
+            ElementKind::Desc => "desc",
+            ElementKind::LexiconUri => "lexicon",
+            ElementKind::Lang => "lang",
+            ElementKind::Text => "#text",
+        }
+    }
+
+    /// Returns the name of the [`SsmlElement`] variant, e.g. `SayAs`.
+    pub fn variant_name(self) -> &'static str {
+        match self {
+            ElementKind::Voice => "Voice",
+            ElementKind::Speak => "Speak",
+            ElementKind::Paragraph => "Paragraph",
+            ElementKind::Sentence => "Sentence",
+            ElementKind::Phoneme => "Phoneme",
+            ElementKind::SayAs => "SayAs",
+            ElementKind::Sub => "Sub",
+            ElementKind::Prosody => "Prosody",
+            ElementKind::Emphasis => "Emphasis",
+            ElementKind::Break => "Break",
+            ElementKind::Mark => "Mark",
+            ElementKind::Audio => "Audio",
+            ElementKind::Desc => "Desc",
+            ElementKind::LexiconUri => "LexiconUri",
+            ElementKind::Lang => "Lang",
+            ElementKind::Text => "Text",
+        }
+    }
+
+    /// Returns the names of the attributes the document model stores for this kind, in
+    /// declaration order.
+    pub fn attributes(self) -> &'static [&'static str] {
+        match self {
+            ElementKind::Speak => &["version", "xmlns", "xml:lang"],
+            ElementKind::Voice => &["name"],
+            ElementKind::Phoneme => &["alphabet", "ph"],
+            ElementKind::SayAs => &["interpret-as", "format", "detail"],
+            ElementKind::Sub => &["alias"],
+            ElementKind::Prosody => &["rate", "pitch", "contour", "range", "volume"],
+            ElementKind::Emphasis => &["level"],
+            ElementKind::Break => &["time", "strength"],
+            ElementKind::Mark => &["name"],
+            ElementKind::Audio => &["src"],
+            ElementKind::LexiconUri => &["uri"],
+            ElementKind::Lang => &["xml:lang"],
+            ElementKind::Paragraph
+            | ElementKind::Sentence
+            | ElementKind::Desc
+            | ElementKind::Text => &[],
+        }
+    }
+
+    /// Returns whether the element can have children, i.e. is not `<break>`, `<mark>`,
+    /// `<lexicon>` or text.
+    pub fn is_container(self) -> bool {
+        !matches!(
+            self,
+            ElementKind::Break | ElementKind::Mark | ElementKind::LexiconUri | ElementKind::Text
+        )
+    }
+
+    /// Returns whether SSML 1.1 allows `child` directly inside an element of this kind.
+    ///
+    /// `<say-as>`, `<phoneme>`, `<sub>` and `<desc>` contain only text, `<break>`, `<mark>`
+    /// and `<lexicon>` are always empty, and `<speak>` never appears below the root.
+    pub fn can_contain(self, child: ElementKind) -> bool {
+        use ElementKind::*;
+
+        let inline = matches!(
+            child,
+            Text | Audio | Break | Emphasis | Lang | Mark | Phoneme | Prosody | SayAs | Sub | Voice
+        );
+
+        match self {
+            Speak => inline || matches!(child, Paragraph | Sentence | LexiconUri),
+            Voice | Prosody | Lang => inline || matches!(child, Paragraph | Sentence),
+            Audio => inline || matches!(child, Paragraph | Sentence | Desc),
+            Paragraph => inline || child == Sentence,
+            Sentence | Emphasis => inline,
+            Phoneme | SayAs | Sub | Desc => child == Text,
+            Break | Mark | LexiconUri | Text => false,
+        }
+    }
+}
+
+/// Writes the variant name, e.g. `SayAs`.
+///
+/// The alternate form (`{:#}`) writes the SSML tag name instead, e.g. `say-as`.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::ElementKind;
+///
+/// assert_eq!(ElementKind::SayAs.to_string(), "SayAs");
+/// assert_eq!(format!("{:#}", ElementKind::SayAs), "say-as");
+/// ```
+impl fmt::Display for ElementKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str(self.tag_name())
+        } else {
+            f.write_str(self.variant_name())
+        }
+    }
+}
+
+impl FromStr for ElementKind {
+    type Err = ();
+
+    /// Parses either a variant name (`SayAs`) or an SSML tag name (`say-as`).
+    ///
+    /// Matching is exact, so `Break` and `break` are accepted but `BREAK` is not.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::ElementKind;
+    ///
+    /// assert_eq!("Sentence".parse(), Ok(ElementKind::Sentence));
+    /// assert_eq!("s".parse(), Ok(ElementKind::Sentence));
+    /// assert_eq!("sentence".parse::<ElementKind>(), Err(()));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        ElementKind::ALL
+            .into_iter()
+            .find(|kind| kind.variant_name() == s || kind.tag_name() == s)
+            .ok_or(())
+    }
+}
+
+impl SsmlElement {
+    /// Returns the type of this element.
+    pub fn kind(&self) -> ElementKind {
+        match self {
+            SsmlElement::Voice { .. } => ElementKind::Voice,
+            SsmlElement::Speak { .. } => ElementKind::Speak,
+            SsmlElement::Paragraph { .. } => ElementKind::Paragraph,
+            SsmlElement::Sentence { .. } => ElementKind::Sentence,
+            SsmlElement::Phoneme { .. } => ElementKind::Phoneme,
+            SsmlElement::SayAs { .. } => ElementKind::SayAs,
+            SsmlElement::Sub { .. } => ElementKind::Sub,
+            SsmlElement::Prosody { .. } => ElementKind::Prosody,
+            SsmlElement::Emphasis { .. } => ElementKind::Emphasis,
+            SsmlElement::Break { .. } => ElementKind::Break,
+            SsmlElement::Mark { .. } => ElementKind::Mark,
+            SsmlElement::Audio { .. } => ElementKind::Audio,
+            SsmlElement::Desc { .. } => ElementKind::Desc,
+            SsmlElement::LexiconUri { .. } => ElementKind::LexiconUri,
+            SsmlElement::Lang { .. } => ElementKind::Lang,
+            SsmlElement::Text(_) => ElementKind::Text,
+        }
+    }
+}
+
+impl borrowed::SsmlElement<'_> {
+    /// Returns the type of this element.
+    pub fn kind(&self) -> ElementKind {
+        use borrowed::SsmlElement;
+
+        match self {
+            SsmlElement::Voice { .. } => ElementKind::Voice,
+            SsmlElement::Speak { .. } => ElementKind::Speak,
+            SsmlElement::Paragraph { .. } => ElementKind::Paragraph,
+            SsmlElement::Sentence { .. } => ElementKind::Sentence,
+            SsmlElement::Phoneme { .. } => ElementKind::Phoneme,
+            SsmlElement::SayAs { .. } => ElementKind::SayAs,
+            SsmlElement::Sub { .. } => ElementKind::Sub,
+            SsmlElement::Prosody { .. } => ElementKind::Prosody,
+            SsmlElement::Emphasis { .. } => ElementKind::Emphasis,
+            SsmlElement::Break { .. } => ElementKind::Break,
+            SsmlElement::Mark { .. } => ElementKind::Mark,
+            SsmlElement::Audio { .. } => ElementKind::Audio,
+            SsmlElement::Desc { .. } => ElementKind::Desc,
+            SsmlElement::LexiconUri { .. } => ElementKind::LexiconUri,
+            SsmlElement::Lang { .. } => ElementKind::Lang,
+            SsmlElement::Text(_) => ElementKind::Text,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_text_only_elements() {
+        for kind in [
+            ElementKind::Phoneme,
+            ElementKind::SayAs,
+            ElementKind::Sub,
+            ElementKind::Desc,
+        ] {
+            assert!(kind.can_contain(ElementKind::Text));
+            assert!(!kind.can_contain(ElementKind::Break));
+        }
+    }
+
+    #[test]
+    fn test_empty_elements() {
+        for kind in [
+            ElementKind::Break,
+            ElementKind::Mark,
+            ElementKind::LexiconUri,
+            ElementKind::Text,
+        ] {
+            assert!(
+                ElementKind::ALL
+                    .iter()
+                    .all(|child| !kind.can_contain(*child))
+            );
+        }
+    }
+
+    #[test]
+    fn test_speak_is_root_only() {
+        assert!(
+            ElementKind::ALL
+                .iter()
+                .all(|parent| !parent.can_contain(ElementKind::Speak))
+        );
+    }
+
+    #[test]
+    fn test_attributes_match_serializer() {
+        let ssml = crate::from_str(
+            r#"<speak version="1.1" xmlns="urn:x" xml:lang="en"><voice name="a"><prosody rate="1" pitch="2" contour="3" range="4" volume="5"><say-as interpret-as="a" format="b" detail="c">1</say-as><phoneme alphabet="ipa" ph="x">x</phoneme><sub alias="a">b</sub><emphasis level="strong">x</emphasis><break time="1s" strength="weak"/><mark name="m"/><audio src="a.wav"/><lang xml:lang="fr">x</lang></prosody></voice><lexicon uri="a.pls"/></speak>"#,
+        )
+        .unwrap();
+
+        let mut elements: Vec<&SsmlElement> = ssml.elements.iter().collect();
+        while let Some(element) = elements.pop() {
+            let names: Vec<&str> =
+                crate::ser::attributes(element, &crate::SerializeOptions::default())
+                    .into_iter()
+                    .map(|(name, _)| name)
+                    .collect();
+            assert_eq!(names, element.kind().attributes(), "{element:?}");
+            elements.extend(crate::ser::children(element).unwrap_or_default());
+        }
+    }
+
+    #[test]
+    fn test_names_round_trip() {
+        for kind in ElementKind::ALL {
+            assert_eq!(kind.to_string().parse(), Ok(kind));
+            assert_eq!(format!("{kind:#}").parse(), Ok(kind));
+        }
+        assert_eq!("lexicon".parse(), Ok(ElementKind::LexiconUri));
+        assert_eq!("#text".parse(), Ok(ElementKind::Text));
+        assert_eq!("amazon:effect".parse::<ElementKind>(), Err(()));
+    }
+
+    #[test]
+    fn test_structure() {
+        assert!(ElementKind::Speak.can_contain(ElementKind::LexiconUri));
+        assert!(!ElementKind::Voice.can_contain(ElementKind::LexiconUri));
+        assert!(ElementKind::Voice.can_contain(ElementKind::Paragraph));
+        assert!(!ElementKind::Paragraph.can_contain(ElementKind::Paragraph));
+        assert!(!ElementKind::Sentence.can_contain(ElementKind::Sentence));
+        assert!(!ElementKind::Emphasis.can_contain(ElementKind::Sentence));
+        assert!(ElementKind::Audio.can_contain(ElementKind::Desc));
+        assert!(!ElementKind::Sentence.can_contain(ElementKind::Desc));
+    }
+}
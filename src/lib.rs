@@ -31,15 +31,56 @@
     unused_mut
 )]
 
-use chumsky::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, time::Duration};
+use std::time::Duration;
 
+pub mod borrowed;
 mod break_strength;
+pub mod builder;
+mod canonical;
+pub mod compact;
+mod concat;
+mod convert;
+pub mod de;
+mod diff;
+mod edit;
+mod error;
+mod escape;
+mod macros;
+mod merge;
+mod normalize;
+mod parser;
+mod path;
+pub mod se;
+mod select;
 mod ser;
+mod split;
+mod template;
+pub mod visit;
 pub use break_strength::BreakStrength;
+pub use canonical::canonicalize;
+pub use de::{DeError, Deserializer};
+pub use diff::{Diff, Edit, diff};
+pub use error::{Error, ErrorKind, Limit, ParseError};
+#[doc(hidden)]
+pub use macros::__private;
+pub use merge::{Conflict, MergeError, merge};
+pub use normalize::{NormalizeOptions, NormalizeReport, NormalizeRule};
+pub use parser::{
+    DEFAULT_MAX_DEPTH, Namespaces, ParseOptions, Parser, SSML_NAMESPACE, UnknownElements,
+    Whitespace,
+};
+pub use path::{InvalidPath, NodeId, NodePath, TrackedSsml};
+pub use se::{SeError, Serializer};
+pub use select::{Selector, SelectorError};
+pub use ser::{AttributeOrder, EmptyElements, Quote, SerializeOptions};
+pub use split::{SplitLimits, split};
+pub use template::{Placeholder, PlaceholderKind, RenderError, Template, TemplateError};
 mod functions;
+pub mod iter;
+mod kind;
 pub use functions::*;
+pub use kind::ElementKind;
 
 /// Represents the entire SSML document structure.
 ///
@@ -66,7 +107,7 @@ pub use functions::*;
 ///     ]
 /// };
 /// ```
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct SSML {
     /// Top-level SSML elements
     pub elements: Vec<SsmlElement>,
@@ -473,250 +514,123 @@ pub enum SsmlElement {
     Text(String),
 }
 
-// Parse an attribute name (letters, digits, underscore, hyphen, colon)
-fn attr_ident() -> impl Parser<char, String, Error = Simple<char>> {
-    filter(|c: &char| c.is_ascii_alphabetic() || *c == '_' || *c == '-' || *c == ':')
-        .chain::<char, _, _>(
-            filter(|c: &char| c.is_ascii_alphanumeric() || *c == '_' || *c == '-' || *c == ':')
-                .repeated(),
-        )
-        .collect()
+impl SsmlElement {
+    /// Returns the child elements of a container element, or `None` for elements that cannot
+    /// have children (`<break>`, `<mark>`, `<lexicon>` and text).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SsmlElement;
+    ///
+    /// let mut element: SsmlElement = "<s>Hello</s>".parse().unwrap();
+    /// if let Some(children) = element.children_mut() {
+    ///     children.push(SsmlElement::Mark { name: "end".to_string() });
+    /// }
+    ///
+    /// assert_eq!(element.to_string(), r#"<s>Hello<mark name="end"/></s>"#);
+    /// ```
+    pub fn children_mut(&mut self) -> Option<&mut Vec<SsmlElement>> {
+        match self {
+            SsmlElement::Voice { children, .. }
+            | SsmlElement::Speak { children, .. }
+            | SsmlElement::Paragraph { children }
+            | SsmlElement::Sentence { children }
+            | SsmlElement::Phoneme { children, .. }
+            | SsmlElement::SayAs { children, .. }
+            | SsmlElement::Sub { children, .. }
+            | SsmlElement::Prosody { children, .. }
+            | SsmlElement::Emphasis { children, .. }
+            | SsmlElement::Audio { children, .. }
+            | SsmlElement::Desc { children }
+            | SsmlElement::Lang { children, .. } => Some(children),
+            SsmlElement::Break { .. }
+            | SsmlElement::Mark { .. }
+            | SsmlElement::LexiconUri { .. }
+            | SsmlElement::Text(_) => None,
+        }
+    }
+}
+
+// Matches the field holding the attribute `$name` of `$element` as a variant of `$Field`,
+// evaluating to `None` if the element has no such attribute
+macro_rules! attribute_field {
+    ($element:expr, $name:expr, $Field:ident) => {{
+        use $Field::{Optional, Strength, Text, Time};
+
+        let field = match ($element, $name) {
+            (SsmlElement::Speak { version, .. }, "version") => Optional(version),
+            (SsmlElement::Speak { xmlns, .. }, "xmlns") => Optional(xmlns),
+            (SsmlElement::Speak { lang, .. }, "xml:lang") => Optional(lang),
+            (SsmlElement::Voice { name, .. }, "name") => Text(name),
+            (SsmlElement::Phoneme { alphabet, .. }, "alphabet") => Text(alphabet),
+            (SsmlElement::Phoneme { ph, .. }, "ph") => Text(ph),
+            (SsmlElement::SayAs { interpret_as, .. }, "interpret-as") => Text(interpret_as),
+            (SsmlElement::SayAs { format, .. }, "format") => Text(format),
+            (SsmlElement::SayAs { detail, .. }, "detail") => Text(detail),
+            (SsmlElement::Sub { alias, .. }, "alias") => Text(alias),
+            (SsmlElement::Prosody { rate, .. }, "rate") => Text(rate),
+            (SsmlElement::Prosody { pitch, .. }, "pitch") => Text(pitch),
+            (SsmlElement::Prosody { contour, .. }, "contour") => Text(contour),
+            (SsmlElement::Prosody { range, .. }, "range") => Text(range),
+            (SsmlElement::Prosody { volume, .. }, "volume") => Text(volume),
+            (SsmlElement::Emphasis { level, .. }, "level") => Text(level),
+            (SsmlElement::Break { time, .. }, "time") => Time(time),
+            (SsmlElement::Break { strength, .. }, "strength") => Strength(strength),
+            (SsmlElement::Mark { name }, "name") => Text(name),
+            (SsmlElement::Audio { src, .. }, "src") => Text(src),
+            (SsmlElement::LexiconUri { uri }, "uri") => Text(uri),
+            (SsmlElement::Lang { xml_lang, .. }, "xml:lang") => Text(xml_lang),
+            _ => return None,
+        };
+        Some(field)
+    }};
 }
 
-// Parse an attribute (e.g., name="value")
-fn attribute() -> impl Parser<char, (String, String), Error = Simple<char>> {
-    attr_ident().padded().then_ignore(just('=').padded()).then(
-        just('"')
-            .ignore_then(none_of("\"").repeated().collect::<String>())
-            .then_ignore(just('"')),
-    )
+// The field holding an attribute of an element, by the type of its value
+pub(crate) enum Attribute<'e> {
+    Text(&'e String),
+    Optional(&'e Option<String>),
+    Time(&'e Option<Duration>),
+    Strength(&'e Option<BreakStrength>),
 }
 
-// Build an SSML parser
-fn ssml_parser() -> impl Parser<char, SSML, Error = Simple<char>> {
-    // Parser for opening tags with attributes
-    let open_tag = |name: &'static str| {
-        just('<')
-            .padded()
-            .ignore_then(just(name).padded())
-            .ignore_then(attribute().padded().repeated().collect::<Vec<_>>())
-            .map(move |attrs| {
-                let mut attrs_map = HashMap::new();
-                for (key, value) in attrs {
-                    let _ = attrs_map.insert(key, value);
-                }
-                attrs_map
-            })
-            .then_ignore(just('>'))
-            .padded()
-    };
-
-    // Parser for closing tags
-    let close_tag = |name: &'static str| {
-        just("</")
-            .padded()
-            .ignore_then(just(name).padded())
-            .then_ignore(just('>'))
-            .to(())
-            .padded()
-    };
-
-    // Parser for self-closing tags
-    let self_close_tag = |name: &'static str| {
-        just('<')
-            .ignore_then(just(name).padded())
-            .ignore_then(attribute().padded().repeated().collect::<Vec<_>>())
-            .map(move |attrs| {
-                let mut attrs_map = HashMap::new();
-                for (key, value) in attrs {
-                    let _ = attrs_map.insert(key, value);
-                }
-                attrs_map
-            })
-            .then_ignore(just("/>"))
-            .padded()
-    };
-
-    // Text content parser
-    let text = none_of("<")
-        .repeated()
-        .at_least(1)
-        .collect::<String>()
-        .map(|txt| txt.trim().to_string())
-        .map(SsmlElement::Text);
-
-    // Parser for XML declaration
-    let xml_decl = just("<?xml")
-        .padded()
-        .ignore_then(
-            // Parse attributes like version="1.0"
-            attribute().padded().repeated(),
-        )
-        .then_ignore(just("?>").padded())
-        .ignored()
-        .padded();
-
-    // Recursive parser for nested elements
-    let ssml_parser = recursive(|element| {
-        let speak_element = open_tag("speak")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("speak"))
-            .map(|(attrs, children)| SsmlElement::Speak {
-                version: attrs.get("version").cloned(),
-                xmlns: attrs.get("xmlns").cloned(),
-                lang: attrs.get("xml:lang").cloned(),
-                children,
-            });
-
-        let voice_element = open_tag("voice")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("voice"))
-            .map(|(attrs, children)| SsmlElement::Voice {
-                name: attrs.get("name").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let paragraph_element = open_tag("p")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("p"))
-            .map(|(_, children)| SsmlElement::Paragraph { children });
-
-        let sentence_element = open_tag("s")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("s"))
-            .map(|(_, children)| SsmlElement::Sentence { children });
-
-        let phoneme_element = open_tag("phoneme")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("phoneme"))
-            .map(|(attrs, children)| SsmlElement::Phoneme {
-                alphabet: attrs.get("alphabet").cloned().unwrap_or_default(),
-                ph: attrs.get("ph").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let say_as_element = open_tag("say-as")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("say-as"))
-            .map(|(attrs, children)| SsmlElement::SayAs {
-                interpret_as: attrs.get("interpret-as").cloned().unwrap_or_default(),
-                format: attrs.get("format").cloned().unwrap_or_default(),
-                detail: attrs.get("detail").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let sub_element = open_tag("sub")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("sub"))
-            .map(|(attrs, children)| SsmlElement::Sub {
-                alias: attrs.get("alias").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let prosody_element = open_tag("prosody")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("prosody"))
-            .map(|(attrs, children)| SsmlElement::Prosody {
-                rate: attrs.get("rate").cloned().unwrap_or_default(),
-                pitch: attrs.get("pitch").cloned().unwrap_or_default(),
-                contour: attrs.get("contour").cloned().unwrap_or_default(),
-                range: attrs.get("range").cloned().unwrap_or_default(),
-                volume: attrs.get("volume").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let emphasis_element = open_tag("emphasis")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("emphasis"))
-            .map(|(attrs, children)| SsmlElement::Emphasis {
-                level: attrs.get("level").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let audio_element = open_tag("audio")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("audio"))
-            .map(|(attrs, children)| SsmlElement::Audio {
-                src: attrs.get("src").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let desc_element = open_tag("desc")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("desc"))
-            .map(|(_, children)| SsmlElement::Desc { children });
-
-        let lang_element = open_tag("lang")
-            .then(element.clone().repeated())
-            .then_ignore(close_tag("lang"))
-            .map(|(attrs, children)| SsmlElement::Lang {
-                xml_lang: attrs.get("xml:lang").cloned().unwrap_or_default(),
-                children,
-            });
-
-        let break_element = self_close_tag("break")
-            .map(|attrs| SsmlElement::Break {
-                time: attrs.get("time").and_then(|t| duration_str::parse(t).ok()),
-                strength: attrs.get("strength").and_then(|s| s.parse().ok()),
-            })
-            .or(open_tag("break")
-                .then_ignore(close_tag("break"))
-                .map(|attrs| SsmlElement::Break {
-                    time: attrs.get("time").and_then(|t| duration_str::parse(t).ok()),
-                    strength: attrs.get("strength").and_then(|s| s.parse().ok()),
-                }));
-
-        let mark_element = self_close_tag("mark")
-            .map(|attrs| SsmlElement::Mark {
-                name: attrs.get("name").cloned().unwrap_or_default(),
-            })
-            .or(open_tag("mark")
-                .then_ignore(close_tag("mark"))
-                .map(|attrs| SsmlElement::Mark {
-                    name: attrs.get("name").cloned().unwrap_or_default(),
-                }));
-
-        let lexicon_element = self_close_tag("lexicon")
-            .map(|attrs| SsmlElement::LexiconUri {
-                uri: attrs.get("uri").cloned().unwrap_or_default(),
-            })
-            .or(open_tag("lexicon")
-                .then_ignore(close_tag("lexicon"))
-                .map(|attrs| SsmlElement::LexiconUri {
-                    uri: attrs.get("uri").cloned().unwrap_or_default(),
-                }));
-
-        choice((
-            speak_element,
-            voice_element,
-            paragraph_element,
-            sentence_element,
-            phoneme_element,
-            say_as_element,
-            sub_element,
-            prosody_element,
-            emphasis_element,
-            audio_element,
-            desc_element,
-            lang_element,
-            break_element,
-            mark_element,
-            lexicon_element,
-            text,
-        ))
-    })
-    .repeated()
-    .collect::<Vec<_>>()
-    .map(|elements| SSML { elements });
-
-    xml_decl
-        .or_not()
-        .ignore_then(ssml_parser)
-        .then_ignore(end())
+// The field holding an attribute of an element, borrowed for writing
+pub(crate) enum AttributeMut<'e> {
+    Text(&'e mut String),
+    Optional(&'e mut Option<String>),
+    Time(&'e mut Option<Duration>),
+    Strength(&'e mut Option<BreakStrength>),
 }
 
-/// Represents a parse error, containing a list of simple error tokens.
-pub type ParseError = Vec<Simple<char>>;
+impl AttributeMut<'_> {
+    // Sets the value to that of the same attribute on another element of the same kind
+    pub(crate) fn set(self, value: Attribute<'_>) {
+        match (self, value) {
+            (AttributeMut::Text(target), Attribute::Text(value)) => target.clone_from(value),
+            (AttributeMut::Optional(target), Attribute::Optional(value)) => {
+                target.clone_from(value)
+            }
+            (AttributeMut::Time(target), Attribute::Time(value)) => *target = *value,
+            (AttributeMut::Strength(target), Attribute::Strength(value)) => {
+                target.clone_from(value)
+            }
+            _ => {}
+        }
+    }
+}
+
+impl SsmlElement {
+    // Returns the field holding the attribute `name`, if the element has it
+    pub(crate) fn attribute(&self, name: &str) -> Option<Attribute<'_>> {
+        attribute_field!(self, name, Attribute)
+    }
+
+    // Returns the field holding the attribute `name` for writing, if the element has it
+    pub(crate) fn attribute_mut(&mut self, name: &str) -> Option<AttributeMut<'_>> {
+        attribute_field!(self, name, AttributeMut)
+    }
+}
 
 /// Parses a SSML (Speech Synthesis Markup Language) string into a structured representation.
 ///
@@ -726,9 +640,9 @@ pub type ParseError = Vec<Simple<char>>;
 ///
 /// # Returns
 ///
-/// * `Result<SSML, Vec<Simple<char>>>` -
+/// * `Result<SSML, ParseError>` -
 ///     - `Ok(SSML)` if parsing is successful, containing the parsed SSML structure
-///     - `Err(Vec<Simple<char>>)` if parsing fails, containing parse errors
+///     - `Err(ParseError)` if parsing fails, containing parse errors
 ///
 /// # Examples
 ///
@@ -803,19 +717,154 @@ pub type ParseError = Vec<Simple<char>>;
 ///
 /// - The parser is lenient with whitespace and nested structures
 /// - Attributes are parsed and stored for various elements
-/// - Text content is preserved as `SsmlElement::Text`
+/// - Text content is preserved as `SsmlElement::Text`, with XML entities decoded
+/// - Parsing is a single pass over the input; use [`borrowed::from_str`] to avoid copying
+///   text and attribute values
+/// - Nesting deeper than [`DEFAULT_MAX_DEPTH`] is rejected; use [`from_str_with_options`]
+///   to configure this and other resource limits
+/// - This is a shorthand for [`Parser::parse`] with the default configuration; build a
+///   [`Parser`] for strict validation, other whitespace policies or vendor extensions
 ///
 /// # Limitations
 ///
 /// - Does not validate against official SSML schemas
 /// - Parsing is based on structural recognition, not semantic validation
 pub fn from_str(input: impl AsRef<str>) -> Result<SSML, ParseError> {
-    ssml_parser().parse(input.as_ref())
+    Parser::new().parse(input.as_ref())
+}
+
+/// Parses a SSML string like [`from_str`], enforcing the resource limits in `options`.
+///
+/// Use this for untrusted input: a document exceeding any limit fails with
+/// [`ErrorKind::LimitExceeded`] rather than exhausting memory or the stack.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{ErrorKind, Limit, ParseOptions};
+///
+/// let options = ParseOptions::new().max_elements(2);
+/// let errors = serde_ssml::from_str_with_options(
+///     r#"<speak><break time="1s"/><break time="2s"/></speak>"#,
+///     &options,
+/// )
+/// .unwrap_err();
+///
+/// assert_eq!(errors[0].kind(), &ErrorKind::LimitExceeded(Limit::Elements(2)));
+/// ```
+pub fn from_str_with_options(
+    input: impl AsRef<str>,
+    options: &ParseOptions,
+) -> Result<SSML, ParseError> {
+    Parser::new().limits(*options).parse(input.as_ref())
+}
+
+/// Parses inline SSML content as if it appeared directly inside an element of kind `context`.
+///
+/// Fragments need no `<speak>` root, but every element must be allowed by the SSML content
+/// model where it appears; see [`Parser::parse_fragment`].
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{ElementKind, SsmlElement};
+///
+/// let fragment =
+///     serde_ssml::from_fragment(r#"Hello <break time="1s"/> world"#, ElementKind::Sentence)
+///         .unwrap();
+/// assert_eq!(fragment[0], SsmlElement::Text("Hello".to_string()));
+/// assert_eq!(
+///     serde_ssml::to_fragment_string(&fragment),
+///     r#"Hello<break time="1000ms"/>world"#
+/// );
+///
+/// assert!(serde_ssml::from_fragment("<p>Hi</p>", ElementKind::Sentence).is_err());
+/// ```
+pub fn from_fragment(
+    input: impl AsRef<str>,
+    context: ElementKind,
+) -> Result<Vec<SsmlElement>, ParseError> {
+    Parser::new().parse_fragment(input.as_ref(), context)
 }
 
 /// Converts a structured SSML representation into a serialized SSML string.
 pub fn to_string(ssml: &SSML) -> String {
-    ser::to_ssml(ssml)
+    ser::to_ssml(ssml, &SerializeOptions::default())
+}
+
+/// Serializes a SSML document like [`to_string`], formatted according to `options`.
+///
+/// See [`SerializeOptions`] for an example.
+pub fn to_string_with_options(ssml: &SSML, options: &SerializeOptions) -> String {
+    ser::to_ssml(ssml, options)
+}
+
+/// Converts a structured SSML representation into an indented, human-readable SSML string.
+///
+/// Block elements (`<speak>`, `<voice>`, `<p>`, `<s>`, `<prosody>` and `<lang>`) put their
+/// children on separate, indented lines. As soon as an element contains text or inline markup
+/// such as `<emphasis>`, `<say-as>` or `<audio>`, its content is written on one line exactly
+/// like [`to_string`] does, so whitespace is never introduced where it could be spoken. Parsing
+/// the output with the default [`Parser`] yields the original document.
+///
+/// Indentation is two spaces; use [`SerializeOptions::indent`] for another width.
+///
+/// # Examples
+///
+/// ```rust
+/// let ssml = serde_ssml::from_str(
+///     r#"<speak><p><s>Hello <emphasis>big</emphasis> world</s><break time="1s"/><s>Bye</s></p></speak>"#,
+/// )
+/// .unwrap();
+///
+/// let pretty = serde_ssml::to_string_pretty(&ssml);
+/// assert_eq!(
+///     pretty,
+///     r#"<speak>
+///   <p>
+///     <s>Hello<emphasis>big</emphasis>world</s>
+///     <break time="1000ms"/>
+///     <s>Bye</s>
+///   </p>
+/// </speak>"#
+/// );
+/// assert_eq!(serde_ssml::from_str(&pretty).unwrap(), ssml);
+/// ```
+pub fn to_string_pretty(ssml: &SSML) -> String {
+    ser::to_ssml(ssml, &SerializeOptions::new().indent(ser::DEFAULT_INDENT))
+}
+
+/// Serializes a SSML document in canonical form.
+///
+/// Documents that only differ in ways that don't change what is spoken, such as attribute
+/// order, whitespace, `1s` vs `1000ms` or a default `level`, produce byte-identical output.
+/// See [`canonicalize`] for the exact rules and [`SSML::content_hash`] for a cache key.
+///
+/// # Examples
+///
+/// ```rust
+/// let a = serde_ssml::from_str(r#"<speak><emphasis>Hi</emphasis><break time="1s"/></speak>"#);
+/// let b = serde_ssml::from_str(
+///     r#"<speak>
+///         <emphasis level="moderate">Hi</emphasis>
+///         <break time="1000ms"/>
+///     </speak>"#,
+/// );
+///
+/// assert_eq!(
+///     serde_ssml::to_string_canonical(&a.unwrap()),
+///     serde_ssml::to_string_canonical(&b.unwrap())
+/// );
+/// ```
+pub fn to_string_canonical(ssml: &SSML) -> String {
+    canonical::to_ssml_canonical(ssml)
+}
+
+/// Serializes a sequence of sibling elements without wrapping them in a `<speak>` root.
+///
+/// This is the inverse of [`from_fragment`].
+pub fn to_fragment_string(elements: &[SsmlElement]) -> String {
+    ser::to_ssml_fragment(elements, &SerializeOptions::default())
 }
 
 // Example usage and demonstration module
@@ -847,32 +896,12 @@ mod documentation_examples {
             assert_eq!(version.as_deref(), Some("1.1"));
             assert_eq!(lang.as_deref(), Some("en-US"));
 
-            // Recursive function to find emphasized text
-            fn find_emphasized_text(element: &SsmlElement) -> Option<String> {
-                match element {
-                    SsmlElement::Emphasis { children, .. } => children.iter().find_map(|child| {
-                        if let SsmlElement::Text(text) = child {
-                            Some(text.clone())
-                        } else {
-                            None
-                        }
-                    }),
-                    SsmlElement::Paragraph { children }
-                    | SsmlElement::Sentence { children }
-                    | SsmlElement::Voice { children, .. }
-                    | SsmlElement::Prosody { children, .. }
-                    | SsmlElement::Audio { children, .. }
-                    | SsmlElement::Lang { children, .. } => {
-                        children.iter().find_map(find_emphasized_text)
-                    }
-                    _ => None,
-                }
-            }
-
             // Find the emphasized text
             let emphasized_text = children
                 .iter()
-                .find_map(find_emphasized_text)
+                .flat_map(SsmlElement::descendants)
+                .find(|element| matches!(element, SsmlElement::Emphasis { .. }))
+                .and_then(|element| element.texts().next())
                 .expect("No emphasized text found");
 
             assert_eq!(emphasized_text, "important");
@@ -0,0 +1,479 @@
+//! The `ssml!` macro and the runtime support its expansion calls into.
+
+/// Builds an [`SSML`](crate::SSML) document from inline markup.
+///
+/// The markup is checked while compiling: unknown elements, attributes an element does not
+/// have, mismatched or unclosed tags, `<break>`, `<mark>` and `<lexicon>` used as containers,
+/// and literal `<break>` times and strengths the parser would reject are all compile errors.
+/// Literal times are stricter than the parser: only whole seconds or milliseconds, such as
+/// `2s` or `500ms`, are accepted, so other times like `2m` need a `Duration`.
+///
+/// Rust expressions in braces are interpolated with [`ToString`], either as text or as
+/// attribute values. They become plain values in the document, so they are escaped when the
+/// document is serialized and can never inject markup. The `time` and `strength` of
+/// `<break>` are typed instead: they take a [`Duration`](std::time::Duration) and a
+/// [`BreakStrength`](crate::BreakStrength), so they cannot hold invalid values either.
+///
+/// Text follows these rules:
+///
+/// - Bare words and interpolations are separated by single spaces, e.g. `Hello {name}`.
+/// - String literals are inserted verbatim, so `"Hello, " {name} "!"` controls spacing and
+///   punctuation exactly, and is needed for text Rust cannot tokenize, such as apostrophes.
+/// - As with [`from_str`](crate::from_str), text is trimmed at element boundaries.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+///
+/// use serde_ssml::ssml;
+///
+/// let name = "<Alice>";
+/// let pause = Duration::from_millis(500);
+/// let ssml = ssml!(
+///     <speak xml:lang="en-US">
+///         <s>Hello {name}</s>
+///         <break time={pause}/>
+///         <say-as interpret-as="cardinal">{42}</say-as>
+///     </speak>
+/// );
+///
+/// assert_eq!(
+///     ssml.to_string(),
+///     r#"<speak xml:lang="en-US"><s>Hello &lt;Alice&gt;</s><break time="500ms"/><say-as interpret-as="cardinal">42</say-as></speak>"#
+/// );
+/// ```
+///
+/// Invalid markup does not compile:
+///
+/// ```rust,compile_fail
+/// let ssml = serde_ssml::ssml!(<speak><s>Hello</p></speak>);
+/// ```
+///
+/// ```rust,compile_fail
+/// let ssml = serde_ssml::ssml!(<speak><break time="soon"/></speak>);
+/// ```
+///
+/// ```rust,compile_fail
+/// let ssml = serde_ssml::ssml!(<speak><break time="2m"/></speak>);
+/// ```
+///
+/// ```rust,compile_fail
+/// let time = "soon";
+/// let ssml = serde_ssml::ssml!(<speak><break time={time}/></speak>);
+/// ```
+///
+/// Long documents may need a higher `#![recursion_limit]`, as the macro expands once per tag,
+/// attribute and run of text.
+#[macro_export]
+macro_rules! ssml {
+    ($($markup:tt)*) => {
+        $crate::__ssml!(@parse [] [] $($markup)*)
+    };
+}
+
+// The parser is a token muncher whose state is a stack of open elements, each stored as
+// `{ (tag) [attributes] [children of the parent] }`, and the children of the innermost open
+// element, stored as a list of parenthesized expressions evaluating to `__private::Node`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __ssml {
+    // End of input
+    (@parse [] [$($children:tt)*]) => {
+        $crate::__private::document(::std::vec![$($children),*])
+    };
+    (@parse [{ $tag:tt $($frame:tt)* } $($frames:tt)*] [$($children:tt)*]) => {
+        ::std::compile_error!(::std::concat!(
+            "unclosed element <", $crate::__ssml!(@name $tag), ">"
+        ))
+    };
+
+    // Closing tags
+    (@parse [{ $open:tt [$($attributes:tt)*] [$($parent:tt)*] } $($frames:tt)*] [$($children:tt)*]
+        < / say - as > $($rest:tt)*) => {
+        $crate::__ssml!(@parse [$($frames)*] [$($parent)* ($crate::__ssml!(
+            @element $open (say - as) [$($attributes)*] [$($children)*]
+        ))] $($rest)*)
+    };
+    (@parse [{ $open:tt [$($attributes:tt)*] [$($parent:tt)*] } $($frames:tt)*] [$($children:tt)*]
+        < / $close:ident > $($rest:tt)*) => {
+        $crate::__ssml!(@parse [$($frames)*] [$($parent)* ($crate::__ssml!(
+            @element $open ($close) [$($attributes)*] [$($children)*]
+        ))] $($rest)*)
+    };
+    (@parse [] $children:tt < / $($rest:tt)*) => {
+        ::std::compile_error!("closing tag without a matching opening tag")
+    };
+
+    // Opening tags
+    (@parse $frames:tt $children:tt < say - as $($rest:tt)*) => {
+        $crate::__ssml!(@open $frames $children (say - as) [] $($rest)*)
+    };
+    (@parse $frames:tt $children:tt < $tag:ident $($rest:tt)*) => {
+        $crate::__ssml!(@open $frames $children ($tag) [] $($rest)*)
+    };
+
+    // Text
+    (@parse $frames:tt [$($children:tt)*] { $($value:tt)* } $($rest:tt)*) => {
+        $crate::__ssml!(@parse $frames [$($children)* (
+            $crate::__private::Node::Word(::std::string::ToString::to_string(&{ $($value)* }))
+        )] $($rest)*)
+    };
+    (@parse $frames:tt [$($children:tt)*] $text:literal $($rest:tt)*) => {
+        $crate::__ssml!(@parse $frames [$($children)* (
+            $crate::__private::Literal::into_node($text)
+        )] $($rest)*)
+    };
+    (@parse $frames:tt $children:tt $word:tt $($rest:tt)*) => {
+        $crate::__ssml!(@words $frames $children [$word] $($rest)*)
+    };
+
+    // Runs of bare words, which are stringified together to keep their punctuation
+    (@words $frames:tt [$($children:tt)*] [$($words:tt)*] < $($rest:tt)*) => {
+        $crate::__ssml!(@parse $frames [$($children)* (
+            $crate::__private::Node::Word(::std::string::String::from(::std::stringify!($($words)*)))
+        )] < $($rest)*)
+    };
+    (@words $frames:tt [$($children:tt)*] [$($words:tt)*] { $($value:tt)* } $($rest:tt)*) => {
+        $crate::__ssml!(@parse $frames [$($children)* (
+            $crate::__private::Node::Word(::std::string::String::from(::std::stringify!($($words)*)))
+        )] { $($value)* } $($rest)*)
+    };
+    (@words $frames:tt [$($children:tt)*] [$($words:tt)*] $text:literal $($rest:tt)*) => {
+        $crate::__ssml!(@parse $frames [$($children)* (
+            $crate::__private::Node::Word(::std::string::String::from(::std::stringify!($($words)*)))
+        )] $text $($rest)*)
+    };
+    (@words $frames:tt $children:tt [$($words:tt)*] $word:tt $($rest:tt)*) => {
+        $crate::__ssml!(@words $frames $children [$($words)* $word] $($rest)*)
+    };
+    (@words $frames:tt [$($children:tt)*] [$($words:tt)*]) => {
+        $crate::__ssml!(@parse $frames [$($children)* (
+            $crate::__private::Node::Word(::std::string::String::from(::std::stringify!($($words)*)))
+        )])
+    };
+
+    // Attributes and the end of opening tags
+    (@open $frames:tt [$($children:tt)*] $tag:tt [$($attributes:tt)*] / > $($rest:tt)*) => {
+        $crate::__ssml!(@parse $frames [$($children)* ($crate::__ssml!(
+            @element $tag $tag [$($attributes)*] []
+        ))] $($rest)*)
+    };
+    (@open $frames:tt $children:tt (break) $attributes:tt > $($rest:tt)*) => {
+        ::std::compile_error!("<break> must be self-closing")
+    };
+    (@open $frames:tt $children:tt (mark) $attributes:tt > $($rest:tt)*) => {
+        ::std::compile_error!("<mark> must be self-closing")
+    };
+    (@open $frames:tt $children:tt (lexicon) $attributes:tt > $($rest:tt)*) => {
+        ::std::compile_error!("<lexicon> must be self-closing")
+    };
+    (@open [$($frames:tt)*] $children:tt $tag:tt $attributes:tt > $($rest:tt)*) => {
+        $crate::__ssml!(@parse [{ $tag $attributes $children } $($frames)*] [] $($rest)*)
+    };
+    (@open $frames:tt $children:tt $tag:tt [$($attributes:tt)*] xml : lang = $value:tt $($rest:tt)*) => {
+        $crate::__ssml!(@open $frames $children $tag [$($attributes)* ((xml : lang) $value)] $($rest)*)
+    };
+    (@open $frames:tt $children:tt $tag:tt [$($attributes:tt)*] interpret - as = $value:tt $($rest:tt)*) => {
+        $crate::__ssml!(@open $frames $children $tag [$($attributes)* ((interpret - as) $value)] $($rest)*)
+    };
+    (@open $frames:tt $children:tt $tag:tt [$($attributes:tt)*] $key:ident = $value:tt $($rest:tt)*) => {
+        $crate::__ssml!(@open $frames $children $tag [$($attributes)* (($key) $value)] $($rest)*)
+    };
+    (@open $frames:tt $children:tt $tag:tt $attributes:tt $($rest:tt)*) => {
+        ::std::compile_error!(::std::concat!(
+            "expected an attribute, `>` or `/>` in <", $crate::__ssml!(@name $tag), ">"
+        ))
+    };
+
+    // Completed elements
+    (@element $open:tt $close:tt [$(($key:tt $value:tt))*] [$($children:tt)*]) => {{
+        $crate::__ssml!(@close $open $close);
+        $crate::__private::Node::Element($crate::__private::element(
+            $crate::__ssml!(@name $open),
+            ::std::vec![$(($crate::__ssml!(@key $open $key), $crate::__ssml!(@value $open $key $value))),*],
+            ::std::vec![$($children),*],
+        ))
+    }};
+
+    // Checks that a closing tag matches its opening tag
+    (@close (speak) (speak)) => {};
+    (@close (voice) (voice)) => {};
+    (@close (p) (p)) => {};
+    (@close (s) (s)) => {};
+    (@close (phoneme) (phoneme)) => {};
+    (@close (say - as) (say - as)) => {};
+    (@close (sub) (sub)) => {};
+    (@close (prosody) (prosody)) => {};
+    (@close (emphasis) (emphasis)) => {};
+    (@close (break) (break)) => {};
+    (@close (mark) (mark)) => {};
+    (@close (audio) (audio)) => {};
+    (@close (desc) (desc)) => {};
+    (@close (lexicon) (lexicon)) => {};
+    (@close (lang) (lang)) => {};
+    (@close $open:tt ($($close:tt)*)) => {
+        ::std::compile_error!(::std::concat!(
+            "mismatched closing tag </", ::std::stringify!($($close)*), "> for <",
+            $crate::__ssml!(@name $open), ">"
+        ))
+    };
+
+    // Element names
+    (@name (speak)) => { "speak" };
+    (@name (voice)) => { "voice" };
+    (@name (p)) => { "p" };
+    (@name (s)) => { "s" };
+    (@name (phoneme)) => { "phoneme" };
+    (@name (say - as)) => { "say-as" };
+    (@name (sub)) => { "sub" };
+    (@name (prosody)) => { "prosody" };
+    (@name (emphasis)) => { "emphasis" };
+    (@name (break)) => { "break" };
+    (@name (mark)) => { "mark" };
+    (@name (audio)) => { "audio" };
+    (@name (desc)) => { "desc" };
+    (@name (lexicon)) => { "lexicon" };
+    (@name (lang)) => { "lang" };
+    (@name ($($tag:tt)*)) => {
+        ::std::compile_error!(::std::concat!("unknown element <", ::std::stringify!($($tag)*), ">"))
+    };
+
+    // Attribute names by element
+    (@key (speak) (version)) => { "version" };
+    (@key (speak) (xmlns)) => { "xmlns" };
+    (@key (speak) (xml : lang)) => { "xml:lang" };
+    (@key (voice) (name)) => { "name" };
+    (@key (phoneme) (alphabet)) => { "alphabet" };
+    (@key (phoneme) (ph)) => { "ph" };
+    (@key (say - as) (interpret - as)) => { "interpret-as" };
+    (@key (say - as) (format)) => { "format" };
+    (@key (say - as) (detail)) => { "detail" };
+    (@key (sub) (alias)) => { "alias" };
+    (@key (prosody) (rate)) => { "rate" };
+    (@key (prosody) (pitch)) => { "pitch" };
+    (@key (prosody) (contour)) => { "contour" };
+    (@key (prosody) (range)) => { "range" };
+    (@key (prosody) (volume)) => { "volume" };
+    (@key (emphasis) (level)) => { "level" };
+    (@key (break) (time)) => { "time" };
+    (@key (break) (strength)) => { "strength" };
+    (@key (mark) (name)) => { "name" };
+    (@key (audio) (src)) => { "src" };
+    (@key (lexicon) (uri)) => { "uri" };
+    (@key (lang) (xml : lang)) => { "xml:lang" };
+    (@key $tag:tt ($($key:tt)*)) => {
+        ::std::compile_error!(::std::concat!(
+            "unknown attribute `", ::std::stringify!($($key)*), "` on <",
+            $crate::__ssml!(@name $tag), ">"
+        ))
+    };
+
+    // Attribute values, checking literals the document model cannot represent and taking
+    // typed values where the model has them
+    (@value (break) (time) { $($value:tt)* }) => {
+        $crate::__private::Value::Time({ $($value)* })
+    };
+    (@value (break) (strength) { $($value:tt)* }) => {
+        $crate::__private::Value::Strength({ $($value)* })
+    };
+    (@value $tag:tt $key:tt { $($value:tt)* }) => {
+        $crate::__private::Value::Text(::std::string::ToString::to_string(&{ $($value)* }))
+    };
+    (@value (break) (time) $value:literal) => {{
+        const _: () = ::std::assert!(
+            $crate::__private::is_time($value),
+            ::std::concat!("invalid <break> time ", ::std::stringify!($value))
+        );
+        $crate::__private::Value::Text(::std::string::String::from($value))
+    }};
+    (@value (break) (strength) $value:literal) => {{
+        const _: () = ::std::assert!(
+            $crate::__private::is_strength($value),
+            ::std::concat!("invalid <break> strength ", ::std::stringify!($value))
+        );
+        $crate::__private::Value::Text(::std::string::String::from($value))
+    }};
+    (@value $tag:tt $key:tt $value:literal) => {
+        $crate::__private::Value::Text(::std::string::String::from($value))
+    };
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use std::{borrow::Cow, time::Duration};
+
+    use crate::{BreakStrength, SSML, SsmlElement, borrowed, parser};
+
+    /// Content of an element as written in the macro.
+    #[derive(Debug)]
+    pub enum Node {
+        /// A bare word or interpolated value, separated from neighbouring words by a space.
+        Word(String),
+        /// A string literal, inserted verbatim.
+        Verbatim(String),
+        /// A nested element.
+        Element(SsmlElement),
+    }
+
+    /// An attribute value as written in the macro.
+    #[derive(Debug)]
+    pub enum Value {
+        /// A literal or interpolated value, as it would be written in markup.
+        Text(String),
+        /// An interpolated `<break>` time.
+        Time(Duration),
+        /// An interpolated `<break>` strength.
+        Strength(BreakStrength),
+    }
+
+    /// Literals that may appear as text.
+    pub trait Literal {
+        /// Returns the literal as content.
+        fn into_node(self) -> Node;
+    }
+
+    impl Literal for &str {
+        fn into_node(self) -> Node {
+            Node::Verbatim(self.to_string())
+        }
+    }
+
+    macro_rules! word_literals {
+        ($($ty:ty),*) => {
+            $(impl Literal for $ty {
+                fn into_node(self) -> Node {
+                    Node::Word(self.to_string())
+                }
+            })*
+        };
+    }
+
+    word_literals!(i32, i64, u32, u64, f32, f64, bool, char);
+
+    /// Returns a document with the given top-level nodes.
+    pub fn document(nodes: Vec<Node>) -> SSML {
+        SSML {
+            elements: children(nodes),
+        }
+    }
+
+    /// Returns an element; names and attributes have been checked by the macro.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a value cannot be represented by the document model. Literal values are
+    /// checked while compiling and typed values are always valid, so this does not happen.
+    pub fn element(name: &str, attributes: Vec<(&str, Value)>, nodes: Vec<Node>) -> SsmlElement {
+        let mut element = parser::new_element(name).expect("element names are checked");
+        for (key, value) in attributes {
+            match (&mut element, value) {
+                (borrowed::SsmlElement::Break { time, .. }, Value::Time(value)) => {
+                    *time = Some(value)
+                }
+                (borrowed::SsmlElement::Break { strength, .. }, Value::Strength(value)) => {
+                    *strength = Some(value)
+                }
+                (element, Value::Text(value)) => {
+                    if let Err(value) = parser::set_attribute(element, key, Cow::Owned(value)) {
+                        panic!("invalid value {value:?} for `{key}` on <{name}>");
+                    }
+                }
+                (_, value) => panic!("invalid value {value:?} for `{key}` on <{name}>"),
+            }
+        }
+
+        let mut element = element.into_owned();
+        if let Some(children) = element.children_mut() {
+            *children = self::children(nodes);
+        }
+        element
+    }
+
+    // Joins runs of text following the spacing rules of the macro, trimming them like the
+    // default parser does
+    fn children(nodes: Vec<Node>) -> Vec<SsmlElement> {
+        let mut children = Vec::new();
+        let mut text = String::new();
+        let mut after_word = false;
+        for node in nodes {
+            match node {
+                Node::Word(word) => {
+                    if word.is_empty() {
+                        continue;
+                    }
+                    if after_word {
+                        text.push(' ');
+                    }
+                    text.push_str(&word);
+                    after_word = true;
+                }
+                Node::Verbatim(literal) => {
+                    text.push_str(&literal);
+                    after_word = false;
+                }
+                Node::Element(element) => {
+                    flush(&mut text, &mut children);
+                    after_word = false;
+                    children.push(element);
+                }
+            }
+        }
+        flush(&mut text, &mut children);
+        children
+    }
+
+    fn flush(text: &mut String, children: &mut Vec<SsmlElement>) {
+        let trimmed = text.trim();
+        if !trimmed.is_empty() {
+            children.push(SsmlElement::Text(trimmed.to_string()));
+        }
+        text.clear();
+    }
+
+    /// Returns whether `time` is a whole number of seconds or milliseconds, e.g. `2s` or
+    /// `500ms`. This is a subset of the times the parser accepts.
+    pub const fn is_time(time: &str) -> bool {
+        let bytes = time.as_bytes();
+        let mut index = 0;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        if index == 0 {
+            return false;
+        }
+        match bytes.len() - index {
+            1 => bytes[index] == b's',
+            2 => bytes[index] == b'm' && bytes[index + 1] == b's',
+            _ => false,
+        }
+    }
+
+    /// Returns whether `strength` is a valid `<break>` strength.
+    pub const fn is_strength(strength: &str) -> bool {
+        const STRENGTHS: [&str; 6] = ["none", "x-weak", "weak", "medium", "strong", "x-strong"];
+        let mut index = 0;
+        while index < STRENGTHS.len() {
+            if equals(STRENGTHS[index], strength) {
+                return true;
+            }
+            index += 1;
+        }
+        false
+    }
+
+    const fn equals(a: &str, b: &str) -> bool {
+        let (a, b) = (a.as_bytes(), b.as_bytes());
+        if a.len() != b.len() {
+            return false;
+        }
+        let mut index = 0;
+        while index < a.len() {
+            if a[index] != b[index] {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+}
@@ -0,0 +1,322 @@
+//! Three-way merging of documents.
+
+use std::fmt;
+
+use crate::{
+    NodePath, SSML, SsmlElement,
+    diff::{Aligned, align, attribute},
+};
+
+/// A change made differently on both sides of a [`merge`].
+///
+/// Paths refer to the merged document, where the conflicting content holds our version.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Conflict {
+    /// An attribute that was changed to different values.
+    Attribute {
+        /// Path of the element.
+        path: NodePath,
+        /// Name of the attribute.
+        name: &'static str,
+        /// The value in the base document, if set.
+        base: Option<String>,
+        /// Our value, if set.
+        ours: Option<String>,
+        /// Their value, if set.
+        theirs: Option<String>,
+    },
+    /// A text node that was changed to different texts.
+    Text {
+        /// Path of the text node.
+        path: NodePath,
+        /// The text in the base document.
+        base: String,
+        /// Our text.
+        ours: String,
+        /// Their text.
+        theirs: String,
+    },
+    /// A run of sibling nodes that was changed differently, e.g. removed on one side and
+    /// edited on the other, or replaced by different nodes.
+    Nodes {
+        /// Path of the first of our nodes, or where they would be if there are none.
+        path: NodePath,
+        /// The nodes in the base document.
+        base: Vec<SsmlElement>,
+        /// Our nodes.
+        ours: Vec<SsmlElement>,
+        /// Their nodes.
+        theirs: Vec<SsmlElement>,
+    },
+}
+
+impl Conflict {
+    /// Returns the path of the conflict in the merged document.
+    pub fn path(&self) -> &NodePath {
+        match self {
+            Conflict::Attribute { path, .. }
+            | Conflict::Text { path, .. }
+            | Conflict::Nodes { path, .. } => path,
+        }
+    }
+}
+
+impl fmt::Display for Conflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn value(value: &Option<String>) -> String {
+            value
+                .as_ref()
+                .map_or_else(|| "(none)".to_string(), |value| format!("{value:?}"))
+        }
+        fn nodes(nodes: &[SsmlElement]) -> String {
+            crate::to_fragment_string(nodes)
+        }
+
+        match self {
+            Conflict::Attribute {
+                path,
+                name,
+                base,
+                ours,
+                theirs,
+            } => write!(
+                f,
+                "{path} @{name}: base {}, ours {}, theirs {}",
+                value(base),
+                value(ours),
+                value(theirs)
+            ),
+            Conflict::Text {
+                path,
+                base,
+                ours,
+                theirs,
+            } => write!(f, "{path}: base {base:?}, ours {ours:?}, theirs {theirs:?}"),
+            Conflict::Nodes {
+                path,
+                base,
+                ours,
+                theirs,
+            } => write!(
+                f,
+                "{path}: base {:?}, ours {:?}, theirs {:?}",
+                nodes(base),
+                nodes(ours),
+                nodes(theirs)
+            ),
+        }
+    }
+}
+
+/// The error returned by [`merge`] when both sides changed the same content differently.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MergeError {
+    merged: SSML,
+    conflicts: Vec<Conflict>,
+}
+
+impl MergeError {
+    /// Returns the merged document, holding our version wherever there is a conflict.
+    pub fn merged(&self) -> &SSML {
+        &self.merged
+    }
+
+    /// Returns the merged document, holding our version wherever there is a conflict.
+    pub fn into_merged(self) -> SSML {
+        self.merged
+    }
+
+    /// Returns the conflicts in document order.
+    pub fn conflicts(&self) -> &[Conflict] {
+        &self.conflicts
+    }
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} merge conflict(s)", self.conflicts.len())?;
+        for conflict in &self.conflicts {
+            write!(f, "\n{conflict}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for MergeError {}
+
+/// Merges the changes made to `base` in `ours` and in `theirs`.
+///
+/// Changes to different nodes, and to different attributes of the same element, are
+/// combined. Nodes are matched up between the documents as in [`crate::diff`]. Changes that
+/// overlap but agree are applied once. All other overlapping changes are reported as
+/// [`Conflict`]s, together with a merged document that keeps our side for each of them.
+///
+/// # Examples
+///
+/// ```rust
+/// let base = serde_ssml::from_str(r#"<speak><prosody rate="slow">Helo</prosody></speak>"#).unwrap();
+/// let ours = serde_ssml::from_str(r#"<speak><prosody rate="fast">Helo</prosody></speak>"#).unwrap();
+/// let theirs = serde_ssml::from_str(r#"<speak><prosody rate="slow">Hello</prosody></speak>"#).unwrap();
+///
+/// let merged = serde_ssml::merge(&base, &ours, &theirs).unwrap();
+/// assert_eq!(merged.to_string(), r#"<speak><prosody rate="fast">Hello</prosody></speak>"#);
+/// ```
+pub fn merge(base: &SSML, ours: &SSML, theirs: &SSML) -> Result<SSML, MergeError> {
+    let mut conflicts = Vec::new();
+    let elements = merge_nodes(
+        &base.elements,
+        &ours.elements,
+        &theirs.elements,
+        &NodePath::root(),
+        &mut conflicts,
+    );
+
+    let merged = SSML { elements };
+    if conflicts.is_empty() {
+        Ok(merged)
+    } else {
+        Err(MergeError { merged, conflicts })
+    }
+}
+
+fn merge_nodes(
+    base: &[SsmlElement],
+    ours: &[SsmlElement],
+    theirs: &[SsmlElement],
+    path: &NodePath,
+    conflicts: &mut Vec<Conflict>,
+) -> Vec<SsmlElement> {
+    if let Some(merged) = trivial(base, ours, theirs) {
+        return merged.to_vec();
+    }
+
+    // Base nodes with a counterpart on both sides anchor the merge; the runs between them
+    // are merged as a whole
+    let ours_matches = matches(base, ours);
+    let theirs_matches = matches(base, theirs);
+    let anchors = (0..base.len())
+        .filter_map(|b| Some((b, ours_matches[b]?, theirs_matches[b]?)))
+        .chain([(base.len(), ours.len(), theirs.len())]);
+
+    let mut merged = Vec::new();
+    let (mut b, mut o, mut t) = (0, 0, 0);
+    for (next_b, next_o, next_t) in anchors {
+        let (base_run, ours_run, theirs_run) =
+            (&base[b..next_b], &ours[o..next_o], &theirs[t..next_t]);
+        match trivial(base_run, ours_run, theirs_run) {
+            Some(run) => merged.extend_from_slice(run),
+            None => {
+                conflicts.push(Conflict::Nodes {
+                    path: path.child(merged.len()),
+                    base: base_run.to_vec(),
+                    ours: ours_run.to_vec(),
+                    theirs: theirs_run.to_vec(),
+                });
+                merged.extend_from_slice(ours_run);
+            }
+        }
+
+        if next_b < base.len() {
+            let node = merge_node(
+                &base[next_b],
+                &ours[next_o],
+                &theirs[next_t],
+                &path.child(merged.len()),
+                conflicts,
+            );
+            merged.push(node);
+        }
+        (b, o, t) = (next_b + 1, next_o + 1, next_t + 1);
+    }
+    merged
+}
+
+// Merges three versions of a node of the same kind
+fn merge_node(
+    base: &SsmlElement,
+    ours: &SsmlElement,
+    theirs: &SsmlElement,
+    path: &NodePath,
+    conflicts: &mut Vec<Conflict>,
+) -> SsmlElement {
+    if let Some([merged]) = trivial(
+        std::slice::from_ref(base),
+        std::slice::from_ref(ours),
+        std::slice::from_ref(theirs),
+    ) {
+        return merged.clone();
+    }
+
+    if let (SsmlElement::Text(base), SsmlElement::Text(ours), SsmlElement::Text(theirs)) =
+        (base, ours, theirs)
+    {
+        conflicts.push(Conflict::Text {
+            path: path.clone(),
+            base: base.clone(),
+            ours: ours.clone(),
+            theirs: theirs.clone(),
+        });
+        return SsmlElement::Text(ours.clone());
+    }
+
+    let mut merged = ours.clone();
+    for &name in ours.kind().attributes() {
+        let values = (
+            attribute(base, name),
+            attribute(ours, name),
+            attribute(theirs, name),
+        );
+        match values {
+            (base, ours, theirs) if ours == theirs || theirs == base => {}
+            (base, ours, _) if ours == base => {
+                if let (Some(target), Some(value)) =
+                    (merged.attribute_mut(name), theirs.attribute(name))
+                {
+                    target.set(value);
+                }
+            }
+            (base, ours, theirs) => conflicts.push(Conflict::Attribute {
+                path: path.clone(),
+                name,
+                base,
+                ours,
+                theirs,
+            }),
+        }
+    }
+
+    let children = merge_nodes(
+        base.children(),
+        ours.children(),
+        theirs.children(),
+        path,
+        conflicts,
+    );
+    if let Some(slot) = merged.children_mut() {
+        *slot = children;
+    }
+    merged
+}
+
+// Returns the merge of three versions if at most one side changed, or both made the same
+// change
+fn trivial<'a, T: PartialEq + ?Sized>(base: &T, ours: &'a T, theirs: &'a T) -> Option<&'a T> {
+    if ours == theirs || theirs == base {
+        Some(ours)
+    } else if ours == base {
+        Some(theirs)
+    } else {
+        None
+    }
+}
+
+// Returns the index of the counterpart of each base node in `other`, if it has one
+fn matches(base: &[SsmlElement], other: &[SsmlElement]) -> Vec<Option<usize>> {
+    let mut matches = vec![None; base.len()];
+    for aligned in align(base, other) {
+        if let Aligned::Both(b, o) = aligned {
+            matches[b] = Some(o);
+        }
+    }
+    matches
+}
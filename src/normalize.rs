@@ -0,0 +1,348 @@
+//! Simplification of document trees without changing what they say.
+
+use std::{fmt, mem};
+
+use crate::{ElementKind, SSML, SerializeOptions, SsmlElement, ser};
+
+/// A simplification applied by [`SSML::normalize_with`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NormalizeRule {
+    /// Merges adjacent text nodes into one.
+    MergeText,
+    /// Removes text nodes with empty text.
+    RemoveEmptyText,
+    /// Removes `<voice>`, `<p>`, `<s>`, `<prosody>`, `<emphasis>`, `<say-as>`, `<desc>` and
+    /// `<lang>` elements without content. Elements that say something even when empty, such
+    /// as `<audio>`, `<sub>` and `<phoneme>`, are kept, as is `<speak>`.
+    RemoveEmptyContainers,
+    /// Replaces `<prosody>` elements without attributes by their content.
+    UnwrapEmptyProsody,
+    /// Replaces an element nested directly in an element of the same kind with the same
+    /// attributes, such as `<p>` inside `<p>`, by its content. `<prosody>` is left alone, as
+    /// relative values such as `+10%` compound.
+    FlattenNesting,
+    /// Combines a `<prosody>` whose only child is another `<prosody>` into one element when
+    /// they set different attributes. Relative values such as `+10%` would compound, so
+    /// elements setting the same attribute are kept apart.
+    MergeProsody,
+}
+
+impl NormalizeRule {
+    /// All rules, in the order they are listed in a [`NormalizeReport`].
+    pub const ALL: [NormalizeRule; 6] = [
+        NormalizeRule::MergeText,
+        NormalizeRule::RemoveEmptyText,
+        NormalizeRule::RemoveEmptyContainers,
+        NormalizeRule::UnwrapEmptyProsody,
+        NormalizeRule::FlattenNesting,
+        NormalizeRule::MergeProsody,
+    ];
+
+    fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// Options for [`SSML::normalize_with`], selecting the rules to apply.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{NormalizeOptions, NormalizeRule};
+///
+/// let mut ssml = serde_ssml::from_str("<speak><p><p>Hi</p></p><s></s></speak>").unwrap();
+/// let options = NormalizeOptions::new().rule(NormalizeRule::RemoveEmptyContainers, false);
+/// let report = ssml.normalize_with(&options);
+///
+/// assert_eq!(ssml.to_string(), "<speak><p>Hi</p><s></s></speak>");
+/// assert_eq!(report.count(NormalizeRule::FlattenNesting), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NormalizeOptions {
+    rules: [bool; NormalizeRule::ALL.len()],
+}
+
+impl NormalizeOptions {
+    /// Creates options with every rule enabled.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates options with every rule disabled, to enable rules one by one.
+    pub fn none() -> Self {
+        Self {
+            rules: [false; NormalizeRule::ALL.len()],
+        }
+    }
+
+    /// Enables or disables a rule.
+    pub fn rule(mut self, rule: NormalizeRule, enabled: bool) -> Self {
+        self.rules[rule.index()] = enabled;
+        self
+    }
+
+    /// Returns whether a rule is enabled.
+    pub fn is_enabled(&self, rule: NormalizeRule) -> bool {
+        self.rules[rule.index()]
+    }
+}
+
+impl Default for NormalizeOptions {
+    fn default() -> Self {
+        Self {
+            rules: [true; NormalizeRule::ALL.len()],
+        }
+    }
+}
+
+/// The changes made by [`SSML::normalize`], counted per rule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NormalizeReport {
+    counts: [usize; NormalizeRule::ALL.len()],
+}
+
+impl NormalizeReport {
+    /// Returns how many times a rule was applied.
+    pub fn count(&self, rule: NormalizeRule) -> usize {
+        self.counts[rule.index()]
+    }
+
+    /// Returns the total number of changes.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Returns whether the document was left unchanged.
+    pub fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+
+    fn record(&mut self, rule: NormalizeRule) {
+        self.counts[rule.index()] += 1;
+    }
+}
+
+impl fmt::Display for NormalizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return f.write_str("no changes");
+        }
+        let mut separator = "";
+        for rule in NormalizeRule::ALL {
+            let count = self.count(rule);
+            if count == 0 {
+                continue;
+            }
+            let description = match rule {
+                NormalizeRule::MergeText => "merged adjacent text",
+                NormalizeRule::RemoveEmptyText => "removed empty text",
+                NormalizeRule::RemoveEmptyContainers => "removed empty elements",
+                NormalizeRule::UnwrapEmptyProsody => "unwrapped empty prosody",
+                NormalizeRule::FlattenNesting => "flattened nested elements",
+                NormalizeRule::MergeProsody => "merged nested prosody",
+            };
+            write!(f, "{separator}{description}: {count}")?;
+            separator = ", ";
+        }
+        Ok(())
+    }
+}
+
+impl SSML {
+    /// Simplifies the document with every [`NormalizeRule`], returning what was changed.
+    ///
+    /// Normalization only changes the tree, not what a speech engine says; see
+    /// [`crate::canonicalize`] for whitespace and default attribute values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SSML;
+    ///
+    /// let mut ssml: SSML = r#"<speak><prosody rate="slow"><prosody pitch="high">Hi</prosody></prosody><p></p></speak>"#
+    ///     .parse()
+    ///     .unwrap();
+    /// let report = ssml.normalize();
+    ///
+    /// assert_eq!(
+    ///     ssml.to_string(),
+    ///     r#"<speak><prosody rate="slow" pitch="high">Hi</prosody></speak>"#
+    /// );
+    /// assert_eq!(report.to_string(), "removed empty elements: 1, merged nested prosody: 1");
+    /// ```
+    pub fn normalize(&mut self) -> NormalizeReport {
+        self.normalize_with(&NormalizeOptions::default())
+    }
+
+    /// Simplifies the document with the rules enabled in `options`, returning what was
+    /// changed.
+    ///
+    /// Unwrapping an element can enable further rules for its children, so the rules are
+    /// applied until the document no longer changes. Normalizing again has no effect.
+    pub fn normalize_with(&mut self, options: &NormalizeOptions) -> NormalizeReport {
+        let mut report = NormalizeReport::default();
+        loop {
+            let mut pass = NormalizeReport::default();
+            normalize(&mut self.elements, None, options, &mut pass);
+            if pass.is_empty() {
+                return report;
+            }
+            for (total, count) in report.counts.iter_mut().zip(pass.counts) {
+                *total += count;
+            }
+        }
+    }
+}
+
+// Normalizes `nodes` bottom-up; `parent` is the element containing them, without its children
+fn normalize(
+    nodes: &mut Vec<SsmlElement>,
+    parent: Option<&SsmlElement>,
+    options: &NormalizeOptions,
+    report: &mut NormalizeReport,
+) {
+    for mut node in mem::take(nodes) {
+        if let Some(children) = node.children_mut() {
+            let mut children = mem::take(children);
+            normalize(&mut children, Some(&node), options, report);
+            if let Some(slot) = node.children_mut() {
+                *slot = children;
+            }
+        }
+        if options.is_enabled(NormalizeRule::MergeProsody) && merge_prosody(&mut node) {
+            report.record(NormalizeRule::MergeProsody);
+        }
+
+        if matches!(&node, SsmlElement::Text(text) if text.is_empty())
+            && options.is_enabled(NormalizeRule::RemoveEmptyText)
+        {
+            report.record(NormalizeRule::RemoveEmptyText);
+            continue;
+        }
+
+        let unwrap = if is_empty_prosody(&node) {
+            Some(NormalizeRule::UnwrapEmptyProsody)
+        } else if node.kind().is_container()
+            && node.kind() != ElementKind::Prosody
+            && parent.is_some_and(|parent| same_element(parent, &node))
+        {
+            Some(NormalizeRule::FlattenNesting)
+        } else {
+            None
+        };
+        if let Some(rule) = unwrap
+            && options.is_enabled(rule)
+            && let Some(children) = node.children_mut()
+        {
+            report.record(rule);
+            nodes.append(children);
+            continue;
+        }
+
+        if is_removable(&node)
+            && node.children().is_empty()
+            && options.is_enabled(NormalizeRule::RemoveEmptyContainers)
+        {
+            report.record(NormalizeRule::RemoveEmptyContainers);
+            continue;
+        }
+
+        nodes.push(node);
+    }
+
+    if options.is_enabled(NormalizeRule::MergeText) {
+        merge_text(nodes, report);
+    }
+}
+
+fn merge_text(nodes: &mut Vec<SsmlElement>, report: &mut NormalizeReport) {
+    for node in mem::take(nodes) {
+        if let (Some(SsmlElement::Text(previous)), SsmlElement::Text(text)) =
+            (nodes.last_mut(), &node)
+        {
+            previous.push_str(text);
+            report.record(NormalizeRule::MergeText);
+        } else {
+            nodes.push(node);
+        }
+    }
+}
+
+// Combines a prosody with its only child when that is a prosody setting other attributes
+fn merge_prosody(node: &mut SsmlElement) -> bool {
+    let SsmlElement::Prosody {
+        rate,
+        pitch,
+        contour,
+        range,
+        volume,
+        children,
+    } = node
+    else {
+        return false;
+    };
+    let [
+        SsmlElement::Prosody {
+            rate: inner_rate,
+            pitch: inner_pitch,
+            contour: inner_contour,
+            range: inner_range,
+            volume: inner_volume,
+            children: inner_children,
+        },
+    ] = children.as_mut_slice()
+    else {
+        return false;
+    };
+
+    let mut attributes = [
+        (rate, inner_rate),
+        (pitch, inner_pitch),
+        (contour, inner_contour),
+        (range, inner_range),
+        (volume, inner_volume),
+    ];
+    if attributes
+        .iter()
+        .any(|(outer, inner)| !outer.is_empty() && !inner.is_empty())
+    {
+        return false;
+    }
+    for (outer, inner) in &mut attributes {
+        if outer.is_empty() {
+            **outer = mem::take(*inner);
+        }
+    }
+    let inner_children = mem::take(inner_children);
+    *children = inner_children;
+    true
+}
+
+fn is_empty_prosody(node: &SsmlElement) -> bool {
+    matches!(
+        node,
+        SsmlElement::Prosody { rate, pitch, contour, range, volume, .. }
+            if rate.is_empty() && pitch.is_empty() && contour.is_empty() && range.is_empty() && volume.is_empty()
+    )
+}
+
+// Whether the elements have the same kind and attributes, ignoring their children
+fn same_element(a: &SsmlElement, b: &SsmlElement) -> bool {
+    let options = SerializeOptions::default();
+    a.kind() == b.kind() && ser::attributes(a, &options) == ser::attributes(b, &options)
+}
+
+// Containers that say nothing without content
+fn is_removable(node: &SsmlElement) -> bool {
+    matches!(
+        node.kind(),
+        ElementKind::Voice
+            | ElementKind::Paragraph
+            | ElementKind::Sentence
+            | ElementKind::Prosody
+            | ElementKind::Emphasis
+            | ElementKind::SayAs
+            | ElementKind::Desc
+            | ElementKind::Lang
+    )
+}
@@ -0,0 +1,945 @@
+use std::borrow::Cow;
+
+use crate::{
+    ElementKind, Error, ErrorKind, Limit, ParseError,
+    borrowed::{SSML, SsmlElement},
+    escape::unescape,
+};
+
+/// Default maximum nesting depth, see [`ParseOptions::max_depth`].
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// The SSML namespace URI, required on `<speak>` by [`Namespaces::Require`].
+pub const SSML_NAMESPACE: &str = "http://www.w3.org/2001/10/synthesis";
+
+/// Resource limits applied while parsing untrusted SSML.
+///
+/// Exceeding any limit makes parsing fail with [`ErrorKind::LimitExceeded`] instead of
+/// exhausting memory or overflowing the stack in code that walks the resulting tree.
+///
+/// The default nesting depth is [`DEFAULT_MAX_DEPTH`]; every other limit is unbounded.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{ErrorKind, Limit, ParseOptions};
+///
+/// let options = ParseOptions::new().max_depth(2).max_elements(100);
+///
+/// let result = serde_ssml::from_str_with_options("<speak><p><s>Hi</s></p></speak>", &options);
+/// let errors = result.unwrap_err();
+/// assert_eq!(errors[0].kind(), &ErrorKind::LimitExceeded(Limit::Depth(2)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    max_depth: usize,
+    max_elements: usize,
+    max_input_len: usize,
+    max_text_len: usize,
+    max_attributes: usize,
+}
+
+impl ParseOptions {
+    /// Creates options with the default limits.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Maximum nesting depth of elements; a top-level element has depth 1.
+    pub fn max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    /// Maximum number of elements in the document, not counting text runs.
+    pub fn max_elements(mut self, max_elements: usize) -> Self {
+        self.max_elements = max_elements;
+        self
+    }
+
+    /// Maximum length of the whole input in bytes.
+    pub fn max_input_len(mut self, max_input_len: usize) -> Self {
+        self.max_input_len = max_input_len;
+        self
+    }
+
+    /// Maximum length in bytes of a single text run, before entity decoding.
+    pub fn max_text_len(mut self, max_text_len: usize) -> Self {
+        self.max_text_len = max_text_len;
+        self
+    }
+
+    /// Maximum number of attributes on a single element.
+    pub fn max_attributes(mut self, max_attributes: usize) -> Self {
+        self.max_attributes = max_attributes;
+        self
+    }
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_elements: usize::MAX,
+            max_input_len: usize::MAX,
+            max_text_len: usize::MAX,
+            max_attributes: usize::MAX,
+        }
+    }
+}
+
+/// How whitespace in text content is handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Whitespace {
+    /// Trim leading and trailing whitespace from every text run and drop whitespace-only runs.
+    #[default]
+    Trim,
+
+    /// Keep text exactly as written, including whitespace-only runs inside elements.
+    Preserve,
+
+    /// Collapse every run of whitespace to a single space and drop whitespace-only runs.
+    Collapse,
+}
+
+/// How elements outside the supported SSML vocabulary are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum UnknownElements {
+    /// Fail with [`ErrorKind::UnknownElement`].
+    #[default]
+    Reject,
+
+    /// Drop the tags but keep their content, e.g. for vendor extensions like `<amazon:effect>`.
+    Unwrap,
+
+    /// Drop the element together with its content.
+    Skip,
+}
+
+/// How XML namespaces are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Namespaces {
+    /// Match element names literally, so `<ssml:speak>` is an unknown element.
+    #[default]
+    Ignore,
+
+    /// Strip any prefix from element names before matching, so `<ssml:s>` is a sentence.
+    StripPrefixes,
+
+    /// Require every `<speak>` element to declare [`SSML_NAMESPACE`] as its `xmlns`.
+    Require,
+}
+
+/// A configurable SSML parser.
+///
+/// The default configuration matches [`crate::from_str`]: lenient attribute handling,
+/// trimmed text, unknown elements rejected, any top-level content accepted, namespaces
+/// ignored and the default [`ParseOptions`] limits. A `Parser` holds only configuration,
+/// so it can be built once and reused for any number of documents.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{Parser, UnknownElements, Whitespace};
+///
+/// let parser = Parser::new()
+///     .strict(true)
+///     .require_root(true)
+///     .whitespace(Whitespace::Collapse)
+///     .unknown_elements(UnknownElements::Unwrap);
+///
+/// let ssml = parser
+///     .parse(r#"<speak><amazon:effect name="whispered">Hello,   world</amazon:effect></speak>"#)
+///     .unwrap();
+/// assert_eq!(serde_ssml::to_string(&ssml), "<speak>Hello, world</speak>");
+///
+/// // Strict mode rejects values the document model cannot represent
+/// assert!(parser.parse(r#"<speak><break time="soon"/></speak>"#).is_err());
+/// // and a required root rejects anything but a single <speak> element
+/// assert!(parser.parse("Hello").is_err());
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Parser {
+    limits: ParseOptions,
+    strict: bool,
+    whitespace: Whitespace,
+    unknown_elements: UnknownElements,
+    require_root: bool,
+    namespaces: Namespaces,
+}
+
+impl Parser {
+    /// Creates a parser with the default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// In strict mode, attribute values the document model cannot represent (such as an
+    /// unparseable `<break time>`) fail with [`ErrorKind::InvalidAttribute`], and elements
+    /// missing a required attribute (such as `<say-as>` without `interpret-as`) fail with
+    /// [`ErrorKind::MissingAttribute`]. Lenient mode, the default, ignores both.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Sets how whitespace in text content is handled.
+    pub fn whitespace(mut self, whitespace: Whitespace) -> Self {
+        self.whitespace = whitespace;
+        self
+    }
+
+    /// Sets how elements outside the supported SSML vocabulary are handled.
+    pub fn unknown_elements(mut self, unknown_elements: UnknownElements) -> Self {
+        self.unknown_elements = unknown_elements;
+        self
+    }
+
+    /// Requires the document to consist of a single `<speak>` element, failing with
+    /// [`ErrorKind::InvalidRoot`] otherwise.
+    pub fn require_root(mut self, require_root: bool) -> Self {
+        self.require_root = require_root;
+        self
+    }
+
+    /// Sets how XML namespaces are handled.
+    pub fn namespaces(mut self, namespaces: Namespaces) -> Self {
+        self.namespaces = namespaces;
+        self
+    }
+
+    /// Sets the resource limits applied while parsing.
+    pub fn limits(mut self, limits: ParseOptions) -> Self {
+        self.limits = limits;
+        self
+    }
+
+    /// Parses an SSML string into an owned [`crate::SSML`] document.
+    pub fn parse(&self, input: &str) -> Result<crate::SSML, ParseError> {
+        self.parse_borrowed(input).map(SSML::into_owned)
+    }
+
+    /// Parses an SSML string into a [`crate::borrowed::SSML`] tree that borrows from `input`.
+    ///
+    /// This is a single pass over the input bytes. All markup characters are ASCII, so every
+    /// slice taken at a markup boundary is valid UTF-8 and can be borrowed directly. Nesting is
+    /// tracked with an explicit stack instead of recursion, and attributes are written straight
+    /// into the element being built, without an intermediate attribute map.
+    pub fn parse_borrowed<'a>(&self, input: &'a str) -> Result<SSML<'a>, ParseError> {
+        self.reader(input, None)
+            .document()
+            .map_err(|error| vec![error])
+    }
+
+    /// Parses inline SSML content, such as `Hello <break time="1s"/> world`, as if it appeared
+    /// directly inside an element of kind `context`.
+    ///
+    /// Unlike [`Parser::parse`], every element is checked against the SSML content model:
+    /// top-level nodes must be allowed inside `context` and nested ones inside their parent,
+    /// otherwise parsing fails with [`ErrorKind::InvalidChild`]. [`Parser::require_root`] does
+    /// not apply to fragments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{ElementKind, ErrorKind, Parser};
+    ///
+    /// let parser = Parser::new();
+    /// let fragment = parser
+    ///     .parse_fragment(r#"Hello <break time="1s"/> world"#, ElementKind::Sentence)
+    ///     .unwrap();
+    /// assert_eq!(fragment.len(), 3);
+    ///
+    /// let errors = parser.parse_fragment("<p>Hi</p>", ElementKind::Sentence).unwrap_err();
+    /// assert_eq!(
+    ///     errors[0].kind(),
+    ///     &ErrorKind::InvalidChild {
+    ///         parent: ElementKind::Sentence,
+    ///         child: ElementKind::Paragraph,
+    ///     }
+    /// );
+    /// ```
+    pub fn parse_fragment(
+        &self,
+        input: &str,
+        context: ElementKind,
+    ) -> Result<Vec<crate::SsmlElement>, ParseError> {
+        self.parse_fragment_borrowed(input, context)
+            .map(|elements| elements.into_iter().map(SsmlElement::into_owned).collect())
+    }
+
+    /// Parses inline SSML content like [`Parser::parse_fragment`], borrowing from `input`.
+    pub fn parse_fragment_borrowed<'a>(
+        &self,
+        input: &'a str,
+        context: ElementKind,
+    ) -> Result<Vec<SsmlElement<'a>>, ParseError> {
+        self.reader(input, Some(context))
+            .document()
+            .map(|ssml| ssml.elements)
+            .map_err(|error| vec![error])
+    }
+
+    // Parses like `parse`, also returning an `InvalidAttribute` error for every attribute value
+    // that lenient mode dropped
+    pub(crate) fn parse_with_dropped(
+        &self,
+        input: &str,
+    ) -> Result<(crate::SSML, Vec<Error>), ParseError> {
+        let mut reader = self.reader(input, None);
+        let ssml = reader.document().map_err(|error| vec![error])?;
+        Ok((ssml.into_owned(), reader.dropped))
+    }
+
+    fn reader<'a>(&self, input: &'a str, context: Option<ElementKind>) -> Reader<'a, '_> {
+        Reader {
+            input,
+            pos: 0,
+            parser: self,
+            context,
+            elements: 0,
+            skipping: 0,
+            dropped: Vec::new(),
+        }
+    }
+}
+
+// What an open tag turned into
+enum Node<'a> {
+    Element(SsmlElement<'a>),
+    // Unknown element whose content is kept
+    Unwrapped,
+    // Unknown element whose content is dropped
+    Skipped,
+}
+
+// An element whose opening tag has been read but whose closing tag has not
+struct Frame<'a> {
+    name: &'a str,
+    start: usize,
+    node: Node<'a>,
+}
+
+struct Reader<'a, 'p> {
+    input: &'a str,
+    pos: usize,
+    parser: &'p Parser,
+    // Kind of the element a fragment is parsed into; enables content model checks
+    context: Option<ElementKind>,
+    elements: usize,
+    // Number of open `Node::Skipped` frames
+    skipping: usize,
+    // Attribute values dropped in lenient mode
+    dropped: Vec<Error>,
+}
+
+impl<'a> Reader<'a, '_> {
+    fn document(&mut self) -> Result<SSML<'a>, Error> {
+        let limits = &self.parser.limits;
+        if self.input.len() > limits.max_input_len {
+            return Err(self.error(
+                limits.max_input_len,
+                ErrorKind::LimitExceeded(Limit::InputLength(limits.max_input_len)),
+            ));
+        }
+
+        self.skip_whitespace();
+        if self.rest().starts_with("<?xml") {
+            self.xml_declaration()?;
+        }
+
+        let mut elements = Vec::new();
+        let mut stack: Vec<Frame<'a>> = Vec::new();
+
+        while let Some(byte) = self.peek() {
+            let start = self.pos;
+
+            let element = if byte != b'<' {
+                let text = self.text();
+                match self.text_node(text, start, stack.is_empty())? {
+                    Some(text) => {
+                        self.check_child(&stack, ElementKind::Text, start)?;
+                        SsmlElement::Text(text)
+                    }
+                    None => continue,
+                }
+            } else if self.peek_at(1) == Some(b'/') {
+                let name = self.closing_tag()?;
+                let frame = match stack.pop() {
+                    Some(frame) if frame.name == name => frame,
+                    Some(frame) => {
+                        return Err(self.error(
+                            start,
+                            ErrorKind::MismatchedClosingTag {
+                                expected: frame.name.to_string(),
+                                found: name.to_string(),
+                            },
+                        ));
+                    }
+                    None => {
+                        return Err(
+                            self.error(start, ErrorKind::UnexpectedClosingTag(name.to_string()))
+                        );
+                    }
+                };
+                match frame.node {
+                    Node::Element(element) => element,
+                    Node::Unwrapped => continue,
+                    Node::Skipped => {
+                        self.skipping -= 1;
+                        continue;
+                    }
+                }
+            } else {
+                let max_depth = self.parser.limits.max_depth;
+                if stack.len() >= max_depth {
+                    return Err(
+                        self.error(start, ErrorKind::LimitExceeded(Limit::Depth(max_depth)))
+                    );
+                }
+
+                let (name, node, self_closing) = self.opening_tag()?;
+                if let Node::Element(element) = &node {
+                    self.check_child(&stack, element.kind(), start)?;
+                }
+                match node {
+                    Node::Element(element) if self_closing => element,
+                    Node::Element(element) if is_void(&element) => {
+                        // `<break ...></break>` style void elements must be closed immediately
+                        self.skip_whitespace();
+                        let close_start = self.pos;
+                        if self.rest().starts_with("</") && self.closing_tag()? == name {
+                            element
+                        } else {
+                            return Err(self.error(
+                                close_start,
+                                ErrorKind::UnexpectedContent(name.to_string()),
+                            ));
+                        }
+                    }
+                    _ if self_closing => continue,
+                    node => {
+                        if matches!(node, Node::Skipped) {
+                            self.skipping += 1;
+                        }
+                        stack.push(Frame { name, start, node });
+                        continue;
+                    }
+                }
+            };
+
+            if self.skipping > 0 {
+                continue;
+            }
+
+            let parent = stack
+                .iter_mut()
+                .rev()
+                .find_map(|frame| match &mut frame.node {
+                    Node::Element(element) => element.children_mut(),
+                    Node::Unwrapped | Node::Skipped => None,
+                });
+            match parent {
+                Some(children) => children.push(element),
+                None => {
+                    if self.parser.require_root
+                        && self.context.is_none()
+                        && (!elements.is_empty() || !matches!(element, SsmlElement::Speak { .. }))
+                    {
+                        return Err(self.error(start, ErrorKind::InvalidRoot));
+                    }
+                    elements.push(element);
+                }
+            }
+        }
+
+        if let Some(frame) = stack.last() {
+            return Err(self.error(
+                frame.start,
+                ErrorKind::UnclosedElement(frame.name.to_string()),
+            ));
+        }
+
+        if self.parser.require_root && self.context.is_none() && elements.is_empty() {
+            return Err(self.error(self.input.len(), ErrorKind::InvalidRoot));
+        }
+
+        Ok(SSML { elements })
+    }
+
+    // In fragment mode, checks that `child` may appear inside its nearest known ancestor
+    fn check_child(
+        &self,
+        stack: &[Frame<'a>],
+        child: ElementKind,
+        start: usize,
+    ) -> Result<(), Error> {
+        let Some(context) = self.context else {
+            return Ok(());
+        };
+        if self.skipping > 0 {
+            return Ok(());
+        }
+
+        let parent = stack
+            .iter()
+            .rev()
+            .find_map(|frame| match &frame.node {
+                Node::Element(element) => Some(element.kind()),
+                Node::Unwrapped | Node::Skipped => None,
+            })
+            .unwrap_or(context);
+
+        if parent.can_contain(child) {
+            Ok(())
+        } else {
+            Err(self.error(start, ErrorKind::InvalidChild { parent, child }))
+        }
+    }
+
+    // Parses `<?xml ... ?>`, ignoring its attributes
+    fn xml_declaration(&mut self) -> Result<(), Error> {
+        self.pos += "<?xml".len();
+        let mut attributes = 0;
+        loop {
+            self.skip_whitespace();
+            if self.rest().starts_with("?>") {
+                self.pos += "?>".len();
+                self.skip_whitespace();
+                return Ok(());
+            }
+            attributes += 1;
+            self.check_attributes(attributes)?;
+            let _ = self.attribute()?;
+        }
+    }
+
+    // Parses `<name attr="value" ...>` or `<name ... />`, returning whether it was self-closing
+    fn opening_tag(&mut self) -> Result<(&'a str, Node<'a>, bool), Error> {
+        let start = self.pos;
+        self.pos += 1;
+        self.skip_whitespace();
+
+        let name = self.name()?;
+        let local_name = match self.parser.namespaces {
+            Namespaces::StripPrefixes => name.split_once(':').map_or(name, |(_, local)| local),
+            Namespaces::Ignore | Namespaces::Require => name,
+        };
+
+        let mut node = match new_element(local_name) {
+            Some(element) => Node::Element(element),
+            // Everything inside a skipped element is dropped anyway
+            None if self.skipping > 0 => Node::Unwrapped,
+            None => match self.parser.unknown_elements {
+                UnknownElements::Reject => {
+                    return Err(self.error(start, ErrorKind::UnknownElement(name.to_string())));
+                }
+                UnknownElements::Unwrap => Node::Unwrapped,
+                UnknownElements::Skip => Node::Skipped,
+            },
+        };
+
+        self.elements += 1;
+        let max_elements = self.parser.limits.max_elements;
+        if self.elements > max_elements {
+            return Err(self.error(
+                start,
+                ErrorKind::LimitExceeded(Limit::Elements(max_elements)),
+            ));
+        }
+
+        let mut attributes = 0;
+        let self_closing = loop {
+            self.skip_whitespace();
+            match self.peek() {
+                Some(b'>') => {
+                    self.pos += 1;
+                    break false;
+                }
+                Some(b'/') if self.peek_at(1) == Some(b'>') => {
+                    self.pos += 2;
+                    break true;
+                }
+                Some(_) => {
+                    attributes += 1;
+                    self.check_attributes(attributes)?;
+
+                    let attribute_start = self.pos;
+                    let (key, value) = self.attribute()?;
+                    if let Node::Element(element) = &mut node
+                        && let Err(value) = set_attribute(element, key, value)
+                    {
+                        let error = self.error(
+                            attribute_start,
+                            ErrorKind::InvalidAttribute {
+                                element: local_name.to_string(),
+                                attribute: key.to_string(),
+                                value: value.into_owned(),
+                            },
+                        );
+                        if self.parser.strict {
+                            return Err(error);
+                        }
+                        self.dropped.push(error);
+                    }
+                }
+                None => return Err(self.unexpected(Some('>'))),
+            }
+        };
+
+        if let Node::Element(element) = &node {
+            self.check_element(element, local_name, start)?;
+        }
+
+        Ok((name, node, self_closing))
+    }
+
+    // Applies the configured strictness and namespace checks to a fully attributed element
+    fn check_element(
+        &self,
+        element: &SsmlElement<'a>,
+        name: &str,
+        start: usize,
+    ) -> Result<(), Error> {
+        if self.parser.strict
+            && let Some(attribute) = missing_attribute(element)
+        {
+            return Err(self.error(
+                start,
+                ErrorKind::MissingAttribute {
+                    element: name.to_string(),
+                    attribute: attribute.to_string(),
+                },
+            ));
+        }
+
+        if self.parser.namespaces == Namespaces::Require
+            && let SsmlElement::Speak { xmlns, .. } = element
+            && xmlns.as_deref() != Some(SSML_NAMESPACE)
+        {
+            return Err(self.error(start, ErrorKind::MissingNamespace));
+        }
+
+        Ok(())
+    }
+
+    // Parses `</name>`
+    fn closing_tag(&mut self) -> Result<&'a str, Error> {
+        self.pos += "</".len();
+        self.skip_whitespace();
+        let name = self.name()?;
+        self.skip_whitespace();
+        self.expect(b'>')?;
+        Ok(name)
+    }
+
+    // Parses `name="value"` or `name='value'`
+    fn attribute(&mut self) -> Result<(&'a str, Cow<'a, str>), Error> {
+        let key = self.name()?;
+        self.skip_whitespace();
+        self.expect(b'=')?;
+        self.skip_whitespace();
+        let quote = if self.peek() == Some(b'\'') {
+            b'\''
+        } else {
+            b'"'
+        };
+        self.expect(quote)?;
+
+        let start = self.pos;
+        let Some(len) = self.rest().find(char::from(quote)) else {
+            self.pos = self.input.len();
+            return Err(self.unexpected(Some(char::from(quote))));
+        };
+        self.pos += len + 1;
+
+        Ok((key, unescape(&self.input[start..start + len])))
+    }
+
+    // Parses a tag or attribute name (letters, digits, underscore, hyphen, colon, period)
+    fn name(&mut self) -> Result<&'a str, Error> {
+        let start = self.pos;
+        match self.peek() {
+            Some(b) if b.is_ascii_alphabetic() || matches!(b, b'_' | b'-' | b':') => {}
+            _ => return Err(self.unexpected(None)),
+        }
+        while matches!(self.peek(), Some(b) if b.is_ascii_alphanumeric() || matches!(b, b'_' | b'-' | b':' | b'.'))
+        {
+            self.pos += 1;
+        }
+        Ok(&self.input[start..self.pos])
+    }
+
+    // Reads raw text up to the next tag
+    fn text(&mut self) -> &'a str {
+        let start = self.pos;
+        self.pos = self
+            .rest()
+            .find('<')
+            .map_or(self.input.len(), |i| start + i);
+        &self.input[start..self.pos]
+    }
+
+    // Applies the whitespace policy to a raw text run, returning `None` if it is dropped
+    fn text_node(
+        &self,
+        raw: &'a str,
+        start: usize,
+        top_level: bool,
+    ) -> Result<Option<Cow<'a, str>>, Error> {
+        let text = match self.parser.whitespace {
+            Whitespace::Trim => raw.trim(),
+            Whitespace::Preserve if top_level => raw,
+            Whitespace::Preserve => {
+                return self.check_text(raw, start).map(|()| Some(unescape(raw)));
+            }
+            Whitespace::Collapse => raw,
+        };
+
+        // Whitespace-only runs are only kept when preserving whitespace inside an element
+        if text.trim().is_empty() {
+            return Ok(None);
+        }
+        self.check_text(text, start)?;
+
+        Ok(Some(match self.parser.whitespace {
+            Whitespace::Collapse => collapse_whitespace(unescape(text)),
+            Whitespace::Trim | Whitespace::Preserve => unescape(text),
+        }))
+    }
+
+    fn check_text(&self, text: &str, start: usize) -> Result<(), Error> {
+        let max_text_len = self.parser.limits.max_text_len;
+        if text.len() > max_text_len {
+            Err(self.error(
+                start,
+                ErrorKind::LimitExceeded(Limit::TextLength(max_text_len)),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn check_attributes(&self, attributes: usize) -> Result<(), Error> {
+        let max_attributes = self.parser.limits.max_attributes;
+        if attributes > max_attributes {
+            Err(self.error(
+                self.pos,
+                ErrorKind::LimitExceeded(Limit::Attributes(max_attributes)),
+            ))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), Error> {
+        if self.peek() == Some(byte) {
+            self.pos += 1;
+            Ok(())
+        } else {
+            Err(self.unexpected(Some(byte as char)))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b) if b.is_ascii_whitespace()) {
+            self.pos += 1;
+        }
+    }
+
+    fn rest(&self) -> &'a str {
+        &self.input[self.pos..]
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.input.as_bytes().get(self.pos).copied()
+    }
+
+    fn peek_at(&self, offset: usize) -> Option<u8> {
+        self.input.as_bytes().get(self.pos + offset).copied()
+    }
+
+    fn error(&self, at: usize, kind: ErrorKind) -> Error {
+        let len = self
+            .input
+            .get(at..)
+            .and_then(|rest| rest.chars().next())
+            .map_or(0, char::len_utf8);
+        Error::new(kind, at..at + len)
+    }
+
+    fn unexpected(&self, expected: Option<char>) -> Error {
+        let kind = match self.rest().chars().next() {
+            Some(found) => ErrorKind::UnexpectedChar { expected, found },
+            None => ErrorKind::UnexpectedEof { expected },
+        };
+        self.error(self.pos, kind)
+    }
+}
+
+// Replaces every run of whitespace with a single space
+pub(crate) fn collapse_whitespace(text: Cow<'_, str>) -> Cow<'_, str> {
+    let mut previous_whitespace = false;
+    let collapsed = text.chars().all(|c| {
+        let whitespace = c.is_whitespace();
+        let ok = !whitespace || (c == ' ' && !previous_whitespace);
+        previous_whitespace = whitespace;
+        ok
+    });
+    if collapsed {
+        return text;
+    }
+
+    let mut output = String::with_capacity(text.len());
+    let mut previous_whitespace = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !previous_whitespace {
+                output.push(' ');
+            }
+            previous_whitespace = true;
+        } else {
+            output.push(c);
+            previous_whitespace = false;
+        }
+    }
+    Cow::Owned(output)
+}
+
+// Creates an element without attributes or children from its tag name
+pub(crate) fn new_element<'a>(name: &str) -> Option<SsmlElement<'a>> {
+    let empty = || Cow::Borrowed("");
+    Some(match name {
+        "speak" => SsmlElement::Speak {
+            version: None,
+            xmlns: None,
+            lang: None,
+            children: Vec::new(),
+        },
+        "voice" => SsmlElement::Voice {
+            name: empty(),
+            children: Vec::new(),
+        },
+        "p" => SsmlElement::Paragraph {
+            children: Vec::new(),
+        },
+        "s" => SsmlElement::Sentence {
+            children: Vec::new(),
+        },
+        "phoneme" => SsmlElement::Phoneme {
+            alphabet: empty(),
+            ph: empty(),
+            children: Vec::new(),
+        },
+        "say-as" => SsmlElement::SayAs {
+            interpret_as: empty(),
+            format: empty(),
+            detail: empty(),
+            children: Vec::new(),
+        },
+        "sub" => SsmlElement::Sub {
+            alias: empty(),
+            children: Vec::new(),
+        },
+        "prosody" => SsmlElement::Prosody {
+            rate: empty(),
+            pitch: empty(),
+            contour: empty(),
+            range: empty(),
+            volume: empty(),
+            children: Vec::new(),
+        },
+        "emphasis" => SsmlElement::Emphasis {
+            level: empty(),
+            children: Vec::new(),
+        },
+        "break" => SsmlElement::Break {
+            time: None,
+            strength: None,
+        },
+        "mark" => SsmlElement::Mark { name: empty() },
+        "audio" => SsmlElement::Audio {
+            src: empty(),
+            children: Vec::new(),
+        },
+        "desc" => SsmlElement::Desc {
+            children: Vec::new(),
+        },
+        "lexicon" => SsmlElement::LexiconUri { uri: empty() },
+        "lang" => SsmlElement::Lang {
+            xml_lang: empty(),
+            children: Vec::new(),
+        },
+        _ => return None,
+    })
+}
+
+// Stores a recognised attribute on the element, returning whether it was recognised; unknown
+// attributes are ignored. Values the element cannot represent are handed back as the error.
+pub(crate) fn set_attribute<'a>(
+    element: &mut SsmlElement<'a>,
+    key: &str,
+    value: Cow<'a, str>,
+) -> Result<bool, Cow<'a, str>> {
+    match (element, key) {
+        (SsmlElement::Speak { version, .. }, "version") => *version = Some(value),
+        (SsmlElement::Speak { xmlns, .. }, "xmlns") => *xmlns = Some(value),
+        (SsmlElement::Speak { lang, .. }, "xml:lang") => *lang = Some(value),
+        (SsmlElement::Voice { name, .. }, "name") => *name = value,
+        (SsmlElement::Phoneme { alphabet, .. }, "alphabet") => *alphabet = value,
+        (SsmlElement::Phoneme { ph, .. }, "ph") => *ph = value,
+        (SsmlElement::SayAs { interpret_as, .. }, "interpret-as") => *interpret_as = value,
+        (SsmlElement::SayAs { format, .. }, "format") => *format = value,
+        (SsmlElement::SayAs { detail, .. }, "detail") => *detail = value,
+        (SsmlElement::Sub { alias, .. }, "alias") => *alias = value,
+        (SsmlElement::Prosody { rate, .. }, "rate") => *rate = value,
+        (SsmlElement::Prosody { pitch, .. }, "pitch") => *pitch = value,
+        (SsmlElement::Prosody { contour, .. }, "contour") => *contour = value,
+        (SsmlElement::Prosody { range, .. }, "range") => *range = value,
+        (SsmlElement::Prosody { volume, .. }, "volume") => *volume = value,
+        (SsmlElement::Emphasis { level, .. }, "level") => *level = value,
+        (SsmlElement::Break { time, .. }, "time") => {
+            *time = duration_str::parse(&*value).ok();
+            if time.is_none() {
+                return Err(value);
+            }
+        }
+        (SsmlElement::Break { strength, .. }, "strength") => {
+            *strength = value.parse().ok();
+            if strength.is_none() {
+                return Err(value);
+            }
+        }
+        (SsmlElement::Mark { name }, "name") => *name = value,
+        (SsmlElement::Audio { src, .. }, "src") => *src = value,
+        (SsmlElement::LexiconUri { uri }, "uri") => *uri = value,
+        (SsmlElement::Lang { xml_lang, .. }, "xml:lang") => *xml_lang = value,
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+// Returns the name of a required attribute the element is missing
+fn missing_attribute(element: &SsmlElement<'_>) -> Option<&'static str> {
+    match element {
+        SsmlElement::Phoneme { ph, .. } if ph.is_empty() => Some("ph"),
+        SsmlElement::SayAs { interpret_as, .. } if interpret_as.is_empty() => Some("interpret-as"),
+        SsmlElement::Sub { alias, .. } if alias.is_empty() => Some("alias"),
+        SsmlElement::Mark { name } if name.is_empty() => Some("name"),
+        SsmlElement::Audio { src, .. } if src.is_empty() => Some("src"),
+        SsmlElement::LexiconUri { uri } if uri.is_empty() => Some("uri"),
+        SsmlElement::Lang { xml_lang, .. } if xml_lang.is_empty() => Some("xml:lang"),
+        _ => None,
+    }
+}
+
+// Elements that never have children
+fn is_void(element: &SsmlElement<'_>) -> bool {
+    matches!(
+        element,
+        SsmlElement::Break { .. } | SsmlElement::Mark { .. } | SsmlElement::LexiconUri { .. }
+    )
+}
@@ -0,0 +1,438 @@
+//! Addressing nodes by position, and stable node IDs.
+
+use std::{fmt, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{SSML, SsmlElement};
+
+/// The position of a node in a document, as child indices from the top level down.
+///
+/// `[1, 0]` is the first child of the second top-level node. The empty path is the document
+/// itself, which is not a node, so lookups with it return `None`. Paths order like the nodes
+/// they point to in document order.
+///
+/// A path is written as `/1/0`, and serializes as an array of indices.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::NodePath;
+///
+/// let ssml = serde_ssml::from_str("<speak><p><s>One</s><s>Two</s></p></speak>").unwrap();
+/// let path = NodePath::from([0, 0, 1]);
+///
+/// assert_eq!(ssml.get(&path).unwrap().to_string(), "<s>Two</s>");
+/// assert_eq!(path.to_string(), "/0/0/1");
+/// assert_eq!("/0/0/1".parse(), Ok(path));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NodePath(Vec<usize>);
+
+impl NodePath {
+    /// Returns the empty path, which points at the document itself.
+    pub fn root() -> Self {
+        NodePath(Vec::new())
+    }
+
+    /// Returns the child indices of the path.
+    pub fn indices(&self) -> &[usize] {
+        &self.0
+    }
+
+    /// Returns the depth of the node, i.e. the number of indices.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Returns whether this is the empty path.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Returns the index of the node among its siblings, or `None` for the empty path.
+    pub fn index(&self) -> Option<usize> {
+        self.0.last().copied()
+    }
+
+    /// Returns the path of the parent, or `None` for the empty path.
+    pub fn parent(&self) -> Option<NodePath> {
+        let (_, parent) = self.0.split_last()?;
+        Some(NodePath(parent.to_vec()))
+    }
+
+    /// Returns the path of the child at `index`.
+    pub fn child(&self, index: usize) -> NodePath {
+        let mut path = self.clone();
+        path.0.push(index);
+        path
+    }
+
+    /// Returns whether `other` points inside the subtree of this path, excluding the node
+    /// itself.
+    pub fn is_ancestor_of(&self, other: &NodePath) -> bool {
+        other.0.len() > self.0.len() && other.0.starts_with(&self.0)
+    }
+}
+
+impl From<Vec<usize>> for NodePath {
+    fn from(indices: Vec<usize>) -> Self {
+        NodePath(indices)
+    }
+}
+
+impl From<&[usize]> for NodePath {
+    fn from(indices: &[usize]) -> Self {
+        NodePath(indices.to_vec())
+    }
+}
+
+impl<const N: usize> From<[usize; N]> for NodePath {
+    fn from(indices: [usize; N]) -> Self {
+        NodePath(indices.to_vec())
+    }
+}
+
+impl fmt::Display for NodePath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_empty() {
+            return f.write_str("/");
+        }
+        for index in &self.0 {
+            write!(f, "/{index}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for NodePath {
+    type Err = ();
+
+    /// Parses a path written as `/1/0`, or `/` for the empty path.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.strip_prefix('/').ok_or(())? {
+            "" => Ok(NodePath::root()),
+            indices => indices
+                .split('/')
+                .map(|index| index.parse().map_err(|_| ()))
+                .collect::<Result<_, _>>()
+                .map(NodePath),
+        }
+    }
+}
+
+impl SSML {
+    /// Returns the node at `path`, or `None` if there is no such node.
+    pub fn get(&self, path: &NodePath) -> Option<&SsmlElement> {
+        get(&self.elements, path.indices())
+    }
+
+    /// Returns the node at `path` mutably, or `None` if there is no such node.
+    pub fn get_mut(&mut self, path: &NodePath) -> Option<&mut SsmlElement> {
+        get_mut(&mut self.elements, path.indices())
+    }
+
+    /// Returns the element containing the node at `path`, or `None` for top-level nodes and
+    /// invalid paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{ElementKind, NodePath};
+    ///
+    /// let ssml = serde_ssml::from_str("<speak><s>Hi</s></speak>").unwrap();
+    ///
+    /// let parent = ssml.parent_of(&NodePath::from([0, 0, 0])).unwrap();
+    /// assert_eq!(parent.kind(), ElementKind::Sentence);
+    /// assert!(ssml.parent_of(&NodePath::from([0])).is_none());
+    /// ```
+    pub fn parent_of(&self, path: &NodePath) -> Option<&SsmlElement> {
+        let (_, parent) = path.indices().split_last()?;
+        get(&self.elements, parent)
+    }
+
+    /// Inserts `node` so that it ends up at `path`, shifting later siblings.
+    ///
+    /// The last index may equal the number of siblings to append. Fails if the parent does
+    /// not exist, cannot have children, or the index is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::{NodePath, SsmlElement};
+    ///
+    /// let mut ssml = serde_ssml::from_str("<speak><s>Hi</s></speak>").unwrap();
+    /// let mark = SsmlElement::Mark { name: "start".to_string() };
+    ///
+    /// ssml.insert_at(&NodePath::from([0, 0]), mark.clone()).unwrap();
+    /// assert_eq!(ssml.to_string(), r#"<speak><mark name="start"/><s>Hi</s></speak>"#);
+    /// assert!(ssml.insert_at(&NodePath::from([0, 5]), mark).is_err());
+    /// ```
+    pub fn insert_at(&mut self, path: &NodePath, node: SsmlElement) -> Result<(), InvalidPath> {
+        insert_at(&mut self.elements, path.indices(), node)
+    }
+
+    /// Removes and returns the node at `path`, shifting later siblings.
+    pub fn remove_at(&mut self, path: &NodePath) -> Option<SsmlElement> {
+        let (index, siblings) = siblings_mut(&mut self.elements, path.indices())?;
+        (index < siblings.len()).then(|| siblings.remove(index))
+    }
+}
+
+fn get<'a>(nodes: &'a [SsmlElement], path: &[usize]) -> Option<&'a SsmlElement> {
+    let (first, rest) = path.split_first()?;
+    let node = nodes.get(*first)?;
+    if rest.is_empty() {
+        Some(node)
+    } else {
+        get(node.children(), rest)
+    }
+}
+
+pub(crate) fn get_mut<'a>(
+    nodes: &'a mut [SsmlElement],
+    path: &[usize],
+) -> Option<&'a mut SsmlElement> {
+    let (first, rest) = path.split_first()?;
+    let node = nodes.get_mut(*first)?;
+    if rest.is_empty() {
+        Some(node)
+    } else {
+        get_mut(node.children_mut()?, rest)
+    }
+}
+
+// Returns the last index of `path` and the list of nodes it indexes into
+pub(crate) fn siblings_mut<'a>(
+    nodes: &'a mut Vec<SsmlElement>,
+    path: &[usize],
+) -> Option<(usize, &'a mut Vec<SsmlElement>)> {
+    let (index, parent) = path.split_last()?;
+    if parent.is_empty() {
+        Some((*index, nodes))
+    } else {
+        Some((*index, get_mut(nodes, parent)?.children_mut()?))
+    }
+}
+
+fn insert_at(
+    nodes: &mut Vec<SsmlElement>,
+    path: &[usize],
+    node: SsmlElement,
+) -> Result<(), InvalidPath> {
+    match siblings_mut(nodes, path) {
+        Some((index, siblings)) if index <= siblings.len() => {
+            siblings.insert(index, node);
+            Ok(())
+        }
+        _ => Err(InvalidPath),
+    }
+}
+
+/// The error returned when inserting at a path where no node can be inserted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InvalidPath;
+
+impl fmt::Display for InvalidPath {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("no node can be inserted at this path")
+    }
+}
+
+impl std::error::Error for InvalidPath {}
+
+/// An identifier for a node of a [`TrackedSsml`] document.
+///
+/// IDs are unique within a document and never reused. They serialize as plain numbers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct NodeId(u64);
+
+impl fmt::Display for NodeId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+/// A document whose nodes have [`NodeId`]s that stay the same while it is edited.
+///
+/// IDs are assigned in document order starting from `0`, so two parties that start from the
+/// same document agree on them. Nodes inserted later get new IDs. Structural edits should
+/// go through [`TrackedSsml::insert_at`] and [`TrackedSsml::remove_at`]; if
+/// [`TrackedSsml::modify`] changes how many children an element has, those children get new
+/// IDs.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{NodePath, SsmlElement, TrackedSsml};
+///
+/// let ssml = serde_ssml::from_str(r#"<speak><prosody rate="slow">Hi</prosody></speak>"#).unwrap();
+/// let mut tracked = TrackedSsml::new(ssml);
+/// let prosody = tracked.id_at(&NodePath::from([0, 0])).unwrap();
+///
+/// // Another edit moves the prosody element, but its ID still finds it
+/// tracked
+///     .insert_at(&NodePath::from([0, 0]), SsmlElement::Text("Well,".to_string()))
+///     .unwrap();
+/// tracked.modify(prosody, |element| {
+///     if let SsmlElement::Prosody { rate, .. } = element {
+///         *rate = "fast".to_string();
+///     }
+/// });
+///
+/// assert_eq!(tracked.path_of(prosody), Some(NodePath::from([0, 1])));
+/// assert_eq!(
+///     tracked.ssml().to_string(),
+///     r#"<speak>Well,<prosody rate="fast">Hi</prosody></speak>"#
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrackedSsml {
+    ssml: SSML,
+    ids: Vec<IdNode>,
+    next: u64,
+}
+
+// The IDs of a node and its children, mirroring the document tree
+#[derive(Debug, Clone, PartialEq)]
+struct IdNode {
+    id: NodeId,
+    children: Vec<IdNode>,
+}
+
+impl TrackedSsml {
+    /// Assigns IDs to every node of `ssml` in document order.
+    pub fn new(ssml: SSML) -> Self {
+        let mut next = 0;
+        let ids = assign(&ssml.elements, &mut next);
+        TrackedSsml { ssml, ids, next }
+    }
+
+    /// Returns the document.
+    pub fn ssml(&self) -> &SSML {
+        &self.ssml
+    }
+
+    /// Returns the document, dropping the IDs.
+    pub fn into_inner(self) -> SSML {
+        self.ssml
+    }
+
+    /// Returns the ID of the node at `path`.
+    pub fn id_at(&self, path: &NodePath) -> Option<NodeId> {
+        let (first, rest) = path.indices().split_first()?;
+        let mut node = self.ids.get(*first)?;
+        for index in rest {
+            node = node.children.get(*index)?;
+        }
+        Some(node.id)
+    }
+
+    /// Returns the current path of the node with the given ID, or `None` if it was removed.
+    pub fn path_of(&self, id: NodeId) -> Option<NodePath> {
+        fn find(ids: &[IdNode], id: NodeId, path: &mut Vec<usize>) -> bool {
+            for (index, node) in ids.iter().enumerate() {
+                path.push(index);
+                if node.id == id || find(&node.children, id, path) {
+                    return true;
+                }
+                let _ = path.pop();
+            }
+            false
+        }
+
+        let mut path = Vec::new();
+        find(&self.ids, id, &mut path).then_some(NodePath(path))
+    }
+
+    /// Returns the node with the given ID.
+    pub fn get(&self, id: NodeId) -> Option<&SsmlElement> {
+        self.ssml.get(&self.path_of(id)?)
+    }
+
+    /// Calls `f` on the node with the given ID and returns its result, or `None` if there is
+    /// no such node.
+    pub fn modify<F, R>(&mut self, id: NodeId, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut SsmlElement) -> R,
+    {
+        let path = self.path_of(id)?;
+        let element = get_mut(&mut self.ssml.elements, path.indices())?;
+        let result = f(element);
+
+        let (index, siblings) = id_siblings_mut(&mut self.ids, path.indices())?;
+        sync(&mut siblings[index], element, &mut self.next);
+        Some(result)
+    }
+
+    /// Inserts `node` at `path` like [`SSML::insert_at`] and returns its new ID.
+    pub fn insert_at(&mut self, path: &NodePath, node: SsmlElement) -> Result<NodeId, InvalidPath> {
+        let (index, siblings) =
+            id_siblings_mut(&mut self.ids, path.indices()).ok_or(InvalidPath)?;
+        let mut next = self.next;
+        let ids = assign(std::slice::from_ref(&node), &mut next);
+        insert_at(&mut self.ssml.elements, path.indices(), node)?;
+
+        let id = ids[0].id;
+        let _ = siblings.splice(index..index, ids);
+        self.next = next;
+        Ok(id)
+    }
+
+    /// Removes and returns the node at `path` like [`SSML::remove_at`]; its ID and the IDs
+    /// of its descendants are retired.
+    pub fn remove_at(&mut self, path: &NodePath) -> Option<SsmlElement> {
+        let node = self.ssml.remove_at(path)?;
+        let (index, siblings) = id_siblings_mut(&mut self.ids, path.indices())?;
+        let _ = siblings.remove(index);
+        Some(node)
+    }
+
+    /// Removes and returns the node with the given ID.
+    pub fn remove(&mut self, id: NodeId) -> Option<SsmlElement> {
+        let path = self.path_of(id)?;
+        self.remove_at(&path)
+    }
+}
+
+// Creates fresh IDs for `nodes` and their descendants
+fn assign(nodes: &[SsmlElement], next: &mut u64) -> Vec<IdNode> {
+    nodes
+        .iter()
+        .map(|node| {
+            let id = NodeId(*next);
+            *next += 1;
+            IdNode {
+                id,
+                children: assign(node.children(), next),
+            }
+        })
+        .collect()
+}
+
+// Keeps IDs where the shape of the tree is unchanged and assigns fresh ones elsewhere
+fn sync(ids: &mut IdNode, element: &SsmlElement, next: &mut u64) {
+    let children = element.children();
+    if ids.children.len() == children.len() {
+        for (ids, child) in ids.children.iter_mut().zip(children) {
+            sync(ids, child, next);
+        }
+    } else {
+        ids.children = assign(children, next);
+    }
+}
+
+// Like `siblings_mut`, for the ID tree
+fn id_siblings_mut<'a>(
+    ids: &'a mut Vec<IdNode>,
+    path: &[usize],
+) -> Option<(usize, &'a mut Vec<IdNode>)> {
+    let (index, parent) = path.split_last()?;
+    let mut siblings = ids;
+    for index in parent {
+        siblings = &mut siblings.get_mut(*index)?.children;
+    }
+    Some((*index, siblings))
+}
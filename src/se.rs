@@ -0,0 +1,684 @@
+//! Serde serializer writing user-defined types as SSML markup.
+//!
+//! Together with [`crate::de`] this makes SSML a serde data format, following the conventions of
+//! `quick-xml`:
+//!
+//! - A struct becomes an element named after the struct, or after the field holding it.
+//! - Fields renamed to `@name` become attributes; `None` attributes are omitted.
+//! - A field renamed to `$text` becomes the text content of the element.
+//! - A field renamed to `$value` holds mixed content: strings become text, and enum variants
+//!   become elements named after the variant. A variant named `$text` is written as text.
+//! - Every other field becomes a child element named after the field; sequences repeat it and
+//!   `None` omits it. Scalars are written as the element's text, `()` as an empty element.
+//!
+//! Only elements and attributes of the SSML vocabulary can be written. The result goes through
+//! the regular document model, so it is always valid input for [`crate::from_str`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use serde::Serialize;
+//!
+//! #[derive(Serialize)]
+//! #[serde(rename = "speak")]
+//! struct Prompt {
+//!     #[serde(rename = "@xml:lang")]
+//!     lang: String,
+//!     #[serde(rename = "s")]
+//!     sentences: Vec<String>,
+//!     #[serde(rename = "break")]
+//!     pause: Pause,
+//! }
+//!
+//! #[derive(Serialize)]
+//! struct Pause {
+//!     #[serde(rename = "@time")]
+//!     time: &'static str,
+//! }
+//!
+//! let prompt = Prompt {
+//!     lang: "en-US".to_string(),
+//!     sentences: vec!["Hello.".to_string(), "Goodbye.".to_string()],
+//!     pause: Pause { time: "500ms" },
+//! };
+//!
+//! assert_eq!(
+//!     serde_ssml::se::to_string(&prompt).unwrap(),
+//!     r#"<speak xml:lang="en-US"><s>Hello.</s><s>Goodbye.</s><break time="500ms"/></speak>"#
+//! );
+//! ```
+
+use std::{borrow::Cow, fmt};
+
+use serde::{
+    Serialize,
+    ser::{self, Impossible},
+};
+
+use crate::{
+    SSML, SsmlElement,
+    parser::{new_element, set_attribute},
+};
+
+/// Serializes `value` as SSML markup.
+///
+/// See the [module documentation](self) for how types map to elements.
+pub fn to_string<T: ?Sized + Serialize>(value: &T) -> Result<String, SeError> {
+    to_ssml(value).map(|ssml| crate::to_string(&ssml))
+}
+
+/// Serializes `value` into an SSML document.
+pub fn to_ssml<T: ?Sized + Serialize>(value: &T) -> Result<SSML, SeError> {
+    Ok(SSML {
+        elements: value.serialize(Serializer::new())?,
+    })
+}
+
+/// An error raised while serializing a value as SSML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SeError {
+    /// An error reported by the [`Serialize`] implementation.
+    Custom(String),
+
+    /// An element name that is not part of the SSML vocabulary.
+    UnknownElement(String),
+
+    /// An attribute the element does not have.
+    UnknownAttribute {
+        /// Name of the element.
+        element: String,
+        /// Name of the attribute, without the `@` prefix.
+        attribute: String,
+    },
+
+    /// An attribute value the document model cannot represent, such as an invalid break time.
+    InvalidAttribute {
+        /// Name of the element.
+        element: String,
+        /// Name of the attribute, without the `@` prefix.
+        attribute: String,
+        /// The rejected value.
+        value: String,
+    },
+
+    /// Content for an element that cannot have children (`<break>`, `<mark>`, `<lexicon>`).
+    UnexpectedContent(String),
+
+    /// A value that has no SSML representation, such as bytes or a map without element name.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for SeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SeError::Custom(message) => f.write_str(message),
+            SeError::UnknownElement(name) => write!(f, "unknown element <{name}>"),
+            SeError::UnknownAttribute { element, attribute } => {
+                write!(f, "element <{element}> has no attribute {attribute}")
+            }
+            SeError::InvalidAttribute {
+                element,
+                attribute,
+                value,
+            } => write!(
+                f,
+                "invalid value \"{value}\" for <{element}> attribute {attribute}"
+            ),
+            SeError::UnexpectedContent(name) => write!(f, "element <{name}> cannot have content"),
+            SeError::Unsupported(what) => write!(f, "{what} cannot be serialized as SSML"),
+        }
+    }
+}
+
+impl std::error::Error for SeError {}
+
+impl ser::Error for SeError {
+    fn custom<T: fmt::Display>(message: T) -> Self {
+        SeError::Custom(message.to_string())
+    }
+}
+
+/// A serde [`Serializer`](ser::Serializer) producing SSML nodes.
+///
+/// Serializing a value yields the top-level nodes of a document; use [`to_ssml`] or
+/// [`to_string`] unless you need to drive serialization yourself.
+#[derive(Debug, Clone, Default)]
+pub struct Serializer {
+    // Element name for the value; `None` names elements after their type or variant and
+    // writes scalars as text
+    name: Option<Cow<'static, str>>,
+}
+
+impl Serializer {
+    /// Creates a serializer for a top-level value.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn named(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: Some(name.into()),
+        }
+    }
+
+    fn scalar(self, value: String) -> Result<Vec<SsmlElement>, SeError> {
+        match self.name {
+            None if value.is_empty() => Ok(Vec::new()),
+            None => Ok(vec![SsmlElement::Text(value)]),
+            Some(name) => {
+                let children = if value.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![SsmlElement::Text(value)]
+                };
+                Ok(vec![build(&name, Vec::new(), children)?])
+            }
+        }
+    }
+
+    fn element(self, name: &'static str) -> ElementSerializer {
+        ElementSerializer {
+            name: self.name.unwrap_or(Cow::Borrowed(name)),
+            attributes: Vec::new(),
+            children: Vec::new(),
+            key: None,
+        }
+    }
+}
+
+// Creates an element from its tag name, attributes and children
+pub(crate) fn build(
+    name: &str,
+    attributes: Vec<(String, String)>,
+    children: Vec<SsmlElement>,
+) -> Result<SsmlElement, SeError> {
+    let mut element = new_element(name).ok_or_else(|| SeError::UnknownElement(name.to_string()))?;
+
+    for (attribute, value) in attributes {
+        match set_attribute(&mut element, &attribute, Cow::Owned(value)) {
+            Ok(true) => {}
+            Ok(false) => {
+                return Err(SeError::UnknownAttribute {
+                    element: name.to_string(),
+                    attribute,
+                });
+            }
+            Err(value) => {
+                return Err(SeError::InvalidAttribute {
+                    element: name.to_string(),
+                    attribute,
+                    value: value.into_owned(),
+                });
+            }
+        }
+    }
+
+    let mut element = element.into_owned();
+    if !children.is_empty() {
+        let Some(content) = element.children_mut() else {
+            return Err(SeError::UnexpectedContent(name.to_string()));
+        };
+        *content = children;
+    }
+    Ok(element)
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($type:ty),)*) => {$(
+        fn $method(self, value: $type) -> Result<Self::Ok, Self::Error> {
+            self.scalar(value.to_string())
+        }
+    )*};
+}
+
+impl ser::Serializer for Serializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+    type SerializeSeq = SeqSerializer;
+    type SerializeTuple = SeqSerializer;
+    type SerializeTupleStruct = SeqSerializer;
+    type SerializeTupleVariant = SeqSerializer;
+    type SerializeMap = ElementSerializer;
+    type SerializeStruct = ElementSerializer;
+    type SerializeStructVariant = ElementSerializer;
+
+    serialize_display! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(SeError::Unsupported("a byte array"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(Vec::new())
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        match self.name {
+            None => Ok(Vec::new()),
+            Some(name) => Ok(vec![build(&name, Vec::new(), Vec::new())?]),
+        }
+    }
+
+    fn serialize_unit_struct(self, name: &'static str) -> Result<Self::Ok, Self::Error> {
+        let name = self.name.unwrap_or(Cow::Borrowed(name));
+        Ok(vec![build(&name, Vec::new(), Vec::new())?])
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        match self.name {
+            // A unit variant inside a named element is its text, e.g. `<level>strong</level>`
+            Some(_) => self.scalar(variant.to_string()),
+            None => Ok(vec![build(variant, Vec::new(), Vec::new())?]),
+        }
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        match variant {
+            "$text" => value.serialize(Serializer::new()),
+            variant => value.serialize(Serializer::named(variant)),
+        }
+    }
+
+    fn serialize_seq(self, len: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Ok(SeqSerializer {
+            name: self.name,
+            nodes: Vec::with_capacity(len.unwrap_or(0)),
+        })
+    }
+
+    fn serialize_tuple(self, len: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        len: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Serializer::named(variant).serialize_seq(Some(len))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        match self.name {
+            Some(_) => Ok(self.element("")),
+            None => Err(SeError::Unsupported("a map without element name")),
+        }
+    }
+
+    fn serialize_struct(
+        self,
+        name: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Ok(self.element(name))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Ok(Serializer::named(variant).element(variant))
+    }
+}
+
+/// Collects the nodes of a sequence; every item is written with the same element name.
+#[derive(Debug)]
+pub struct SeqSerializer {
+    name: Option<Cow<'static, str>>,
+    nodes: Vec<SsmlElement>,
+}
+
+impl SeqSerializer {
+    fn push<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SeError> {
+        let serializer = Serializer {
+            name: self.name.clone(),
+        };
+        self.nodes.extend(value.serialize(serializer)?);
+        Ok(())
+    }
+}
+
+impl ser::SerializeSeq for SeqSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        Ok(self.nodes)
+    }
+}
+
+impl ser::SerializeTuple for SeqSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_element<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        Ok(self.nodes)
+    }
+}
+
+impl ser::SerializeTupleStruct for SeqSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        Ok(self.nodes)
+    }
+}
+
+impl ser::SerializeTupleVariant for SeqSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SeError> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        Ok(self.nodes)
+    }
+}
+
+/// Collects the attributes and children of a struct or map written as one element.
+#[derive(Debug)]
+pub struct ElementSerializer {
+    name: Cow<'static, str>,
+    attributes: Vec<(String, String)>,
+    children: Vec<SsmlElement>,
+    // Pending map key
+    key: Option<String>,
+}
+
+impl ElementSerializer {
+    fn field<T: ?Sized + Serialize>(&mut self, key: &str, value: &T) -> Result<(), SeError> {
+        match key {
+            "$text" => {
+                if let Some(text) = value.serialize(ValueSerializer)?
+                    && !text.is_empty()
+                {
+                    self.children.push(SsmlElement::Text(text));
+                }
+            }
+            "$value" => self.children.extend(value.serialize(Serializer::new())?),
+            _ => match key.strip_prefix('@') {
+                Some(attribute) => {
+                    if let Some(value) = value.serialize(ValueSerializer)? {
+                        self.attributes.push((attribute.to_string(), value));
+                    }
+                }
+                None => self
+                    .children
+                    .extend(value.serialize(Serializer::named(key.to_string()))?),
+            },
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Vec<SsmlElement>, SeError> {
+        Ok(vec![build(&self.name, self.attributes, self.children)?])
+    }
+}
+
+impl ser::SerializeStruct for ElementSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SeError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeStructVariant for ElementSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_field<T: ?Sized + Serialize>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SeError> {
+        self.field(key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        self.finish()
+    }
+}
+
+impl ser::SerializeMap for ElementSerializer {
+    type Ok = Vec<SsmlElement>;
+    type Error = SeError;
+
+    fn serialize_key<T: ?Sized + Serialize>(&mut self, key: &T) -> Result<(), SeError> {
+        match key.serialize(ValueSerializer)? {
+            Some(key) => {
+                self.key = Some(key);
+                Ok(())
+            }
+            None => Err(SeError::Unsupported("a map key of None")),
+        }
+    }
+
+    fn serialize_value<T: ?Sized + Serialize>(&mut self, value: &T) -> Result<(), SeError> {
+        let key = self.key.take().unwrap_or_default();
+        self.field(&key, value)
+    }
+
+    fn end(self) -> Result<Self::Ok, SeError> {
+        self.finish()
+    }
+}
+
+// Serializes scalars for attribute values, text content and map keys; `None` is omitted
+struct ValueSerializer;
+
+macro_rules! serialize_value {
+    ($($method:ident($type:ty),)*) => {$(
+        fn $method(self, value: $type) -> Result<Self::Ok, Self::Error> {
+            Ok(Some(value.to_string()))
+        }
+    )*};
+}
+
+impl ser::Serializer for ValueSerializer {
+    type Ok = Option<String>;
+    type Error = SeError;
+    type SerializeSeq = Impossible<Self::Ok, SeError>;
+    type SerializeTuple = Impossible<Self::Ok, SeError>;
+    type SerializeTupleStruct = Impossible<Self::Ok, SeError>;
+    type SerializeTupleVariant = Impossible<Self::Ok, SeError>;
+    type SerializeMap = Impossible<Self::Ok, SeError>;
+    type SerializeStruct = Impossible<Self::Ok, SeError>;
+    type SerializeStructVariant = Impossible<Self::Ok, SeError>;
+
+    serialize_value! {
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_i128(i128),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_u128(u128),
+        serialize_f32(f32),
+        serialize_f64(f64),
+        serialize_char(char),
+        serialize_str(&str),
+    }
+
+    fn serialize_bytes(self, _: &[u8]) -> Result<Self::Ok, Self::Error> {
+        Err(SeError::Unsupported("a byte array"))
+    }
+
+    fn serialize_none(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_some<T: ?Sized + Serialize>(self, value: &T) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_struct(self, _: &'static str) -> Result<Self::Ok, Self::Error> {
+        Ok(None)
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        variant: &'static str,
+    ) -> Result<Self::Ok, Self::Error> {
+        Ok(Some(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        value: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ?Sized + Serialize>(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: &T,
+    ) -> Result<Self::Ok, Self::Error> {
+        Err(SeError::Unsupported(
+            "an enum variant with data in an attribute",
+        ))
+    }
+
+    fn serialize_seq(self, _: Option<usize>) -> Result<Self::SerializeSeq, Self::Error> {
+        Err(SeError::Unsupported("a sequence in an attribute"))
+    }
+
+    fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, Self::Error> {
+        Err(SeError::Unsupported("a tuple in an attribute"))
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleStruct, Self::Error> {
+        Err(SeError::Unsupported("a tuple struct in an attribute"))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeTupleVariant, Self::Error> {
+        Err(SeError::Unsupported(
+            "an enum variant with data in an attribute",
+        ))
+    }
+
+    fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, Self::Error> {
+        Err(SeError::Unsupported("a map in an attribute"))
+    }
+
+    fn serialize_struct(
+        self,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStruct, Self::Error> {
+        Err(SeError::Unsupported("a struct in an attribute"))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _: &'static str,
+        _: u32,
+        _: &'static str,
+        _: usize,
+    ) -> Result<Self::SerializeStructVariant, Self::Error> {
+        Err(SeError::Unsupported(
+            "an enum variant with data in an attribute",
+        ))
+    }
+}
@@ -0,0 +1,701 @@
+//! CSS-like selectors over the elements of a document.
+
+use std::{fmt, ops::ControlFlow, str::FromStr};
+
+use crate::{ElementKind, SSML, SerializeOptions, SsmlElement, path, ser};
+
+/// A parsed selector, matching elements by tag name, attributes and position in the tree.
+///
+/// The syntax is a subset of CSS selectors:
+///
+/// | Syntax | Matches |
+/// |---|---|
+/// | `say-as`, `*` | elements with the given tag name, any element |
+/// | `[name]` | elements with the attribute |
+/// | `[name=value]`, `[name='a value']` | elements whose attribute equals the value |
+/// | `[name~=v]`, `[name^=v]`, `[name$=v]`, `[name*=v]` | attribute containing the word, starting with, ending with or containing the value |
+/// | `a b` | `b` inside `a` at any depth |
+/// | `a > b` | `b` directly inside `a` |
+/// | `:first-child`, `:last-child` | the first or last element among its siblings |
+/// | `:nth-child(an+b)`, `:nth-child(odd)` | the element at a matching 1-based position among its siblings |
+/// | `:has(b)`, `:has(> b)` | elements with a descendant or child matching `b` |
+/// | `a, b` | elements matching either selector |
+///
+/// Attribute values are compared as written in markup, e.g. `break[time=500ms]`. Text nodes
+/// are never matched and do not count as siblings.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::Selector;
+///
+/// let selector: Selector = "voice[name='en-US-Jenny'] prosody > emphasis".parse().unwrap();
+/// let ssml = serde_ssml::from_str(
+///     r#"<speak><voice name="en-US-Jenny"><prosody rate="slow"><emphasis>Hi</emphasis></prosody></voice></speak>"#,
+/// )
+/// .unwrap();
+///
+/// assert_eq!(selector.select(&ssml).len(), 1);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Selector(Vec<Complex>);
+
+/// An error in the syntax of a [`Selector`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SelectorError {
+    position: usize,
+    expected: &'static str,
+}
+
+impl SelectorError {
+    /// Returns the byte offset in the selector where the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl fmt::Display for SelectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid selector: expected {} at position {}",
+            self.expected, self.position
+        )
+    }
+}
+
+impl std::error::Error for SelectorError {}
+
+// Compound selectors joined by combinators, e.g. `voice > s emphasis`
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Complex {
+    compounds: Vec<Compound>,
+    combinators: Vec<Combinator>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Combinator {
+    Descendant,
+    Child,
+}
+
+// Conditions on a single element, e.g. `say-as[interpret-as=date]:first-child`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct Compound {
+    // Matches only the element a `:has()` argument is relative to
+    scope: bool,
+    kind: Option<ElementKind>,
+    attributes: Vec<Attribute>,
+    pseudo_classes: Vec<PseudoClass>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Attribute {
+    name: String,
+    predicate: Option<(Operator, String)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Equals,
+    Includes,
+    Prefix,
+    Suffix,
+    Substring,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum PseudoClass {
+    NthChild { a: i64, b: i64 },
+    LastChild,
+    Has(Vec<Complex>),
+}
+
+// An element on the path from the document root, with its position among its siblings
+#[derive(Debug, Clone, Copy)]
+struct Node<'a> {
+    element: &'a SsmlElement,
+    position: usize,
+    siblings: usize,
+}
+
+impl Selector {
+    /// Parses a selector.
+    pub fn parse(selector: &str) -> Result<Self, SelectorError> {
+        let mut parser = SelectorParser {
+            input: selector,
+            position: 0,
+        };
+        let list = parser.selector_list(false)?;
+        parser.skip_whitespace();
+        match parser.peek() {
+            None => Ok(Selector(list)),
+            Some(_) => Err(parser.error("a combinator, `,` or the end of the selector")),
+        }
+    }
+
+    /// Returns the elements of the document matching the selector, in document order.
+    pub fn select<'a>(&self, ssml: &'a SSML) -> Vec<&'a SsmlElement> {
+        let mut matches = Vec::new();
+        let _ = walk(
+            &ssml.elements,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &mut |chain, _| {
+                if self.matches(chain) {
+                    matches.push(chain[chain.len() - 1].element);
+                }
+                ControlFlow::Continue(())
+            },
+        );
+        matches
+    }
+
+    /// Calls `f` on every element of the document matching the selector and returns the
+    /// number of matches.
+    ///
+    /// All matches are found before `f` is first called. `f` is then called in reverse
+    /// document order, so descendants and later siblings are modified before the elements
+    /// whose changes could move them.
+    pub fn select_mut<F>(&self, ssml: &mut SSML, mut f: F) -> usize
+    where
+        F: FnMut(&mut SsmlElement),
+    {
+        let mut paths = Vec::new();
+        let _ = walk(
+            &ssml.elements,
+            &mut Vec::new(),
+            &mut Vec::new(),
+            &mut |chain, path| {
+                if self.matches(chain) {
+                    paths.push(path.to_vec());
+                }
+                ControlFlow::Continue(())
+            },
+        );
+
+        for path in paths.iter().rev() {
+            if let Some(element) = path::get_mut(&mut ssml.elements, path) {
+                f(element);
+            }
+        }
+        paths.len()
+    }
+
+    fn matches(&self, chain: &[Node<'_>]) -> bool {
+        self.0
+            .iter()
+            .any(|complex| complex.matches(chain, chain.len() - 1, None))
+    }
+}
+
+impl FromStr for Selector {
+    type Err = SelectorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Selector::parse(s)
+    }
+}
+
+impl SSML {
+    /// Returns the elements matching a CSS-like [`Selector`], in document order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// let ssml = serde_ssml::from_str(
+    ///     r#"<speak><s>On <say-as interpret-as="date">2024-01-01</say-as></s><say-as interpret-as="date">today</say-as></speak>"#,
+    /// )
+    /// .unwrap();
+    ///
+    /// let dates = ssml.select("say-as[interpret-as=date]").unwrap();
+    /// let in_sentences = ssml.select("s say-as[interpret-as=date]").unwrap();
+    /// assert_eq!((dates.len(), in_sentences.len()), (2, 1));
+    /// ```
+    pub fn select(&self, selector: &str) -> Result<Vec<&SsmlElement>, SelectorError> {
+        Ok(Selector::parse(selector)?.select(self))
+    }
+
+    /// Calls `f` on every element matching a CSS-like [`Selector`] and returns the number of
+    /// matches; see [`Selector::select_mut`] for the order of the calls.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::SsmlElement;
+    ///
+    /// let mut ssml = serde_ssml::from_str(r#"<speak><p><s>One</s><s>Two</s></p></speak>"#).unwrap();
+    /// let count = ssml
+    ///     .select_mut("p > s:last-child", |element| {
+    ///         *element = SsmlElement::Mark { name: "cut".to_string() };
+    ///     })
+    ///     .unwrap();
+    ///
+    /// assert_eq!(count, 1);
+    /// assert_eq!(ssml.to_string(), r#"<speak><p><s>One</s><mark name="cut"/></p></speak>"#);
+    /// ```
+    pub fn select_mut<F>(&mut self, selector: &str, f: F) -> Result<usize, SelectorError>
+    where
+        F: FnMut(&mut SsmlElement),
+    {
+        Ok(Selector::parse(selector)?.select_mut(self, f))
+    }
+}
+
+// Calls `visit` with the chain of ancestors and the child-index path of every element
+fn walk<'a, F>(
+    nodes: &'a [SsmlElement],
+    chain: &mut Vec<Node<'a>>,
+    path: &mut Vec<usize>,
+    visit: &mut F,
+) -> ControlFlow<()>
+where
+    F: FnMut(&[Node<'a>], &[usize]) -> ControlFlow<()>,
+{
+    let siblings = nodes
+        .iter()
+        .filter(|node| !matches!(node, SsmlElement::Text(_)))
+        .count();
+    let mut position = 0;
+
+    for (index, element) in nodes.iter().enumerate() {
+        if let SsmlElement::Text(_) = element {
+            continue;
+        }
+        position += 1;
+
+        chain.push(Node {
+            element,
+            position,
+            siblings,
+        });
+        path.push(index);
+        let flow = match visit(chain, path) {
+            ControlFlow::Continue(()) => walk(element.children(), chain, path, visit),
+            ControlFlow::Break(()) => ControlFlow::Break(()),
+        };
+        let _ = chain.pop();
+        let _ = path.pop();
+        flow?;
+    }
+    ControlFlow::Continue(())
+}
+
+impl Complex {
+    // Matches the compound at `index` against `chain[position]`, then the compounds to its
+    // left against the ancestors
+    fn matches_at(
+        &self,
+        chain: &[Node<'_>],
+        index: usize,
+        position: usize,
+        scope: Option<usize>,
+    ) -> bool {
+        if !self.compounds[index].matches(chain, position, scope) {
+            return false;
+        }
+        let Some(previous) = index.checked_sub(1) else {
+            return true;
+        };
+        match self.combinators[previous] {
+            Combinator::Child => {
+                position > 0 && self.matches_at(chain, previous, position - 1, scope)
+            }
+            Combinator::Descendant => {
+                (0..position).any(|ancestor| self.matches_at(chain, previous, ancestor, scope))
+            }
+        }
+    }
+
+    fn matches(&self, chain: &[Node<'_>], position: usize, scope: Option<usize>) -> bool {
+        self.matches_at(chain, self.compounds.len() - 1, position, scope)
+    }
+}
+
+impl Compound {
+    fn matches(&self, chain: &[Node<'_>], position: usize, scope: Option<usize>) -> bool {
+        if self.scope {
+            return scope == Some(position);
+        }
+
+        let node = chain[position];
+        if self.kind.is_some_and(|kind| kind != node.element.kind()) {
+            return false;
+        }
+
+        if !self.attributes.is_empty() {
+            let attributes = ser::attributes(node.element, &SerializeOptions::default());
+            let value = |name: &str| {
+                attributes
+                    .iter()
+                    .find(|(attribute, _)| *attribute == name)
+                    .map(|(_, value)| value.as_ref())
+            };
+            if !self
+                .attributes
+                .iter()
+                .all(|attribute| attribute.matches(value(&attribute.name)))
+            {
+                return false;
+            }
+        }
+
+        self.pseudo_classes
+            .iter()
+            .all(|pseudo_class| pseudo_class.matches(chain, position))
+    }
+}
+
+impl Attribute {
+    fn matches(&self, value: Option<&str>) -> bool {
+        let Some(value) = value else {
+            return false;
+        };
+        match &self.predicate {
+            None => true,
+            Some((Operator::Equals, expected)) => value == expected,
+            Some((Operator::Includes, expected)) => {
+                value.split_whitespace().any(|word| word == expected)
+            }
+            Some((Operator::Prefix, expected)) => value.starts_with(expected.as_str()),
+            Some((Operator::Suffix, expected)) => value.ends_with(expected.as_str()),
+            Some((Operator::Substring, expected)) => value.contains(expected.as_str()),
+        }
+    }
+}
+
+impl PseudoClass {
+    fn matches(&self, chain: &[Node<'_>], position: usize) -> bool {
+        let node = chain[position];
+        match self {
+            PseudoClass::NthChild { a, b } => {
+                // Whether `position = a * n + b` for some n >= 0, in a range where any `a`
+                // and `b` fit
+                let offset = node.position as i128 - i128::from(*b);
+                match i128::from(*a) {
+                    0 => offset == 0,
+                    a => offset % a == 0 && offset / a >= 0,
+                }
+            }
+            PseudoClass::LastChild => node.position == node.siblings,
+            PseudoClass::Has(relative) => {
+                let mut scoped = chain[..=position].to_vec();
+                let found = walk(
+                    node.element.children(),
+                    &mut scoped,
+                    &mut Vec::new(),
+                    &mut |chain, _| {
+                        let candidate = chain.len() - 1;
+                        if relative
+                            .iter()
+                            .any(|complex| complex.matches(chain, candidate, Some(position)))
+                        {
+                            ControlFlow::Break(())
+                        } else {
+                            ControlFlow::Continue(())
+                        }
+                    },
+                );
+                found.is_break()
+            }
+        }
+    }
+}
+
+struct SelectorParser<'s> {
+    input: &'s str,
+    position: usize,
+}
+
+impl<'s> SelectorParser<'s> {
+    fn error(&self, expected: &'static str) -> SelectorError {
+        SelectorError {
+            position: self.position,
+            expected,
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn eat(&mut self, c: char) -> bool {
+        if self.peek() == Some(c) {
+            self.position += c.len_utf8();
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, c: char, expected: &'static str) -> Result<(), SelectorError> {
+        if self.eat(c) {
+            Ok(())
+        } else {
+            Err(self.error(expected))
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.position += c.len_utf8();
+        }
+    }
+
+    // Consumes characters while `accept` holds and returns them
+    fn take_while(&mut self, accept: impl Fn(char) -> bool) -> &'s str {
+        let start = self.position;
+        while let Some(c) = self.peek().filter(|c| accept(*c)) {
+            self.position += c.len_utf8();
+        }
+        &self.input[start..self.position]
+    }
+
+    fn selector_list(&mut self, relative: bool) -> Result<Vec<Complex>, SelectorError> {
+        let mut list = Vec::new();
+        loop {
+            self.skip_whitespace();
+            list.push(self.complex(relative)?);
+            self.skip_whitespace();
+            if !self.eat(',') {
+                return Ok(list);
+            }
+        }
+    }
+
+    fn complex(&mut self, relative: bool) -> Result<Complex, SelectorError> {
+        let mut complex = Complex {
+            compounds: Vec::new(),
+            combinators: Vec::new(),
+        };
+
+        if relative {
+            complex.compounds.push(Compound {
+                scope: true,
+                ..Compound::default()
+            });
+            complex.combinators.push(if self.eat('>') {
+                self.skip_whitespace();
+                Combinator::Child
+            } else {
+                Combinator::Descendant
+            });
+        }
+        complex.compounds.push(self.compound()?);
+
+        loop {
+            let start = self.position;
+            self.skip_whitespace();
+            let whitespace = self.position > start;
+            let combinator = if self.eat('>') {
+                self.skip_whitespace();
+                Combinator::Child
+            } else if whitespace && self.peek().is_some_and(|c| !matches!(c, ',' | ')')) {
+                Combinator::Descendant
+            } else {
+                self.position = start;
+                return Ok(complex);
+            };
+            complex.combinators.push(combinator);
+            complex.compounds.push(self.compound()?);
+        }
+    }
+
+    fn compound(&mut self) -> Result<Compound, SelectorError> {
+        let start = self.position;
+        let mut compound = Compound::default();
+
+        if !self.eat('*') {
+            let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-');
+            if !name.is_empty() {
+                let kind = ElementKind::ALL
+                    .into_iter()
+                    .find(|kind| *kind != ElementKind::Text && kind.tag_name() == name);
+                match kind {
+                    Some(kind) => compound.kind = Some(kind),
+                    None => {
+                        self.position = start;
+                        return Err(self.error("an SSML element name"));
+                    }
+                }
+            }
+        }
+
+        loop {
+            if self.eat('[') {
+                compound.attributes.push(self.attribute()?);
+            } else if self.eat(':') {
+                compound.pseudo_classes.push(self.pseudo_class()?);
+            } else {
+                break;
+            }
+        }
+
+        if self.position == start {
+            return Err(self.error("a tag name, `*`, `[` or `:`"));
+        }
+        Ok(compound)
+    }
+
+    fn attribute(&mut self) -> Result<Attribute, SelectorError> {
+        self.skip_whitespace();
+        let name = self
+            .take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | ':' | '_'))
+            .to_string();
+        if name.is_empty() {
+            return Err(self.error("an attribute name"));
+        }
+        self.skip_whitespace();
+
+        let operator = match self.peek() {
+            Some(']') => None,
+            Some('=') => Some(Operator::Equals),
+            Some('~') => Some(Operator::Includes),
+            Some('^') => Some(Operator::Prefix),
+            Some('$') => Some(Operator::Suffix),
+            Some('*') => Some(Operator::Substring),
+            _ => return Err(self.error("`]` or an attribute operator")),
+        };
+        let Some(operator) = operator else {
+            self.position += 1;
+            return Ok(Attribute {
+                name,
+                predicate: None,
+            });
+        };
+        if operator != Operator::Equals {
+            self.position += 1;
+        }
+        self.expect('=', "`=`")?;
+        self.skip_whitespace();
+
+        let value = match self.peek() {
+            Some(quote @ ('"' | '\'')) => {
+                self.position += 1;
+                let value = self.take_while(|c| c != quote).to_string();
+                self.expect(quote, "a closing quote")?;
+                value
+            }
+            _ => {
+                let value = self
+                    .take_while(|c| !c.is_whitespace() && !matches!(c, ']' | '[' | '"' | '\''))
+                    .to_string();
+                if value.is_empty() {
+                    return Err(self.error("an attribute value"));
+                }
+                value
+            }
+        };
+        self.skip_whitespace();
+        self.expect(']', "`]`")?;
+
+        Ok(Attribute {
+            name,
+            predicate: Some((operator, value)),
+        })
+    }
+
+    fn pseudo_class(&mut self) -> Result<PseudoClass, SelectorError> {
+        let start = self.position;
+        let name = self.take_while(|c| c.is_ascii_alphanumeric() || c == '-');
+        let pseudo_class = match name {
+            "first-child" => PseudoClass::NthChild { a: 0, b: 1 },
+            "last-child" => PseudoClass::LastChild,
+            "nth-child" => {
+                self.expect('(', "`(`")?;
+                self.skip_whitespace();
+                let (a, b) = self.nth()?;
+                self.skip_whitespace();
+                self.expect(')', "`)`")?;
+                PseudoClass::NthChild { a, b }
+            }
+            "has" => {
+                self.expect('(', "`(`")?;
+                let relative = self.selector_list(true)?;
+                self.expect(')', "`)`")?;
+                PseudoClass::Has(relative)
+            }
+            _ => {
+                self.position = start;
+                return Err(self.error("`first-child`, `last-child`, `nth-child` or `has`"));
+            }
+        };
+        Ok(pseudo_class)
+    }
+
+    // Parses the `an+b` argument of `:nth-child()`
+    fn nth(&mut self) -> Result<(i64, i64), SelectorError> {
+        let start = self.position;
+        let argument =
+            self.take_while(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | ' '));
+        let argument: String = argument.chars().filter(|c| *c != ' ').collect();
+
+        let parsed = match argument.to_ascii_lowercase().as_str() {
+            "odd" => Some((2, 1)),
+            "even" => Some((2, 0)),
+            argument => match argument.split_once('n') {
+                None => argument.parse().ok().map(|b| (0, b)),
+                Some((a, b)) => {
+                    let a = match a {
+                        "" | "+" => Some(1),
+                        "-" => Some(-1),
+                        a => a.parse().ok(),
+                    };
+                    let b = match b {
+                        "" => Some(0),
+                        b if b.starts_with(['+', '-']) => b.parse().ok(),
+                        _ => None,
+                    };
+                    a.zip(b)
+                }
+            },
+        };
+
+        parsed.ok_or_else(|| {
+            self.position = start;
+            self.error("an `an+b` expression, `odd` or `even`")
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nth(argument: &str) -> Result<(i64, i64), SelectorError> {
+        let mut parser = SelectorParser {
+            input: argument,
+            position: 0,
+        };
+        parser.nth()
+    }
+
+    #[test]
+    fn test_nth() {
+        assert_eq!(nth("odd"), Ok((2, 1)));
+        assert_eq!(nth("even"), Ok((2, 0)));
+        assert_eq!(nth("3"), Ok((0, 3)));
+        assert_eq!(nth("n"), Ok((1, 0)));
+        assert_eq!(nth("-n + 3"), Ok((-1, 3)));
+        assert_eq!(nth("2n+1"), Ok((2, 1)));
+        assert_eq!(nth("+3n-2"), Ok((3, -2)));
+        assert!(nth("n2").is_err());
+        assert!(nth("x").is_err());
+
+        // Extreme values parse and match nothing instead of overflowing
+        let ssml = crate::from_str("<speak><s>One</s><s>Two</s></speak>").unwrap();
+        assert_eq!(nth("-9223372036854775808"), Ok((0, i64::MIN)));
+        for selector in [
+            ":nth-child(-9223372036854775808)",
+            "s:nth-child(-9223372036854775808n+9223372036854775807)",
+            "s:nth-child(-1n-9223372036854775808)",
+        ] {
+            assert_eq!(ssml.select(selector), Ok(Vec::new()));
+        }
+        assert_eq!(
+            ssml.select("s:nth-child(9223372036854775807n+2)")
+                .map(|s| s.len()),
+            Ok(1)
+        );
+    }
+}
@@ -1,213 +1,391 @@
-use crate::{SSML, SsmlElement};
+use std::borrow::Cow;
 
-/// Converts the SSML document to an XML string
-pub(crate) fn to_ssml(ssml: &SSML) -> String {
-    // Start with XML declaration (optional)
-    let mut output = String::new();
+use crate::{
+    ElementKind, SSML, SSML_NAMESPACE, SsmlElement,
+    escape::{escape_attr, escape_text},
+};
+
+/// Formatting options for [`crate::to_string_with_options`].
+///
+/// The defaults produce the same compact output as [`crate::to_string`]. Individual options
+/// exist because some speech platforms, notably older IVR systems, only accept a particular
+/// flavour of XML.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::{AttributeOrder, EmptyElements, Quote, SerializeOptions};
+///
+/// let ssml = serde_ssml::from_str(r#"<speak xml:lang="en-US">Hi<break time="1s"/></speak>"#)
+///     .unwrap();
+/// let options = SerializeOptions::new()
+///     .xml_declaration(true)
+///     .default_namespace(true)
+///     .quote(Quote::Single)
+///     .empty_elements(EmptyElements::Expanded)
+///     .attribute_order(AttributeOrder::Alphabetical);
+///
+/// assert_eq!(
+///     serde_ssml::to_string_with_options(&ssml, &options),
+///     "<?xml version='1.0'?><speak version='1.1' xml:lang='en-US' \
+///      xmlns='http://www.w3.org/2001/10/synthesis'>Hi<break time='1000ms'></break></speak>"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct SerializeOptions {
+    xml_declaration: bool,
+    default_namespace: bool,
+    quote: Quote,
+    empty_elements: EmptyElements,
+    attribute_order: AttributeOrder,
+    indent: Option<usize>,
+}
+
+impl SerializeOptions {
+    /// Creates options producing the compact output of [`crate::to_string`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether to start the document with `<?xml version="1.0"?>`.
+    pub fn xml_declaration(mut self, xml_declaration: bool) -> Self {
+        self.xml_declaration = xml_declaration;
+        self
+    }
 
-    // Add all top-level elements
-    for element in ssml.elements.iter() {
-        output.push_str(&to_ssml_element(element));
+    /// Whether to add `version="1.1"` and the SSML namespace to `<speak>` elements missing them.
+    pub fn default_namespace(mut self, default_namespace: bool) -> Self {
+        self.default_namespace = default_namespace;
+        self
     }
 
-    output
+    /// The character attribute values are quoted with.
+    pub fn quote(mut self, quote: Quote) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    /// How `<break>`, `<mark>` and `<lexicon>` are written.
+    pub fn empty_elements(mut self, empty_elements: EmptyElements) -> Self {
+        self.empty_elements = empty_elements;
+        self
+    }
+
+    /// The order attributes are written in.
+    pub fn attribute_order(mut self, attribute_order: AttributeOrder) -> Self {
+        self.attribute_order = attribute_order;
+        self
+    }
+
+    /// Indents block elements by `width` spaces per level, see [`crate::to_string_pretty`].
+    pub fn indent(mut self, width: usize) -> Self {
+        self.indent = Some(width);
+        self
+    }
 }
 
-fn to_ssml_element(element: &SsmlElement) -> String {
-    match element {
-        SsmlElement::Speak {
-            version,
-            xmlns,
-            lang,
-            children,
-        } => {
-            let mut attrs = Vec::new();
-            if let Some(v) = version {
-                attrs.push(format!("version=\"{}\"", v));
-            }
-            if let Some(x) = xmlns {
-                attrs.push(format!("xmlns=\"{}\"", x));
-            }
-            if let Some(l) = lang {
-                attrs.push(format!("xml:lang=\"{}\"", l));
-            }
+/// Quote character for attribute values, see [`SerializeOptions::quote`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum Quote {
+    /// `name="value"`
+    #[default]
+    Double,
 
-            let attr_str = if attrs.is_empty() {
-                String::new()
-            } else {
-                format!(" {}", attrs.join(" "))
-            };
+    /// `name='value'`
+    Single,
+}
 
-            let child_content: String = children.iter().map(to_ssml_element).collect();
+impl Quote {
+    fn as_char(self) -> char {
+        match self {
+            Quote::Double => '"',
+            Quote::Single => '\'',
+        }
+    }
+}
 
-            format!("<speak{}>{}</speak>", attr_str, child_content)
+/// How elements that never have content are written, see [`SerializeOptions::empty_elements`].
+///
+/// Container elements without children, such as an empty `<p>`, are always written with a
+/// closing tag.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum EmptyElements {
+    /// `<break/>`
+    #[default]
+    SelfClosing,
+
+    /// `<break></break>`
+    Expanded,
+}
+
+/// Attribute order, see [`SerializeOptions::attribute_order`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[non_exhaustive]
+pub enum AttributeOrder {
+    /// The order the attributes are declared in on [`SsmlElement`], e.g. `version`, `xmlns`,
+    /// `xml:lang` for `<speak>`.
+    #[default]
+    Declaration,
+
+    /// Sorted by attribute name.
+    Alphabetical,
+}
+
+/// Indentation width used by [`crate::to_string_pretty`]
+pub(crate) const DEFAULT_INDENT: usize = 2;
+
+/// Converts the SSML document to an XML string
+pub(crate) fn to_ssml(ssml: &SSML, options: &SerializeOptions) -> String {
+    let mut writer = Writer::new(options);
+    if options.xml_declaration {
+        let quote = options.quote.as_char();
+        writer.output.push_str("<?xml version=");
+        writer.output.push(quote);
+        writer.output.push_str("1.0");
+        writer.output.push(quote);
+        writer.output.push_str("?>");
+        if options.indent.is_some() && !ssml.elements.is_empty() {
+            writer.newline(0);
+        }
+    }
+    writer.nodes(&ssml.elements, 0, true);
+    writer.output
+}
+
+/// Converts a sequence of sibling elements to an XML string
+pub(crate) fn to_ssml_fragment(elements: &[SsmlElement], options: &SerializeOptions) -> String {
+    let mut writer = Writer::new(options);
+    writer.nodes(elements, 0, true);
+    writer.output
+}
+
+struct Writer<'o> {
+    output: String,
+    options: &'o SerializeOptions,
+}
+
+impl<'o> Writer<'o> {
+    fn new(options: &'o SerializeOptions) -> Self {
+        Self {
+            output: String::new(),
+            options,
         }
-        SsmlElement::Voice { name, children } => {
-            let name_attr = if name.is_empty() {
-                String::new()
-            } else {
-                format!(" name=\"{}\"", name)
-            };
+    }
 
-            let child_content: String = children.iter().map(to_ssml_element).collect();
+    // Writes sibling nodes, one per line when `block` allows it
+    fn nodes(&mut self, nodes: &[SsmlElement], depth: usize, block: bool) {
+        let expand = self.options.indent.is_some() && block && nodes.iter().all(is_standalone);
 
-            format!("<voice{}>{}</voice>", name_attr, child_content)
+        for (index, node) in nodes.iter().enumerate() {
+            if expand && index > 0 {
+                self.newline(depth);
+            }
+            self.node(node, depth, expand);
         }
-        SsmlElement::Paragraph { children } => {
-            let child_content: String = children.iter().map(to_ssml_element).collect();
+    }
 
-            format!("<p>{}</p>", child_content)
+    fn node(&mut self, node: &SsmlElement, depth: usize, block: bool) {
+        let SsmlElement::Text(text) = node else {
+            return self.element(node, depth, block);
+        };
+        self.output.push_str(&escape_text(text));
+    }
+
+    fn element(&mut self, element: &SsmlElement, depth: usize, block: bool) {
+        let name = element.kind().tag_name();
+        let quote = self.options.quote.as_char();
+
+        let mut attributes = attributes(element, self.options);
+        if self.options.attribute_order == AttributeOrder::Alphabetical {
+            attributes.sort_by_key(|(attribute, _)| *attribute);
         }
-        SsmlElement::Sentence { children } => {
-            let child_content: String = children.iter().map(to_ssml_element).collect();
 
-            format!("<s>{}</s>", child_content)
+        self.output.push('<');
+        self.output.push_str(name);
+        for (attribute, value) in attributes {
+            self.output.push(' ');
+            self.output.push_str(attribute);
+            self.output.push('=');
+            self.output.push(quote);
+            self.output.push_str(&escape_attr(&value, quote));
+            self.output.push(quote);
         }
-        SsmlElement::Phoneme {
-            alphabet,
-            ph,
-            children,
-        } => {
-            let mut attrs = Vec::new();
-            if !alphabet.is_empty() {
-                attrs.push(format!("alphabet=\"{}\"", alphabet));
-            }
-            if !ph.is_empty() {
-                attrs.push(format!("ph=\"{}\"", ph));
+
+        let Some(children) = children(element) else {
+            match self.options.empty_elements {
+                EmptyElements::SelfClosing => self.output.push_str("/>"),
+                EmptyElements::Expanded => {
+                    self.output.push_str("></");
+                    self.output.push_str(name);
+                    self.output.push('>');
+                }
             }
+            return;
+        };
+        self.output.push('>');
 
-            let attr_str = if attrs.is_empty() {
-                String::new()
-            } else {
-                format!(" {}", attrs.join(" "))
-            };
+        // Children are only laid out on their own lines inside block elements which were
+        // themselves laid out that way, so no whitespace is ever added next to spoken text
+        let expand = self.options.indent.is_some()
+            && block
+            && is_block(element.kind())
+            && !children.is_empty()
+            && children.iter().all(is_standalone);
+        if expand {
+            self.newline(depth + 1);
+        }
+        self.nodes(children, depth + 1, expand);
+        if expand {
+            self.newline(depth);
+        }
+
+        self.output.push_str("</");
+        self.output.push_str(name);
+        self.output.push('>');
+    }
 
-            let child_content: String = children.iter().map(to_ssml_element).collect();
+    fn newline(&mut self, depth: usize) {
+        self.output.push('\n');
+        let width = self.options.indent.unwrap_or(0);
+        self.output.extend(std::iter::repeat_n(' ', width * depth));
+    }
+}
+
+// Elements whose content is laid out on indented lines by the pretty printer
+fn is_block(kind: ElementKind) -> bool {
+    matches!(
+        kind,
+        ElementKind::Speak
+            | ElementKind::Voice
+            | ElementKind::Paragraph
+            | ElementKind::Sentence
+            | ElementKind::Prosody
+            | ElementKind::Lang
+    )
+}
 
-            format!("<phoneme{}>{}</phoneme>", attr_str, child_content)
+// Nodes that may be put on their own line: block elements and empty elements, but not text
+// or inline markup such as `<emphasis>`
+fn is_standalone(node: &SsmlElement) -> bool {
+    let kind = node.kind();
+    is_block(kind)
+        || matches!(
+            kind,
+            ElementKind::Break | ElementKind::Mark | ElementKind::LexiconUri
+        )
+}
+
+// Attributes written for an element, in declaration order; empty optional values are omitted
+pub(crate) fn attributes<'e>(
+    element: &'e SsmlElement,
+    options: &SerializeOptions,
+) -> Vec<(&'static str, Cow<'e, str>)> {
+    fn optional<'a>(
+        attributes: &mut Vec<(&'static str, Cow<'a, str>)>,
+        name: &'static str,
+        value: &'a str,
+    ) {
+        if !value.is_empty() {
+            attributes.push((name, Cow::Borrowed(value)));
+        }
+    }
+
+    let mut attributes = Vec::new();
+    match element {
+        SsmlElement::Speak {
+            version,
+            xmlns,
+            lang,
+            ..
+        } => {
+            let (mut version, mut xmlns) = (version.as_deref(), xmlns.as_deref());
+            if options.default_namespace {
+                version = version.or(Some("1.1"));
+                xmlns = xmlns.or(Some(SSML_NAMESPACE));
+            }
+            for (name, value) in [
+                ("version", version),
+                ("xmlns", xmlns),
+                ("xml:lang", lang.as_deref()),
+            ] {
+                if let Some(value) = value {
+                    attributes.push((name, Cow::Borrowed(value)));
+                }
+            }
+        }
+        SsmlElement::Voice { name, .. } => optional(&mut attributes, "name", name),
+        SsmlElement::Phoneme { alphabet, ph, .. } => {
+            optional(&mut attributes, "alphabet", alphabet);
+            optional(&mut attributes, "ph", ph);
         }
         SsmlElement::SayAs {
             interpret_as,
             format,
             detail,
-            children,
+            ..
         } => {
-            let mut attrs = Vec::new();
-            if !interpret_as.is_empty() {
-                attrs.push(format!("interpret-as=\"{}\"", interpret_as));
-            }
-            if !format.is_empty() {
-                attrs.push(format!("format=\"{}\"", format));
-            }
-            if !detail.is_empty() {
-                attrs.push(format!("detail=\"{}\"", detail));
-            }
-
-            let attr_str = if attrs.is_empty() {
-                String::new()
-            } else {
-                format!(" {}", attrs.join(" "))
-            };
-
-            let child_content: String = children.iter().map(to_ssml_element).collect();
-
-            format!("<say-as{}>{}</say-as>", attr_str, child_content)
-        }
-        SsmlElement::Sub { alias, children } => {
-            let alias_attr = if alias.is_empty() {
-                String::new()
-            } else {
-                format!(" alias=\"{}\"", alias)
-            };
-
-            let child_content: String = children.iter().map(to_ssml_element).collect();
-
-            format!("<sub{}>{}</sub>", alias_attr, child_content)
+            optional(&mut attributes, "interpret-as", interpret_as);
+            optional(&mut attributes, "format", format);
+            optional(&mut attributes, "detail", detail);
         }
+        SsmlElement::Sub { alias, .. } => optional(&mut attributes, "alias", alias),
         SsmlElement::Prosody {
             rate,
             pitch,
             contour,
             range,
             volume,
-            children,
+            ..
         } => {
-            let mut attrs = Vec::new();
-            if !rate.is_empty() {
-                attrs.push(format!("rate=\"{}\"", rate));
-            }
-            if !pitch.is_empty() {
-                attrs.push(format!("pitch=\"{}\"", pitch));
-            }
-            if !contour.is_empty() {
-                attrs.push(format!("contour=\"{}\"", contour));
-            }
-            if !range.is_empty() {
-                attrs.push(format!("range=\"{}\"", range));
-            }
-            if !volume.is_empty() {
-                attrs.push(format!("volume=\"{}\"", volume));
-            }
-
-            let attr_str = if attrs.is_empty() {
-                String::new()
-            } else {
-                format!(" {}", attrs.join(" "))
-            };
-
-            let child_content: String = children.iter().map(to_ssml_element).collect();
-
-            format!("<prosody{}>{}</prosody>", attr_str, child_content)
-        }
-        SsmlElement::Emphasis { level, children } => {
-            let level_attr = if level.is_empty() {
-                String::new()
-            } else {
-                format!(" level=\"{}\"", level)
-            };
-
-            let child_content: String = children.iter().map(to_ssml_element).collect();
-
-            format!("<emphasis{}>{}</emphasis>", level_attr, child_content)
+            optional(&mut attributes, "rate", rate);
+            optional(&mut attributes, "pitch", pitch);
+            optional(&mut attributes, "contour", contour);
+            optional(&mut attributes, "range", range);
+            optional(&mut attributes, "volume", volume);
         }
+        SsmlElement::Emphasis { level, .. } => optional(&mut attributes, "level", level),
         SsmlElement::Break { time, strength } => {
-            let mut attrs = Vec::new();
             if let Some(time) = time {
-                attrs.push(format!("time=\"{}ms\"", time.as_millis()));
+                attributes.push(("time", Cow::Owned(format!("{}ms", time.as_millis()))));
             }
-            if let Some(s) = strength {
-                attrs.push(format!("strength=\"{}\"", &s.to_string()));
+            if let Some(strength) = strength {
+                attributes.push(("strength", Cow::Borrowed(strength.to_ssml())));
             }
-
-            let attr_str = if attrs.is_empty() {
-                String::new()
-            } else {
-                format!(" {}", attrs.join(" "))
-            };
-
-            format!("<break{}/>", attr_str)
         }
-        SsmlElement::Mark { name } => {
-            format!("<mark name=\"{}\"/>", name)
+        SsmlElement::Mark { name } => attributes.push(("name", Cow::Borrowed(name.as_str()))),
+        SsmlElement::Audio { src, .. } => optional(&mut attributes, "src", src),
+        SsmlElement::LexiconUri { uri } => attributes.push(("uri", Cow::Borrowed(uri.as_str()))),
+        SsmlElement::Lang { xml_lang, .. } => {
+            attributes.push(("xml:lang", Cow::Borrowed(xml_lang.as_str())))
         }
-        SsmlElement::Audio { src, children } => {
-            let child_content: String = children.iter().map(to_ssml_element).collect();
-
-            format!("<audio src=\"{}\">{}</audio>", src, child_content)
-        }
-        SsmlElement::Desc { children } => {
-            let child_content: String = children.iter().map(to_ssml_element).collect();
-
-            format!("<desc>{}</desc>", child_content)
-        }
-        SsmlElement::LexiconUri { uri } => {
-            format!("<lexicon uri=\"{}\"/>", uri)
-        }
-        SsmlElement::Lang { xml_lang, children } => {
-            let child_content: String = children.iter().map(to_ssml_element).collect();
+        SsmlElement::Paragraph { .. }
+        | SsmlElement::Sentence { .. }
+        | SsmlElement::Desc { .. }
+        | SsmlElement::Text(_) => {}
+    }
+    attributes
+}
 
-            format!("<lang xml:lang=\"{}\">{}</lang>", xml_lang, child_content,)
-        }
-        SsmlElement::Text(text) => text.to_owned(),
+// Children of a container element, or `None` for empty elements written as `<name/>`
+pub(crate) fn children(element: &SsmlElement) -> Option<&[SsmlElement]> {
+    match element {
+        SsmlElement::Voice { children, .. }
+        | SsmlElement::Speak { children, .. }
+        | SsmlElement::Paragraph { children }
+        | SsmlElement::Sentence { children }
+        | SsmlElement::Phoneme { children, .. }
+        | SsmlElement::SayAs { children, .. }
+        | SsmlElement::Sub { children, .. }
+        | SsmlElement::Prosody { children, .. }
+        | SsmlElement::Emphasis { children, .. }
+        | SsmlElement::Audio { children, .. }
+        | SsmlElement::Desc { children }
+        | SsmlElement::Lang { children, .. } => Some(children),
+        SsmlElement::Break { .. }
+        | SsmlElement::Mark { .. }
+        | SsmlElement::LexiconUri { .. }
+        | SsmlElement::Text(_) => None,
     }
 }
@@ -0,0 +1,304 @@
+//! Splitting documents into chunks that fit the request limits of speech services.
+
+use std::mem;
+
+use crate::{ElementKind, SSML, SsmlElement};
+
+/// Size limits for the chunks created by [`split`].
+///
+/// Every limit is unbounded by default. The presets match the documented request limits of
+/// common speech services.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::SplitLimits;
+///
+/// let limits = SplitLimits::polly().max_chars(4000);
+/// assert_eq!(limits, SplitLimits::new().max_billable_chars(3000).max_chars(4000));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SplitLimits {
+    max_chars: usize,
+    max_billable_chars: usize,
+    max_bytes: usize,
+}
+
+impl SplitLimits {
+    /// Creates limits without any bounds.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits of Amazon Polly: 3,000 billable characters and 6,000 characters in total.
+    pub fn polly() -> Self {
+        Self::new().max_billable_chars(3000).max_chars(6000)
+    }
+
+    /// Limits of Google Cloud Text-to-Speech: 5,000 bytes of input.
+    pub fn google() -> Self {
+        Self::new().max_bytes(5000)
+    }
+
+    /// Limits of the Azure AI Speech real-time API: 64 KiB of SSML per request.
+    pub fn azure() -> Self {
+        Self::new().max_bytes(64 * 1024)
+    }
+
+    /// Maximum number of characters of the serialized chunk, including markup.
+    pub fn max_chars(mut self, max_chars: usize) -> Self {
+        self.max_chars = max_chars;
+        self
+    }
+
+    /// Maximum number of characters of text content in the chunk, not counting markup.
+    pub fn max_billable_chars(mut self, max_billable_chars: usize) -> Self {
+        self.max_billable_chars = max_billable_chars;
+        self
+    }
+
+    /// Maximum length of the serialized chunk in bytes.
+    pub fn max_bytes(mut self, max_bytes: usize) -> Self {
+        self.max_bytes = max_bytes;
+        self
+    }
+
+    fn fit(&self, elements: &[SsmlElement]) -> bool {
+        let markup = crate::to_fragment_string(elements);
+        let billable: usize = elements
+            .iter()
+            .flat_map(SsmlElement::texts)
+            .map(|text| text.chars().count())
+            .sum();
+        markup.len() <= self.max_bytes
+            && billable <= self.max_billable_chars
+            && markup.chars().count() <= self.max_chars
+    }
+}
+
+impl Default for SplitLimits {
+    fn default() -> Self {
+        Self {
+            max_chars: usize::MAX,
+            max_billable_chars: usize::MAX,
+            max_bytes: usize::MAX,
+        }
+    }
+}
+
+/// Splits a document into standalone documents that each fit `limits`.
+///
+/// Content is packed into as few chunks as possible, breaking between paragraphs where
+/// possible, then between sentences and finally between words. Each chunk re-opens the
+/// `<speak>`, `<voice>`, `<prosody>`, `<lang>`, `<p>`, `<s>` and `<emphasis>` elements the
+/// break falls into, including the `<lexicon>` references of `<speak>`. All other nodes,
+/// such as `<mark>`, `<say-as>` or `<audio>`, are kept whole; a single word or such node
+/// that exceeds the limits by itself gets a chunk of its own.
+///
+/// # Examples
+///
+/// ```rust
+/// use serde_ssml::SplitLimits;
+///
+/// let ssml = serde_ssml::from_str(
+///     r#"<speak><prosody rate="slow"><s>First sentence.</s><s>Second sentence.</s></prosody></speak>"#,
+/// )
+/// .unwrap();
+/// let chunks = serde_ssml::split(&ssml, &SplitLimits::new().max_billable_chars(20));
+///
+/// assert_eq!(chunks.len(), 2);
+/// assert_eq!(
+///     chunks[1].to_string(),
+///     r#"<speak><prosody rate="slow"><s>Second sentence.</s></prosody></speak>"#
+/// );
+/// ```
+pub fn split(ssml: &SSML, limits: &SplitLimits) -> Vec<SSML> {
+    let mut splitter = Splitter {
+        limits,
+        stack: vec![Frame {
+            shell: None,
+            children: Vec::new(),
+        }],
+        chunks: Vec::new(),
+    };
+    for element in &ssml.elements {
+        splitter.add(element.clone(), false);
+    }
+    splitter.flush();
+    splitter.chunks
+}
+
+// An element that is open in the current chunk, with the children added to it so far
+struct Frame {
+    shell: Option<SsmlElement>,
+    children: Vec<SsmlElement>,
+}
+
+struct Splitter<'a> {
+    limits: &'a SplitLimits,
+    stack: Vec<Frame>,
+    chunks: Vec<SSML>,
+}
+
+impl Splitter<'_> {
+    // Adds a node to the innermost open element. Text is joined to a preceding text node
+    // with a space if `join` is set.
+    fn add(&mut self, node: SsmlElement, join: bool) {
+        if self.fits_with(&node, join) {
+            self.push(node, join);
+            return;
+        }
+        if !self.is_empty() {
+            self.flush();
+            if self.fits_with(&node, join) {
+                self.push(node, join);
+                return;
+            }
+        }
+
+        match node {
+            SsmlElement::Text(text) => {
+                let segments = match sentences(&text) {
+                    sentences if sentences.len() > 1 => sentences,
+                    _ => text.split_whitespace().collect(),
+                };
+                if segments.len() > 1 {
+                    for (index, segment) in segments.into_iter().enumerate() {
+                        self.add(SsmlElement::Text(segment.to_string()), join || index > 0);
+                    }
+                } else {
+                    self.push(SsmlElement::Text(text), join);
+                }
+            }
+            mut node if is_reopened(node.kind()) => {
+                let Some(children) = node.children_mut() else {
+                    return self.push(node, join);
+                };
+                let (context, content): (Vec<_>, Vec<_>) = mem::take(children)
+                    .into_iter()
+                    .partition(|child| matches!(child, SsmlElement::LexiconUri { .. }));
+                *children = context;
+
+                self.stack.push(Frame {
+                    shell: Some(node),
+                    children: Vec::new(),
+                });
+                for child in content {
+                    self.add(child, false);
+                }
+                if let Some(Frame {
+                    shell: Some(mut shell),
+                    children,
+                }) = self.stack.pop()
+                    && !children.is_empty()
+                {
+                    if let Some(slot) = shell.children_mut() {
+                        slot.extend(children);
+                    }
+                    self.push(shell, false);
+                }
+            }
+            node => self.push(node, join),
+        }
+    }
+
+    fn push(&mut self, node: SsmlElement, join: bool) {
+        if let Some(frame) = self.stack.last_mut() {
+            push(&mut frame.children, node, join);
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.stack.iter().all(|frame| frame.children.is_empty())
+    }
+
+    fn fits_with(&self, node: &SsmlElement, join: bool) -> bool {
+        let mut stack: Vec<_> = self
+            .stack
+            .iter()
+            .map(|frame| (frame.shell.as_ref(), frame.children.clone()))
+            .collect();
+        if let Some((_, children)) = stack.last_mut() {
+            push(children, node.clone(), join);
+        }
+        self.limits.fit(&build(stack))
+    }
+
+    // Completes the current chunk, keeping the open elements for the next one
+    fn flush(&mut self) {
+        if self.is_empty() {
+            return;
+        }
+        let stack = self
+            .stack
+            .iter_mut()
+            .map(|frame| (frame.shell.as_ref(), mem::take(&mut frame.children)))
+            .collect();
+        self.chunks.push(SSML {
+            elements: build(stack),
+        });
+    }
+}
+
+// Closes the open elements from the innermost outwards, skipping those without content
+fn build(stack: Vec<(Option<&SsmlElement>, Vec<SsmlElement>)>) -> Vec<SsmlElement> {
+    let mut inner = Vec::new();
+    for (shell, mut children) in stack.into_iter().rev() {
+        children.append(&mut inner);
+        match shell {
+            Some(shell) if !children.is_empty() => {
+                let mut element = shell.clone();
+                if let Some(slot) = element.children_mut() {
+                    slot.extend(children);
+                }
+                inner.push(element);
+            }
+            Some(_) => {}
+            None => inner = children,
+        }
+    }
+    inner
+}
+
+fn push(children: &mut Vec<SsmlElement>, node: SsmlElement, join: bool) {
+    match (children.last_mut(), node) {
+        (Some(SsmlElement::Text(last)), SsmlElement::Text(text)) if join => {
+            last.push(' ');
+            last.push_str(&text);
+        }
+        (_, node) => children.push(node),
+    }
+}
+
+// Elements that are opened again in every chunk their content is split across
+fn is_reopened(kind: ElementKind) -> bool {
+    matches!(
+        kind,
+        ElementKind::Speak
+            | ElementKind::Voice
+            | ElementKind::Prosody
+            | ElementKind::Lang
+            | ElementKind::Paragraph
+            | ElementKind::Sentence
+            | ElementKind::Emphasis
+    )
+}
+
+// Splits text after sentence-ending punctuation that is followed by whitespace
+fn sentences(text: &str) -> Vec<&str> {
+    let mut sentences = Vec::new();
+    let mut start = 0;
+    let mut chars = text.char_indices().peekable();
+    while let Some((_, c)) = chars.next() {
+        if matches!(c, '.' | '!' | '?' | '…')
+            && let Some(&(next, following)) = chars.peek()
+            && following.is_whitespace()
+        {
+            sentences.push(text[start..next].trim());
+            start = next;
+        }
+    }
+    sentences.push(text[start..].trim());
+    sentences.retain(|sentence| !sentence.is_empty());
+    sentences
+}
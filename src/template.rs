@@ -0,0 +1,421 @@
+//! Documents with placeholders that are parsed once and rendered many times.
+
+use std::{
+    borrow::Borrow,
+    collections::HashMap,
+    fmt,
+    hash::{BuildHasher, Hash},
+    mem,
+    str::FromStr,
+};
+
+use crate::{AttributeMut, ErrorKind, NodePath, ParseError, Parser, SSML, SsmlElement};
+
+/// A document with `{name}` placeholders in its text and attribute values.
+///
+/// The template is parsed and its placeholders are validated once. [`Template::render`] then
+/// fills in values without parsing again. Values are inserted as plain text or attribute
+/// values, so they are escaped when the document is serialized and cannot inject markup.
+///
+/// Placeholder names consist of ASCII letters, digits and underscores. `{{` and `}}` stand
+/// for literal braces. Placeholders may appear in text and in string-valued attributes, but
+/// not in `<break>` attributes, which are parsed into typed values. A name is used either
+/// for text or for attribute values, never for both.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::collections::HashMap;
+///
+/// use serde_ssml::Template;
+///
+/// let template = Template::parse(
+///     r#"<speak><voice name="{voice}">You owe <say-as interpret-as="currency">{amount}</say-as></voice></speak>"#,
+/// )
+/// .unwrap();
+///
+/// let values = HashMap::from([("voice", "Joanna"), ("amount", "$5 & change")]);
+/// let ssml = template.render(&values).unwrap();
+///
+/// assert_eq!(
+///     ssml.to_string(),
+///     r#"<speak><voice name="Joanna">You owe<say-as interpret-as="currency">$5 &amp; change</say-as></voice></speak>"#
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Template {
+    ssml: SSML,
+    placeholders: Vec<Placeholder>,
+    slots: Vec<Slot>,
+}
+
+/// A placeholder of a [`Template`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholder {
+    name: String,
+    kind: PlaceholderKind,
+}
+
+/// Where a [`Placeholder`] appears.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlaceholderKind {
+    /// In text content.
+    Text,
+    /// In attribute values.
+    Attribute,
+}
+
+// A text node or attribute value containing placeholders
+#[derive(Debug, Clone)]
+struct Slot {
+    path: NodePath,
+    attribute: Option<&'static str>,
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone)]
+enum Segment {
+    Literal(String),
+    // Index into the placeholders
+    Placeholder(usize),
+}
+
+impl Placeholder {
+    /// Returns the name of the placeholder.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns where the placeholder appears.
+    pub fn kind(&self) -> PlaceholderKind {
+        self.kind
+    }
+}
+
+impl Template {
+    /// Parses a template, validating the placeholders it contains.
+    pub fn parse(input: &str) -> Result<Self, TemplateError> {
+        let (ssml, dropped) = Parser::new()
+            .parse_with_dropped(input)
+            .map_err(TemplateError::Parse)?;
+        // Typed attributes drop values they cannot represent, placeholders included
+        for error in dropped {
+            if let ErrorKind::InvalidAttribute {
+                element,
+                attribute,
+                value,
+            } = error.kind()
+                && value.contains('{')
+            {
+                return Err(TemplateError::UnsupportedPosition {
+                    element: element.clone(),
+                    attribute: attribute.clone(),
+                });
+            }
+        }
+
+        let mut template = Template {
+            ssml,
+            placeholders: Vec::new(),
+            slots: Vec::new(),
+        };
+        let mut elements = mem::take(&mut template.ssml.elements);
+        template.scan(&mut elements, &mut Vec::new())?;
+        template.ssml.elements = elements;
+        Ok(template)
+    }
+
+    /// Returns the placeholders in order of their first appearance.
+    pub fn placeholders(&self) -> &[Placeholder] {
+        &self.placeholders
+    }
+
+    /// Renders the template with the values in `values`, keyed by placeholder name.
+    ///
+    /// Values for names the template does not use are ignored.
+    pub fn render<K, V, S>(&self, values: &HashMap<K, V, S>) -> Result<SSML, RenderError>
+    where
+        K: Borrow<str> + Eq + Hash,
+        V: AsRef<str>,
+        S: BuildHasher,
+    {
+        self.render_with(|name| values.get(name))
+    }
+
+    /// Renders the template with the values returned by `value` for each placeholder name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use serde_ssml::Template;
+    ///
+    /// let template: Template = "<speak>Hello {name}</speak>".parse().unwrap();
+    /// let ssml = template.render_with(|name| Some(name.to_uppercase())).unwrap();
+    ///
+    /// assert_eq!(ssml.to_string(), "<speak>Hello NAME</speak>");
+    /// assert!(template.render_with(|_| None::<&str>).is_err());
+    /// ```
+    pub fn render_with<F, V>(&self, mut value: F) -> Result<SSML, RenderError>
+    where
+        F: FnMut(&str) -> Option<V>,
+        V: AsRef<str>,
+    {
+        let values = self
+            .placeholders
+            .iter()
+            .map(|placeholder| match value(&placeholder.name) {
+                Some(value) => Ok(value.as_ref().to_string()),
+                None => Err(RenderError {
+                    name: placeholder.name.clone(),
+                }),
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut ssml = self.ssml.clone();
+        for slot in &self.slots {
+            let rendered: String = slot
+                .segments
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Literal(text) => text.as_str(),
+                    Segment::Placeholder(index) => values[*index].as_str(),
+                })
+                .collect();
+            if let Some(target) = ssml
+                .get_mut(&slot.path)
+                .and_then(|element| string_mut(element, slot.attribute))
+            {
+                *target = rendered;
+            }
+        }
+        Ok(ssml)
+    }
+
+    // Records the placeholders in the text and attributes of `nodes`, replacing strings
+    // without placeholders by their unescaped form
+    fn scan(
+        &mut self,
+        nodes: &mut [SsmlElement],
+        path: &mut Vec<usize>,
+    ) -> Result<(), TemplateError> {
+        for (index, node) in nodes.iter_mut().enumerate() {
+            path.push(index);
+            if let SsmlElement::Text(text) = node {
+                self.scan_string(text, PlaceholderKind::Text, path, None)?;
+            }
+            for attribute in ATTRIBUTES {
+                if let Some(value) = string_mut(node, Some(attribute)) {
+                    self.scan_string(value, PlaceholderKind::Attribute, path, Some(attribute))?;
+                }
+            }
+            if let Some(children) = node.children_mut() {
+                self.scan(children, path)?;
+            }
+            let _ = path.pop();
+        }
+        Ok(())
+    }
+
+    fn scan_string(
+        &mut self,
+        target: &mut String,
+        kind: PlaceholderKind,
+        path: &[usize],
+        attribute: Option<&'static str>,
+    ) -> Result<(), TemplateError> {
+        let mut segments = segments(target)?;
+        if let [Piece::Literal(text)] = segments.as_mut_slice() {
+            *target = mem::take(text);
+            return Ok(());
+        }
+
+        let segments = segments
+            .into_iter()
+            .map(|piece| match piece {
+                Piece::Literal(text) => Ok(Segment::Literal(text)),
+                Piece::Placeholder(name) => self.placeholder(name, kind).map(Segment::Placeholder),
+            })
+            .collect::<Result<_, _>>()?;
+        self.slots.push(Slot {
+            path: NodePath::from(path),
+            attribute,
+            segments,
+        });
+        Ok(())
+    }
+
+    // Returns the index of the placeholder called `name`, adding it if it is new
+    fn placeholder(&mut self, name: String, kind: PlaceholderKind) -> Result<usize, TemplateError> {
+        match self.placeholders.iter().position(|p| p.name == name) {
+            Some(index) if self.placeholders[index].kind == kind => Ok(index),
+            Some(_) => Err(TemplateError::ConflictingPlaceholder(name)),
+            None => {
+                self.placeholders.push(Placeholder { name, kind });
+                Ok(self.placeholders.len() - 1)
+            }
+        }
+    }
+}
+
+impl FromStr for Template {
+    type Err = TemplateError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Template::parse(s)
+    }
+}
+
+// Attributes that may hold placeholders, across all elements
+const ATTRIBUTES: [&str; 18] = [
+    "version",
+    "xmlns",
+    "xml:lang",
+    "name",
+    "alphabet",
+    "ph",
+    "interpret-as",
+    "format",
+    "detail",
+    "alias",
+    "rate",
+    "pitch",
+    "contour",
+    "range",
+    "volume",
+    "level",
+    "src",
+    "uri",
+];
+
+// Returns the text of a text node for `None`, or the value of a string-valued attribute that
+// is present on the element
+fn string_mut<'a>(element: &'a mut SsmlElement, attribute: Option<&str>) -> Option<&'a mut String> {
+    let Some(attribute) = attribute else {
+        return match element {
+            SsmlElement::Text(text) => Some(text),
+            _ => None,
+        };
+    };
+
+    match element.attribute_mut(attribute)? {
+        AttributeMut::Text(value) => Some(value),
+        AttributeMut::Optional(value) => value.as_mut(),
+        AttributeMut::Time(_) | AttributeMut::Strength(_) => None,
+    }
+}
+
+enum Piece {
+    Literal(String),
+    Placeholder(String),
+}
+
+// Splits a string into literal text and placeholder names
+fn segments(input: &str) -> Result<Vec<Piece>, TemplateError> {
+    let invalid = || TemplateError::InvalidPlaceholder(input.to_string());
+    let mut pieces = Vec::new();
+    let mut literal = String::new();
+    let mut chars = input.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                let _ = chars.next();
+                literal.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                let _ = chars.next();
+                literal.push('}');
+            }
+            '{' => {
+                let mut name = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) if c.is_ascii_alphanumeric() || c == '_' => name.push(c),
+                        _ => return Err(invalid()),
+                    }
+                }
+                if name.is_empty() {
+                    return Err(invalid());
+                }
+                if !literal.is_empty() {
+                    pieces.push(Piece::Literal(mem::take(&mut literal)));
+                }
+                pieces.push(Piece::Placeholder(name));
+            }
+            '}' => return Err(invalid()),
+            c => literal.push(c),
+        }
+    }
+    if !literal.is_empty() || pieces.is_empty() {
+        pieces.push(Piece::Literal(literal));
+    }
+    Ok(pieces)
+}
+
+/// The error returned when a [`Template`] cannot be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum TemplateError {
+    /// The template is not a valid document.
+    Parse(ParseError),
+
+    /// A text or attribute value with unbalanced braces or an invalid placeholder name.
+    InvalidPlaceholder(String),
+
+    /// A placeholder in an attribute whose value is not a string, such as `<break time>`.
+    UnsupportedPosition {
+        /// Name of the element carrying the attribute.
+        element: String,
+        /// Name of the attribute.
+        attribute: String,
+    },
+
+    /// A placeholder used both in text and in an attribute value.
+    ConflictingPlaceholder(String),
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TemplateError::Parse(errors) => match errors.first() {
+                Some(error) => write!(f, "invalid template: {error}"),
+                None => f.write_str("invalid template"),
+            },
+            TemplateError::InvalidPlaceholder(value) => {
+                write!(f, "invalid placeholder in \"{value}\"")
+            }
+            TemplateError::UnsupportedPosition { element, attribute } => write!(
+                f,
+                "placeholders are not supported in <{element}> attribute {attribute}"
+            ),
+            TemplateError::ConflictingPlaceholder(name) => write!(
+                f,
+                "placeholder {{{name}}} is used both in text and in an attribute"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// The error returned when rendering a [`Template`] without a value for one of its
+/// placeholders.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderError {
+    name: String,
+}
+
+impl RenderError {
+    /// Returns the name of the placeholder without a value.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl fmt::Display for RenderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "missing value for placeholder {{{}}}", self.name)
+    }
+}
+
+impl std::error::Error for RenderError {}
@@ -0,0 +1,875 @@
+//! Traversal traits for the document model, in the style of `syn::visit`.
+//!
+//! - [`Visit`] walks a borrowed document
+//! - [`VisitMut`] walks a document in place
+//! - [`Fold`] rebuilds a document by value
+//!
+//! Each trait has one method per element type, named after the variant (`visit_prosody`,
+//! `visit_break_mut`, `fold_say_as`, ...), plus methods for the document, any element and
+//! text. Every method defaults to the free function of the same name in this module, which
+//! dispatches or recurses into the children. Override a method to handle an element type and
+//! call the free function from the override to keep descending; not calling it skips the
+//! subtree.
+//!
+//! [`Visit`] and [`VisitMut`] methods return [`ControlFlow`]: returning
+//! `ControlFlow::Break(())` stops the traversal, and the free functions propagate it.
+//!
+//! # Examples
+//!
+//! Count the sentences outside of `<voice>` elements, stopping at the first `<mark>`:
+//!
+//! ```rust
+//! use std::ops::ControlFlow;
+//!
+//! use serde_ssml::{SsmlElement, visit::Visit};
+//!
+//! #[derive(Default)]
+//! struct Sentences(usize);
+//!
+//! impl<'ast> Visit<'ast> for Sentences {
+//!     fn visit_sentence(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+//!         self.0 += 1;
+//!         serde_ssml::visit::visit_sentence(self, element)
+//!     }
+//!
+//!     fn visit_voice(&mut self, _element: &'ast SsmlElement) -> ControlFlow<()> {
+//!         ControlFlow::Continue(())
+//!     }
+//!
+//!     fn visit_mark(&mut self, _element: &'ast SsmlElement) -> ControlFlow<()> {
+//!         ControlFlow::Break(())
+//!     }
+//! }
+//!
+//! let ssml = serde_ssml::from_str(
+//!     r#"<speak><s>One</s><voice name="a"><s>Two</s></voice><s>Three</s><mark name="m"/><s>Four</s></speak>"#,
+//! )
+//! .unwrap();
+//!
+//! let mut sentences = Sentences::default();
+//! let _ = sentences.visit_ssml(&ssml);
+//! assert_eq!(sentences.0, 2);
+//! ```
+
+use std::{mem, ops::ControlFlow};
+
+use crate::{SSML, SsmlElement};
+
+/// Read-only traversal of a document.
+///
+/// Element methods receive the whole [`SsmlElement`], which is always of the variant the
+/// method is named after.
+pub trait Visit<'ast> {
+    /// Visits a document.
+    fn visit_ssml(&mut self, ssml: &'ast SSML) -> ControlFlow<()> {
+        visit_ssml(self, ssml)
+    }
+
+    /// Visits any node, dispatching on its type.
+    fn visit_element(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_element(self, element)
+    }
+
+    /// Visits a `<speak>` element.
+    fn visit_speak(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_speak(self, element)
+    }
+
+    /// Visits a `<voice>` element.
+    fn visit_voice(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_voice(self, element)
+    }
+
+    /// Visits a `<p>` element.
+    fn visit_paragraph(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_paragraph(self, element)
+    }
+
+    /// Visits a `<s>` element.
+    fn visit_sentence(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_sentence(self, element)
+    }
+
+    /// Visits a `<phoneme>` element.
+    fn visit_phoneme(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_phoneme(self, element)
+    }
+
+    /// Visits a `<say-as>` element.
+    fn visit_say_as(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_say_as(self, element)
+    }
+
+    /// Visits a `<sub>` element.
+    fn visit_sub(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_sub(self, element)
+    }
+
+    /// Visits a `<prosody>` element.
+    fn visit_prosody(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_prosody(self, element)
+    }
+
+    /// Visits a `<emphasis>` element.
+    fn visit_emphasis(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_emphasis(self, element)
+    }
+
+    /// Visits a `<break>` element.
+    fn visit_break(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_break(self, element)
+    }
+
+    /// Visits a `<mark>` element.
+    fn visit_mark(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_mark(self, element)
+    }
+
+    /// Visits a `<audio>` element.
+    fn visit_audio(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_audio(self, element)
+    }
+
+    /// Visits a `<desc>` element.
+    fn visit_desc(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_desc(self, element)
+    }
+
+    /// Visits a `<lexicon>` element.
+    fn visit_lexicon(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_lexicon(self, element)
+    }
+
+    /// Visits a `<lang>` element.
+    fn visit_lang(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        visit_lang(self, element)
+    }
+
+    /// Visits text content.
+    fn visit_text(&mut self, text: &'ast str) -> ControlFlow<()> {
+        visit_text(self, text)
+    }
+}
+
+/// Visits each top-level node of the document.
+pub fn visit_ssml<'ast, V>(visitor: &mut V, ssml: &'ast SSML) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for element in &ssml.elements {
+        visitor.visit_element(element)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Calls the [`Visit`] method matching the type of `element`.
+pub fn visit_element<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    match element {
+        SsmlElement::Speak { .. } => visitor.visit_speak(element),
+        SsmlElement::Voice { .. } => visitor.visit_voice(element),
+        SsmlElement::Paragraph { .. } => visitor.visit_paragraph(element),
+        SsmlElement::Sentence { .. } => visitor.visit_sentence(element),
+        SsmlElement::Phoneme { .. } => visitor.visit_phoneme(element),
+        SsmlElement::SayAs { .. } => visitor.visit_say_as(element),
+        SsmlElement::Sub { .. } => visitor.visit_sub(element),
+        SsmlElement::Prosody { .. } => visitor.visit_prosody(element),
+        SsmlElement::Emphasis { .. } => visitor.visit_emphasis(element),
+        SsmlElement::Break { .. } => visitor.visit_break(element),
+        SsmlElement::Mark { .. } => visitor.visit_mark(element),
+        SsmlElement::Audio { .. } => visitor.visit_audio(element),
+        SsmlElement::Desc { .. } => visitor.visit_desc(element),
+        SsmlElement::LexiconUri { .. } => visitor.visit_lexicon(element),
+        SsmlElement::Lang { .. } => visitor.visit_lang(element),
+        SsmlElement::Text(text) => visitor.visit_text(text),
+    }
+}
+
+/// Visits each child of `element`; does nothing for elements without children.
+pub fn visit_children<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    for child in element.children() {
+        visitor.visit_element(child)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Default [`Visit::visit_speak`]: visits the children.
+pub fn visit_speak<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_voice`]: visits the children.
+pub fn visit_voice<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_paragraph`]: visits the children.
+pub fn visit_paragraph<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_sentence`]: visits the children.
+pub fn visit_sentence<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_phoneme`]: visits the children.
+pub fn visit_phoneme<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_say_as`]: visits the children.
+pub fn visit_say_as<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_sub`]: visits the children.
+pub fn visit_sub<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_prosody`]: visits the children.
+pub fn visit_prosody<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_emphasis`]: visits the children.
+pub fn visit_emphasis<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_break`]: does nothing, as the element has no children.
+pub fn visit_break<'ast, V>(_visitor: &mut V, _element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// Default [`Visit::visit_mark`]: does nothing, as the element has no children.
+pub fn visit_mark<'ast, V>(_visitor: &mut V, _element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// Default [`Visit::visit_audio`]: visits the children.
+pub fn visit_audio<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_desc`]: visits the children.
+pub fn visit_desc<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_lexicon`]: does nothing, as the element has no children.
+pub fn visit_lexicon<'ast, V>(_visitor: &mut V, _element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// Default [`Visit::visit_lang`]: visits the children.
+pub fn visit_lang<'ast, V>(visitor: &mut V, element: &'ast SsmlElement) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    visit_children(visitor, element)
+}
+
+/// Default [`Visit::visit_text`]: does nothing.
+pub fn visit_text<'ast, V>(_visitor: &mut V, _text: &'ast str) -> ControlFlow<()>
+where
+    V: Visit<'ast> + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// In-place traversal of a document.
+///
+/// [`VisitMut::visit_element_mut`] may replace the element entirely; the type-specific
+/// methods receive the element after dispatch, so they should only change it in place.
+pub trait VisitMut {
+    /// Visits a document.
+    fn visit_ssml_mut(&mut self, ssml: &mut SSML) -> ControlFlow<()> {
+        visit_ssml_mut(self, ssml)
+    }
+
+    /// Visits any node, dispatching on its type.
+    fn visit_element_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_element_mut(self, element)
+    }
+
+    /// Visits a `<speak>` element.
+    fn visit_speak_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_speak_mut(self, element)
+    }
+
+    /// Visits a `<voice>` element.
+    fn visit_voice_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_voice_mut(self, element)
+    }
+
+    /// Visits a `<p>` element.
+    fn visit_paragraph_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_paragraph_mut(self, element)
+    }
+
+    /// Visits a `<s>` element.
+    fn visit_sentence_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_sentence_mut(self, element)
+    }
+
+    /// Visits a `<phoneme>` element.
+    fn visit_phoneme_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_phoneme_mut(self, element)
+    }
+
+    /// Visits a `<say-as>` element.
+    fn visit_say_as_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_say_as_mut(self, element)
+    }
+
+    /// Visits a `<sub>` element.
+    fn visit_sub_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_sub_mut(self, element)
+    }
+
+    /// Visits a `<prosody>` element.
+    fn visit_prosody_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_prosody_mut(self, element)
+    }
+
+    /// Visits a `<emphasis>` element.
+    fn visit_emphasis_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_emphasis_mut(self, element)
+    }
+
+    /// Visits a `<break>` element.
+    fn visit_break_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_break_mut(self, element)
+    }
+
+    /// Visits a `<mark>` element.
+    fn visit_mark_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_mark_mut(self, element)
+    }
+
+    /// Visits a `<audio>` element.
+    fn visit_audio_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_audio_mut(self, element)
+    }
+
+    /// Visits a `<desc>` element.
+    fn visit_desc_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_desc_mut(self, element)
+    }
+
+    /// Visits a `<lexicon>` element.
+    fn visit_lexicon_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_lexicon_mut(self, element)
+    }
+
+    /// Visits a `<lang>` element.
+    fn visit_lang_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+        visit_lang_mut(self, element)
+    }
+
+    /// Visits text content.
+    fn visit_text_mut(&mut self, text: &mut String) -> ControlFlow<()> {
+        visit_text_mut(self, text)
+    }
+}
+
+/// Visits each top-level node of the document.
+pub fn visit_ssml_mut<V>(visitor: &mut V, ssml: &mut SSML) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    for element in &mut ssml.elements {
+        visitor.visit_element_mut(element)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Calls the [`VisitMut`] method matching the type of `element`.
+pub fn visit_element_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    match *element {
+        SsmlElement::Speak { .. } => visitor.visit_speak_mut(element),
+        SsmlElement::Voice { .. } => visitor.visit_voice_mut(element),
+        SsmlElement::Paragraph { .. } => visitor.visit_paragraph_mut(element),
+        SsmlElement::Sentence { .. } => visitor.visit_sentence_mut(element),
+        SsmlElement::Phoneme { .. } => visitor.visit_phoneme_mut(element),
+        SsmlElement::SayAs { .. } => visitor.visit_say_as_mut(element),
+        SsmlElement::Sub { .. } => visitor.visit_sub_mut(element),
+        SsmlElement::Prosody { .. } => visitor.visit_prosody_mut(element),
+        SsmlElement::Emphasis { .. } => visitor.visit_emphasis_mut(element),
+        SsmlElement::Break { .. } => visitor.visit_break_mut(element),
+        SsmlElement::Mark { .. } => visitor.visit_mark_mut(element),
+        SsmlElement::Audio { .. } => visitor.visit_audio_mut(element),
+        SsmlElement::Desc { .. } => visitor.visit_desc_mut(element),
+        SsmlElement::LexiconUri { .. } => visitor.visit_lexicon_mut(element),
+        SsmlElement::Lang { .. } => visitor.visit_lang_mut(element),
+        SsmlElement::Text(ref mut text) => visitor.visit_text_mut(text),
+    }
+}
+
+/// Visits each child of `element`; does nothing for elements without children.
+pub fn visit_children_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    for child in element.children_mut().into_iter().flatten() {
+        visitor.visit_element_mut(child)?;
+    }
+    ControlFlow::Continue(())
+}
+
+/// Default [`VisitMut::visit_speak_mut`]: visits the children.
+pub fn visit_speak_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_voice_mut`]: visits the children.
+pub fn visit_voice_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_paragraph_mut`]: visits the children.
+pub fn visit_paragraph_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_sentence_mut`]: visits the children.
+pub fn visit_sentence_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_phoneme_mut`]: visits the children.
+pub fn visit_phoneme_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_say_as_mut`]: visits the children.
+pub fn visit_say_as_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_sub_mut`]: visits the children.
+pub fn visit_sub_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_prosody_mut`]: visits the children.
+pub fn visit_prosody_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_emphasis_mut`]: visits the children.
+pub fn visit_emphasis_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_break_mut`]: does nothing, as the element has no children.
+pub fn visit_break_mut<V>(_visitor: &mut V, _element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// Default [`VisitMut::visit_mark_mut`]: does nothing, as the element has no children.
+pub fn visit_mark_mut<V>(_visitor: &mut V, _element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// Default [`VisitMut::visit_audio_mut`]: visits the children.
+pub fn visit_audio_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_desc_mut`]: visits the children.
+pub fn visit_desc_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_lexicon_mut`]: does nothing, as the element has no children.
+pub fn visit_lexicon_mut<V>(_visitor: &mut V, _element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// Default [`VisitMut::visit_lang_mut`]: visits the children.
+pub fn visit_lang_mut<V>(visitor: &mut V, element: &mut SsmlElement) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    visit_children_mut(visitor, element)
+}
+
+/// Default [`VisitMut::visit_text_mut`]: does nothing.
+pub fn visit_text_mut<V>(_visitor: &mut V, _text: &mut String) -> ControlFlow<()>
+where
+    V: VisitMut + ?Sized,
+{
+    ControlFlow::Continue(())
+}
+
+/// By-value transformation of a document.
+///
+/// Unlike [`VisitMut`], a fold may turn an element into one of a different type from any
+/// method. It cannot stop early; return the element unchanged to skip its subtree.
+pub trait Fold {
+    /// Folds a document.
+    fn fold_ssml(&mut self, ssml: SSML) -> SSML {
+        fold_ssml(self, ssml)
+    }
+
+    /// Folds any node, dispatching on its type.
+    fn fold_element(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_element(self, element)
+    }
+
+    /// Folds a `<speak>` element.
+    fn fold_speak(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_speak(self, element)
+    }
+
+    /// Folds a `<voice>` element.
+    fn fold_voice(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_voice(self, element)
+    }
+
+    /// Folds a `<p>` element.
+    fn fold_paragraph(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_paragraph(self, element)
+    }
+
+    /// Folds a `<s>` element.
+    fn fold_sentence(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_sentence(self, element)
+    }
+
+    /// Folds a `<phoneme>` element.
+    fn fold_phoneme(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_phoneme(self, element)
+    }
+
+    /// Folds a `<say-as>` element.
+    fn fold_say_as(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_say_as(self, element)
+    }
+
+    /// Folds a `<sub>` element.
+    fn fold_sub(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_sub(self, element)
+    }
+
+    /// Folds a `<prosody>` element.
+    fn fold_prosody(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_prosody(self, element)
+    }
+
+    /// Folds a `<emphasis>` element.
+    fn fold_emphasis(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_emphasis(self, element)
+    }
+
+    /// Folds a `<break>` element.
+    fn fold_break(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_break(self, element)
+    }
+
+    /// Folds a `<mark>` element.
+    fn fold_mark(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_mark(self, element)
+    }
+
+    /// Folds a `<audio>` element.
+    fn fold_audio(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_audio(self, element)
+    }
+
+    /// Folds a `<desc>` element.
+    fn fold_desc(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_desc(self, element)
+    }
+
+    /// Folds a `<lexicon>` element.
+    fn fold_lexicon(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_lexicon(self, element)
+    }
+
+    /// Folds a `<lang>` element.
+    fn fold_lang(&mut self, element: SsmlElement) -> SsmlElement {
+        fold_lang(self, element)
+    }
+
+    /// Folds text content.
+    fn fold_text(&mut self, text: String) -> String {
+        fold_text(self, text)
+    }
+}
+
+/// Folds each top-level node of the document.
+pub fn fold_ssml<F>(folder: &mut F, ssml: SSML) -> SSML
+where
+    F: Fold + ?Sized,
+{
+    SSML {
+        elements: ssml
+            .elements
+            .into_iter()
+            .map(|element| folder.fold_element(element))
+            .collect(),
+    }
+}
+
+/// Calls the [`Fold`] method matching the type of `element`.
+pub fn fold_element<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    match element {
+        element @ SsmlElement::Speak { .. } => folder.fold_speak(element),
+        element @ SsmlElement::Voice { .. } => folder.fold_voice(element),
+        element @ SsmlElement::Paragraph { .. } => folder.fold_paragraph(element),
+        element @ SsmlElement::Sentence { .. } => folder.fold_sentence(element),
+        element @ SsmlElement::Phoneme { .. } => folder.fold_phoneme(element),
+        element @ SsmlElement::SayAs { .. } => folder.fold_say_as(element),
+        element @ SsmlElement::Sub { .. } => folder.fold_sub(element),
+        element @ SsmlElement::Prosody { .. } => folder.fold_prosody(element),
+        element @ SsmlElement::Emphasis { .. } => folder.fold_emphasis(element),
+        element @ SsmlElement::Break { .. } => folder.fold_break(element),
+        element @ SsmlElement::Mark { .. } => folder.fold_mark(element),
+        element @ SsmlElement::Audio { .. } => folder.fold_audio(element),
+        element @ SsmlElement::Desc { .. } => folder.fold_desc(element),
+        element @ SsmlElement::LexiconUri { .. } => folder.fold_lexicon(element),
+        element @ SsmlElement::Lang { .. } => folder.fold_lang(element),
+        SsmlElement::Text(text) => SsmlElement::Text(folder.fold_text(text)),
+    }
+}
+
+/// Folds each child of `element`; returns elements without children unchanged.
+pub fn fold_children<F>(folder: &mut F, mut element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    if let Some(children) = element.children_mut() {
+        *children = mem::take(children)
+            .into_iter()
+            .map(|child| folder.fold_element(child))
+            .collect();
+    }
+    element
+}
+
+/// Default [`Fold::fold_speak`]: folds the children.
+pub fn fold_speak<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_voice`]: folds the children.
+pub fn fold_voice<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_paragraph`]: folds the children.
+pub fn fold_paragraph<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_sentence`]: folds the children.
+pub fn fold_sentence<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_phoneme`]: folds the children.
+pub fn fold_phoneme<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_say_as`]: folds the children.
+pub fn fold_say_as<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_sub`]: folds the children.
+pub fn fold_sub<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_prosody`]: folds the children.
+pub fn fold_prosody<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_emphasis`]: folds the children.
+pub fn fold_emphasis<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_break`]: returns the element unchanged.
+pub fn fold_break<F>(_folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    element
+}
+
+/// Default [`Fold::fold_mark`]: returns the element unchanged.
+pub fn fold_mark<F>(_folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    element
+}
+
+/// Default [`Fold::fold_audio`]: folds the children.
+pub fn fold_audio<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_desc`]: folds the children.
+pub fn fold_desc<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_lexicon`]: returns the element unchanged.
+pub fn fold_lexicon<F>(_folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    element
+}
+
+/// Default [`Fold::fold_lang`]: folds the children.
+pub fn fold_lang<F>(folder: &mut F, element: SsmlElement) -> SsmlElement
+where
+    F: Fold + ?Sized,
+{
+    fold_children(folder, element)
+}
+
+/// Default [`Fold::fold_text`]: returns the text unchanged.
+pub fn fold_text<F>(_folder: &mut F, text: String) -> String
+where
+    F: Fold + ?Sized,
+{
+    text
+}
@@ -0,0 +1,86 @@
+use std::borrow::Cow;
+
+use serde_ssml::borrowed::{self, SsmlElement};
+
+const COMPREHENSIVE: &str = r#"<?xml version="1.0"?>
+<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en-US">
+    <p>
+        <s>This is the first sentence in a paragraph.</s>
+        <s>This is the second sentence with <break time="300ms"/> a pause.</s>
+    </p>
+    <voice name="en-GB-Standard-A" gender="female">
+        <p>This text will be spoken with a British female voice.</p>
+    </voice>
+    <phoneme alphabet="ipa" ph="təˈmeɪtoʊ">tomato</phoneme>
+    <say-as interpret-as="date" format="mdy">12/31/2020</say-as>
+    <sub alias="World Wide Web Consortium">W3C</sub>
+    <prosody rate="slow" pitch="low">This text is spoken slowly.</prosody>
+    <emphasis level="strong">strongly emphasized</emphasis>
+    <break strength="strong"></break>
+    <audio src="sound.mp3?a=1&amp;b=2">
+        <desc>A sound effect</desc>
+    </audio>
+    <mark name="mark1"/>
+    <lang xml:lang="fr-FR">français</lang>
+    <lexicon uri="https://example.com/lexicon.pls"/>
+    Tom &amp; Jerry &lt;3
+</speak>"#;
+
+#[test]
+fn test_into_owned_matches_from_str() {
+    let borrowed = borrowed::from_str(COMPREHENSIVE).unwrap();
+    let owned = serde_ssml::from_str(COMPREHENSIVE).unwrap();
+
+    similar_asserts::assert_eq!(borrowed.into_owned(), owned);
+}
+
+#[test]
+fn test_borrows_from_input() {
+    let input = r#"<speak xml:lang="en-US"><voice name="Joanna">Hello world</voice></speak>"#;
+    let ssml = borrowed::from_str(input).unwrap();
+
+    let SsmlElement::Speak { lang, children, .. } = &ssml.elements[0] else {
+        panic!("Expected Speak element");
+    };
+    assert!(matches!(lang, Some(Cow::Borrowed("en-US"))));
+
+    let SsmlElement::Voice { name, children } = &children[0] else {
+        panic!("Expected Voice element");
+    };
+    assert!(matches!(name, Cow::Borrowed("Joanna")));
+    assert!(matches!(
+        &children[0],
+        SsmlElement::Text(Cow::Borrowed("Hello world"))
+    ));
+}
+
+#[test]
+fn test_decodes_entities() {
+    let input = r#"<speak><sub alias="&quot;AT&amp;T&quot;">ATT</sub>1 &lt; 2</speak>"#;
+    let ssml = borrowed::from_str(input).unwrap();
+
+    let SsmlElement::Speak { children, .. } = &ssml.elements[0] else {
+        panic!("Expected Speak element");
+    };
+    assert!(
+        matches!(&children[0], SsmlElement::Sub { alias: Cow::Owned(alias), .. } if alias == "\"AT&T\"")
+    );
+    assert!(matches!(&children[1], SsmlElement::Text(Cow::Owned(text)) if text == "1 < 2"));
+}
+
+#[test]
+fn test_round_trip_escapes_entities() {
+    let input = r#"<speak><audio src="a.mp3?x=1&amp;y=2">Tom &amp; Jerry &lt;3</audio></speak>"#;
+    let ssml = serde_ssml::from_str(input).unwrap();
+
+    assert_eq!(serde_ssml::to_string(&ssml), input);
+}
+
+#[test]
+fn test_rejects_invalid_input() {
+    assert!(borrowed::from_str("<speak>Unclosed tag").is_err());
+    assert!(borrowed::from_str("<speak><p>Mismatched</s></speak>").is_err());
+    assert!(borrowed::from_str("<speak><unknown/></speak>").is_err());
+    assert!(borrowed::from_str(r#"<speak version="1.1>Hi</speak>"#).is_err());
+    assert!(borrowed::from_str("<speak><mark name=\"x\">text</mark></speak>").is_err());
+}
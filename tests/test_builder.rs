@@ -0,0 +1,99 @@
+use serde_ssml::builder::{Block, EmphasisLevel, Inline, Paragraph, Prosody, Speak};
+use serde_ssml::{BreakStrength, SSML, SsmlElement, from_str, to_string};
+
+#[test]
+fn test_builder_document() {
+    let ssml = Speak::new()
+        .version("1.1")
+        .lang("en-US")
+        .lexicon("https://example.com/lexicon.pls")
+        .voice("Joanna", |v| {
+            v.p(|p| {
+                p.s("Hello")
+                    .break_ms(300)
+                    .emphasis(EmphasisLevel::Strong, "world")
+            })
+        })
+        .build();
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak version="1.1" xml:lang="en-US"><lexicon uri="https://example.com/lexicon.pls"/><voice name="Joanna"><p><s>Hello</s><break time="300ms"/><emphasis level="strong">world</emphasis></p></voice></speak>"#
+    );
+    assert_eq!(from_str(to_string(&ssml)).unwrap(), ssml);
+}
+
+#[test]
+fn test_builder_elements() {
+    let ssml: SSML = Speak::new()
+        .prosody(Prosody::new().rate("slow").volume("soft"), |b| {
+            b.s_with(|s| {
+                s.text("Call")
+                    .say_as("telephone", "555-0100")
+                    .text("on")
+                    .say_as_format("date", "mdy", "10/18/2026")
+            })
+        })
+        .in_lang("fr-FR", |b| b.p(|p| p.s("Bonjour")))
+        .audio("chime.wav", |a| a.desc("Chime").text("Ding"))
+        .s_with(|s| {
+            s.phoneme("ipa", "təˈmeɪtoʊ", "tomato")
+                .sub("World Wide Web", "WWW")
+                .break_strength(BreakStrength::Strong)
+                .mark("end")
+        })
+        .into();
+
+    assert_eq!(
+        to_string(&ssml),
+        concat!(
+            r#"<speak><prosody rate="slow" volume="soft"><s>Call<say-as interpret-as="telephone">555-0100</say-as>on"#,
+            r#"<say-as interpret-as="date" format="mdy">10/18/2026</say-as></s></prosody>"#,
+            r#"<lang xml:lang="fr-FR"><p><s>Bonjour</s></p></lang>"#,
+            r#"<audio src="chime.wav"><desc>Chime</desc>Ding</audio>"#,
+            r#"<s><phoneme alphabet="ipa" ph="təˈmeɪtoʊ">tomato</phoneme><sub alias="World Wide Web">WWW</sub>"#,
+            r#"<break strength="strong"/><mark name="end"/></s></speak>"#
+        )
+    );
+    assert_eq!(from_str(to_string(&ssml)).unwrap(), ssml);
+}
+
+#[test]
+fn test_builder_fragments() {
+    let inline = Inline::new()
+        .text("Very")
+        .emphasis_with(EmphasisLevel::Reduced, |e| e.text("quiet").break_ms(100))
+        .build();
+    assert_eq!(
+        inline,
+        [
+            SsmlElement::Text("Very".to_string()),
+            SsmlElement::Emphasis {
+                level: "reduced".to_string(),
+                children: vec![
+                    SsmlElement::Text("quiet".to_string()),
+                    SsmlElement::Break {
+                        time: Some(std::time::Duration::from_millis(100)),
+                        strength: None,
+                    },
+                ],
+            },
+        ]
+    );
+
+    let paragraph = Paragraph::new().s("One").s("").build();
+    assert_eq!(
+        paragraph,
+        [
+            SsmlElement::Sentence {
+                children: vec![SsmlElement::Text("One".to_string())],
+            },
+            SsmlElement::Sentence {
+                children: Vec::new(),
+            },
+        ]
+    );
+
+    assert_eq!(Block::new().p(|p| p.text("Text")).build().len(), 1);
+    assert_eq!(EmphasisLevel::default().as_str(), "moderate");
+}
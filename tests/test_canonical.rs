@@ -0,0 +1,126 @@
+use serde_ssml::{
+    BreakStrength, Parser, SSML, SsmlElement, Whitespace, canonicalize, from_str,
+    to_string_canonical,
+};
+
+fn canonical(input: &str) -> String {
+    to_string_canonical(&from_str(input).unwrap())
+}
+
+fn text(text: &str) -> SsmlElement {
+    SsmlElement::Text(text.to_string())
+}
+
+#[test]
+fn test_equivalent_documents_are_byte_identical() {
+    let variants = [
+        r#"<speak version="1.1" xml:lang="en-US"><p><s>Hello <emphasis>world</emphasis></s><break time="1s"/></p></speak>"#,
+        r#"<speak xml:lang="en-US" version="1.1">
+            <p>
+                <s>Hello <emphasis level="moderate">world</emphasis></s>
+                <break time="1000ms"/>
+            </p>
+        </speak>"#,
+        r#"<?xml version="1.0"?><speak xml:lang='en-US' version='1.1'><p><s>Hello <emphasis level="moderate">world</emphasis></s><break time="1s"/></p></speak>"#,
+    ];
+
+    let expected = canonical(variants[0]);
+    assert_eq!(
+        expected,
+        r#"<speak version="1.1" xml:lang="en-US"><p><s>Hello<emphasis>world</emphasis></s><break time="1000ms"/></p></speak>"#
+    );
+    for variant in variants {
+        assert_eq!(canonical(variant), expected, "{variant}");
+        assert_eq!(
+            from_str(variant).unwrap().content_hash(),
+            from_str(variants[0]).unwrap().content_hash()
+        );
+    }
+}
+
+#[test]
+fn test_different_documents_differ() {
+    let documents = [
+        "<speak>Hello world</speak>",
+        "<speak>Hello  World</speak>",
+        r#"<speak><emphasis level="strong">Hello world</emphasis></speak>"#,
+        r#"<speak>Hello world<break time="500ms"/></speak>"#,
+        r#"<speak>Hello world<break strength="strong"/></speak>"#,
+    ];
+
+    for (index, a) in documents.iter().enumerate() {
+        for b in &documents[index + 1..] {
+            assert_ne!(canonical(a), canonical(b), "{a} vs {b}");
+        }
+    }
+}
+
+#[test]
+fn test_whitespace_and_adjacent_text() {
+    let ssml = Parser::new()
+        .whitespace(Whitespace::Preserve)
+        .parse("<speak>\n  <s>  Hello\t\tbig <emphasis> world </emphasis> </s>\n</speak>")
+        .unwrap();
+
+    assert_eq!(
+        canonicalize(&ssml).elements,
+        vec![SsmlElement::Speak {
+            version: None,
+            xmlns: None,
+            lang: None,
+            children: vec![SsmlElement::Sentence {
+                children: vec![
+                    text("Hello big "),
+                    SsmlElement::Emphasis {
+                        level: String::new(),
+                        children: vec![text(" world ")],
+                    },
+                ],
+            }],
+        }]
+    );
+
+    let split = SSML {
+        elements: vec![text("Hello"), text(" "), text(""), text("world")],
+    };
+    assert_eq!(canonicalize(&split).elements, vec![text("Hello world")]);
+}
+
+#[test]
+fn test_default_break_strength() {
+    let ssml = SSML {
+        elements: vec![
+            SsmlElement::Break {
+                time: None,
+                strength: Some(BreakStrength::Medium),
+            },
+            SsmlElement::Break {
+                time: Some(std::time::Duration::from_secs(1)),
+                strength: Some(BreakStrength::Medium),
+            },
+        ],
+    };
+
+    assert_eq!(
+        to_string_canonical(&ssml),
+        r#"<break/><break strength="medium" time="1000ms"/>"#
+    );
+}
+
+#[test]
+fn test_canonicalize_is_idempotent() {
+    let ssml = Parser::new()
+        .whitespace(Whitespace::Preserve)
+        .parse(r#"<speak> <p> <s>One <emphasis level="moderate"> two</emphasis></s> </p> </speak>"#)
+        .unwrap();
+    let once = canonicalize(&ssml);
+
+    assert_eq!(canonicalize(&once), once);
+}
+
+#[test]
+fn test_content_hash_is_stable() {
+    // The hash is part of persisted cache keys and must not change between releases
+    let ssml = from_str("<speak>Hello</speak>").unwrap();
+    assert_eq!(ssml.content_hash(), 0xbfb5_481e_cf74_640c);
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use serde_ssml::{SSML, compact::Compact, from_str};
+
+const DOCUMENT: &str = r#"<speak version="1.1" xml:lang="en-US"><voice name="en-US-Jenny"><p><s>Hello <break strength="weak"/> <say-as interpret-as="characters">SSML</say-as></s></p></voice><mark name="end"/></speak>"#;
+
+#[test]
+fn test_schema_is_up_to_date() {
+    assert_eq!(
+        serde_ssml::compact::json_schema(),
+        include_str!("../schema/ssml-compact.schema.json"),
+        "run `make schema` to regenerate the published schema"
+    );
+}
+
+#[test]
+fn test_schema_lists_elements_and_attributes() {
+    let schema: serde_json::Value =
+        serde_json::from_str(&serde_ssml::compact::json_schema()).unwrap();
+    let definitions = &schema["$defs"];
+
+    assert_eq!(definitions["node"]["oneOf"][0], json!({"type": "string"}));
+    assert_eq!(
+        definitions["break"]["properties"]["break"]["properties"]["strength"]["enum"],
+        json!(["none", "x-weak", "weak", "medium", "strong", "x-strong"])
+    );
+    assert!(definitions["mark"]["properties"]["mark"]["properties"]["children"].is_null());
+    assert_eq!(
+        definitions["say-as"]["properties"]["say-as"]["additionalProperties"],
+        json!(false)
+    );
+}
+
+#[test]
+fn test_compact_shape() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(
+        serde_json::to_value(Compact(&ssml)).unwrap(),
+        json!([{
+            "speak": {
+                "version": "1.1",
+                "xml:lang": "en-US",
+                "children": [
+                    {"voice": {"name": "en-US-Jenny", "children": [
+                        {"p": {"children": [
+                            {"s": {"children": [
+                                "Hello",
+                                {"break": {"strength": "weak"}},
+                                {"say-as": {"interpret-as": "characters", "children": ["SSML"]}}
+                            ]}}
+                        ]}}
+                    ]}},
+                    {"mark": {"name": "end"}}
+                ]
+            }
+        }])
+    );
+}
+
+#[test]
+fn test_compact_round_trip() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let json = serde_json::to_string(&Compact(ssml.clone())).unwrap();
+    let Compact(parsed) = serde_json::from_str::<Compact<SSML>>(&json).unwrap();
+
+    assert_eq!(parsed, ssml);
+}
+
+#[test]
+fn test_compact_with_attribute() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Message {
+        id: u64,
+        #[serde(with = "serde_ssml::compact")]
+        ssml: SSML,
+    }
+
+    let message = Message {
+        id: 7,
+        ssml: from_str("<speak>Hi</speak>").unwrap(),
+    };
+    let json = serde_json::to_string(&message).unwrap();
+
+    assert_eq!(json, r#"{"id":7,"ssml":[{"speak":{"children":["Hi"]}}]}"#);
+    assert_eq!(serde_json::from_str::<Message>(&json).unwrap(), message);
+}
+
+#[test]
+fn test_default_representation_round_trip() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let json = serde_json::to_string(&ssml).unwrap();
+
+    assert!(json.starts_with(r#"{"elements":[{"type":"Speak""#));
+    assert_eq!(serde_json::from_str::<SSML>(&json).unwrap(), ssml);
+}
+
+#[test]
+fn test_compact_errors() {
+    for (input, message) in [
+        (r#"[{"amazon:effect": {}}]"#, "amazon:effect"),
+        (r#"[{"voice": {"gender": "female"}}]"#, "gender"),
+        (r#"[{"break": {"time": "soon"}}]"#, "soon"),
+        (r#"[{"s": {}, "p": {}}]"#, "invalid length"),
+        (r#"[42]"#, "a string or an object"),
+    ] {
+        let error = serde_json::from_str::<Compact<SSML>>(input).unwrap_err();
+        assert!(
+            error.to_string().contains(message),
+            "{input}: unexpected error {error}"
+        );
+    }
+}
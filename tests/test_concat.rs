@@ -0,0 +1,106 @@
+use serde_ssml::{InvalidPath, NodePath, SSML, from_str, to_string};
+
+#[test]
+fn test_concat_roots() {
+    let first = from_str(r#"<speak version="1.0" xml:lang="en-US"><s>One</s></speak>"#).unwrap();
+    let second = from_str(concat!(
+        r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en-US">"#,
+        r#"<lexicon uri="http://example.com/lexicon.pls"/><s>Two</s></speak>"#,
+    ))
+    .unwrap();
+    let third = from_str(r#"<speak xml:lang="es-ES"><s>Tres</s></speak>"#).unwrap();
+
+    assert_eq!(
+        to_string(&SSML::concat([first, second, third])),
+        concat!(
+            r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en-US">"#,
+            r#"<lexicon uri="http://example.com/lexicon.pls"/><s>One</s><s>Two</s>"#,
+            r#"<lang xml:lang="es-ES"><s>Tres</s></lang></speak>"#,
+        )
+    );
+
+    assert_eq!(SSML::concat(Vec::new()), SSML::default());
+}
+
+#[test]
+fn test_append() {
+    let mut ssml = from_str("<speak><s>One</s></speak>").unwrap();
+    ssml.append(from_str(r#"<speak xml:lang="de-DE"><s>Zwei</s></speak>"#).unwrap());
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><s>One</s><lang xml:lang="de-DE"><s>Zwei</s></lang></speak>"#
+    );
+
+    // Two roots in one document are merged as well
+    let mut ssml = from_str("<speak><s>One</s></speak><speak><s>Two</s></speak>").unwrap();
+    ssml.append(SSML::default());
+    assert_eq!(to_string(&ssml), "<speak><s>One</s><s>Two</s></speak>");
+
+    let mut empty = SSML::default();
+    empty.append(from_str("<speak><s>One</s></speak>").unwrap());
+    assert_eq!(to_string(&empty), "<speak><s>One</s></speak>");
+}
+
+#[test]
+fn test_splice_into() {
+    let mut ssml = from_str(concat!(
+        r#"<speak version="1.0" xml:lang="en-US"><lexicon uri="a.pls"/>"#,
+        r#"<lang xml:lang="fr-FR"><s>Un</s><s>Trois</s></lang></speak>"#,
+    ))
+    .unwrap();
+    let fragment = from_str(
+        r#"<speak version="1.1" xml:lang="fr-FR"><lexicon uri="b.pls"/><s>Deux</s></speak>"#,
+    )
+    .unwrap();
+    ssml.splice_into(&NodePath::from([0, 1, 1]), fragment)
+        .unwrap();
+    assert_eq!(
+        to_string(&ssml),
+        concat!(
+            r#"<speak version="1.1" xml:lang="en-US"><lexicon uri="a.pls"/><lexicon uri="b.pls"/>"#,
+            r#"<lang xml:lang="fr-FR"><s>Un</s><s>Deux</s><s>Trois</s></lang></speak>"#,
+        )
+    );
+
+    let fragment = from_str(r#"<speak xml:lang="it-IT"><s>Quattro</s></speak>"#).unwrap();
+    ssml.splice_into(&NodePath::from([0, 3]), fragment).unwrap();
+    assert_eq!(
+        to_string(&ssml),
+        concat!(
+            r#"<speak version="1.1" xml:lang="en-US"><lexicon uri="a.pls"/><lexicon uri="b.pls"/>"#,
+            r#"<lang xml:lang="fr-FR"><s>Un</s><s>Deux</s><s>Trois</s></lang>"#,
+            r#"<lang xml:lang="it-IT"><s>Quattro</s></lang></speak>"#,
+        )
+    );
+}
+
+#[test]
+fn test_splice_into_invalid_path() {
+    let mut ssml = from_str("<speak><s>One</s></speak>").unwrap();
+    let before = ssml.clone();
+
+    for path in [
+        NodePath::from([0, 2]),
+        NodePath::from([1, 0]),
+        NodePath::root(),
+    ] {
+        let result = ssml.splice_into(&path, from_str("<speak><s>Two</s></speak>").unwrap());
+        assert_eq!(result, Err(InvalidPath));
+        assert_eq!(ssml, before);
+    }
+}
+
+#[test]
+fn test_concat_undeclared_lang() {
+    let english = from_str("<speak><s>Hello</s></speak>").unwrap();
+    let french = from_str(r#"<speak xml:lang="fr-FR"><s>Bonjour</s></speak>"#).unwrap();
+
+    assert_eq!(
+        to_string(&SSML::concat([english.clone(), french.clone()])),
+        r#"<speak><s>Hello</s><lang xml:lang="fr-FR"><s>Bonjour</s></lang></speak>"#
+    );
+    assert_eq!(
+        to_string(&SSML::concat([french, english])),
+        r#"<speak><lang xml:lang="fr-FR"><s>Bonjour</s></lang><s>Hello</s></speak>"#
+    );
+}
@@ -0,0 +1,141 @@
+use serde_ssml::{Edit, NodePath, SSML, SsmlElement, diff, from_str};
+
+#[test]
+fn test_diff_equal() {
+    let ssml = from_str(r#"<speak><s>Hello</s><break time="1s"/></speak>"#).unwrap();
+    let diff = diff(&ssml, &ssml.clone());
+
+    assert!(diff.is_empty());
+    assert_eq!(diff.to_string(), "no changes");
+}
+
+#[test]
+fn test_diff_edits() {
+    let old = from_str(concat!(
+        r#"<speak xml:lang="en-US"><voice name="Joanna"><s>Hello</s><s>Goodbye</s></voice>"#,
+        r#"<mark name="end"/></speak>"#,
+    ))
+    .unwrap();
+    let new = from_str(concat!(
+        r#"<speak xml:lang="en-GB"><voice name="Joanna"><s>Hello</s><break time="500ms"/>"#,
+        r#"<s>Goodbye <emphasis>now</emphasis></s></voice></speak>"#,
+    ))
+    .unwrap();
+
+    let diff = diff(&old, &new);
+    assert_eq!(
+        diff.edits(),
+        [
+            Edit::Attribute {
+                path: NodePath::from([0]),
+                name: "xml:lang",
+                old: Some("en-US".to_string()),
+                new: Some("en-GB".to_string()),
+            },
+            Edit::Insert {
+                path: NodePath::from([0, 0, 1]),
+                node: SsmlElement::Break {
+                    time: Some(std::time::Duration::from_millis(500)),
+                    strength: None,
+                },
+            },
+            Edit::Insert {
+                path: NodePath::from([0, 0, 2, 1]),
+                node: SsmlElement::Emphasis {
+                    level: String::new(),
+                    children: vec![SsmlElement::Text("now".to_string())],
+                },
+            },
+            Edit::Remove {
+                path: NodePath::from([0, 1]),
+                node: SsmlElement::Mark {
+                    name: "end".to_string(),
+                },
+            },
+        ]
+    );
+    assert_eq!(
+        diff.to_string(),
+        concat!(
+            "~ /0 @xml:lang: \"en-US\" -> \"en-GB\"\n",
+            "+ /0/0/1 <break time=\"500ms\"/>\n",
+            "+ /0/0/2/1 <emphasis>now</emphasis>\n",
+            "- /0/1 <mark name=\"end\"/>",
+        )
+    );
+}
+
+#[test]
+fn test_diff_attributes_and_moves() {
+    let old = from_str(concat!(
+        r#"<speak><prosody rate="slow" pitch="low">A</prosody><audio src="a.wav"/>"#,
+        r#"<p><s>Moved</s></p><p>Stay</p></speak>"#,
+    ))
+    .unwrap();
+    let new = from_str(concat!(
+        r#"<speak><prosody rate="slow" volume="loud">A</prosody><audio src="a.wav"/>"#,
+        r#"<p>Stay</p><p><s>Moved</s></p></speak>"#,
+    ))
+    .unwrap();
+
+    assert_eq!(
+        diff(&old, &new).to_string(),
+        concat!(
+            "~ /0/0 @pitch: \"low\" -> (none)\n",
+            "~ /0/0 @volume: (none) -> \"loud\"\n",
+            "> /0/2 -> /0/3 <p><s>Moved</s></p>",
+        )
+    );
+
+    // Swapping the documents swaps the attribute values
+    let reverse = diff(&new, &old);
+    assert_eq!(
+        reverse.edits()[0],
+        Edit::Attribute {
+            path: NodePath::from([0, 0]),
+            name: "pitch",
+            old: None,
+            new: Some("low".to_string()),
+        }
+    );
+    assert_eq!(
+        reverse
+            .edits()
+            .iter()
+            .filter(|edit| matches!(edit, Edit::Move { .. }))
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_diff_long_sibling_list() {
+    let sentences = |edited: &str| {
+        let mut children: Vec<_> = (0..10_000)
+            .map(|i| SsmlElement::Sentence {
+                children: vec![SsmlElement::Text(format!("Sentence {i}"))],
+            })
+            .collect();
+        children[5_000] = SsmlElement::Sentence {
+            children: vec![SsmlElement::Text(edited.to_string())],
+        };
+        SSML {
+            elements: vec![SsmlElement::Speak {
+                version: None,
+                xmlns: None,
+                lang: None,
+                children,
+            }],
+        }
+    };
+
+    let diff = diff(&sentences("Before"), &sentences("After"));
+    assert_eq!(
+        diff.edits(),
+        [Edit::Text {
+            path: NodePath::from([0, 5_000, 0]),
+            old: "Before".to_string(),
+            new: "After".to_string(),
+        }]
+    );
+}
@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use serde_ssml::{ErrorKind, SSML, SsmlElement, from_str, to_string, to_string_pretty};
+
+const DOCUMENT: &str = r#"<speak version="1.1"><voice name="en-US-Jenny"><p><s>Hello <say-as interpret-as="characters">SSML</say-as></s></p></voice></speak>"#;
+
+#[test]
+fn test_display_matches_to_string() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(ssml.to_string(), to_string(&ssml));
+    assert_eq!(format!("{ssml:#}"), to_string_pretty(&ssml));
+}
+
+#[test]
+fn test_display_subtree() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let SsmlElement::Speak { children, .. } = &ssml.elements[0] else {
+        panic!("Expected Speak element");
+    };
+
+    assert_eq!(
+        children[0].to_string(),
+        r#"<voice name="en-US-Jenny"><p><s>Hello<say-as interpret-as="characters">SSML</say-as></s></p></voice>"#
+    );
+    assert_eq!(
+        format!("{:#}", children[0]),
+        r#"<voice name="en-US-Jenny">
+  <p>
+    <s>Hello<say-as interpret-as="characters">SSML</say-as></s>
+  </p>
+</voice>"#
+    );
+    assert_eq!(
+        format!("Subtree: {}", SsmlElement::Text("a < b".to_string())),
+        "Subtree: a &lt; b"
+    );
+}
+
+#[test]
+fn test_from_str_and_try_from() {
+    let ssml: SSML = DOCUMENT.parse().unwrap();
+    assert_eq!(ssml, from_str(DOCUMENT).unwrap());
+    assert_eq!(SSML::try_from(DOCUMENT).unwrap(), ssml);
+    assert!("<speak>".parse::<SSML>().is_err());
+
+    let element = SsmlElement::try_from(r#"<break time="250ms"/>"#).unwrap();
+    assert_eq!(
+        element,
+        SsmlElement::Break {
+            time: Some(Duration::from_millis(250)),
+            strength: None,
+        }
+    );
+    assert_eq!(
+        "  plain text ".parse::<SsmlElement>().unwrap(),
+        SsmlElement::Text("plain text".to_string())
+    );
+}
+
+#[test]
+fn test_element_requires_exactly_one_node() {
+    for (input, found) in [("", 0), ("Hello <break/>", 2)] {
+        let errors = input.parse::<SsmlElement>().unwrap_err();
+        assert_eq!(
+            errors[0].kind(),
+            &ErrorKind::ExpectedSingleElement { found }
+        );
+        assert_eq!(errors[0].span(), 0..input.len());
+    }
+}
+
+#[test]
+fn test_display_round_trip() {
+    let element: SsmlElement =
+        r#"<prosody rate="slow"><emphasis>Tom &amp; Jerry</emphasis></prosody>"#
+            .parse()
+            .unwrap();
+
+    assert_eq!(element.to_string().parse::<SsmlElement>().unwrap(), element);
+}
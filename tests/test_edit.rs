@@ -0,0 +1,92 @@
+use serde_ssml::{ElementKind, Parser, SsmlElement, Whitespace, from_str, to_string};
+
+const DOCUMENT: &str = r#"<speak><voice name="a"><prosody rate="slow"><s>One <break time="1s"/> two</s></prosody></voice><audio src="a.wav"><desc>Chime</desc></audio><mark name="end"/></speak>"#;
+
+#[test]
+fn test_retain() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let mut visited = Vec::new();
+    ssml.retain(|element| {
+        visited.push(element.kind());
+        !matches!(element.kind(), ElementKind::Audio | ElementKind::Break)
+    });
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><voice name="a"><prosody rate="slow"><s>Onetwo</s></prosody></voice><mark name="end"/></speak>"#
+    );
+    assert!(!visited.contains(&ElementKind::Desc));
+}
+
+#[test]
+fn test_flat_map_is_bottom_up() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let mut order = Vec::new();
+    ssml.flat_map(|element| {
+        order.push(element.kind());
+        match element {
+            SsmlElement::Break { .. } => vec![
+                SsmlElement::Text(",".to_string()),
+                SsmlElement::Mark {
+                    name: "pause".to_string(),
+                },
+            ],
+            SsmlElement::Sentence { children } => {
+                assert_eq!(children.len(), 4);
+                vec![SsmlElement::Sentence { children }]
+            }
+            SsmlElement::Audio { .. } => vec![],
+            element => vec![element],
+        }
+    });
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><voice name="a"><prosody rate="slow"><s>One,<mark name="pause"/>two</s></prosody></voice><mark name="end"/></speak>"#
+    );
+    assert_eq!(order.first(), Some(&ElementKind::Text));
+    assert_eq!(order.last(), Some(&ElementKind::Speak));
+}
+
+#[test]
+fn test_unwrap_where() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    ssml.unwrap_where(|element| {
+        matches!(
+            element.kind(),
+            ElementKind::Voice | ElementKind::Prosody | ElementKind::Break | ElementKind::Desc
+        )
+    });
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><s>Onetwo</s><audio src="a.wav">Chime</audio><mark name="end"/></speak>"#
+    );
+}
+
+#[test]
+fn test_unwrap_where_keeps_text() {
+    let mut ssml = from_str("<speak><s>Hello</s></speak>").unwrap();
+    ssml.unwrap_where(|_| true);
+
+    assert_eq!(ssml.elements, vec![SsmlElement::Text("Hello".to_string())]);
+}
+
+#[test]
+fn test_unwrap_where_joins_preserved_text() {
+    let mut ssml = Parser::new()
+        .whitespace(Whitespace::Preserve)
+        .parse("<speak>un<emphasis>believ</emphasis>able</speak>")
+        .unwrap();
+    ssml.unwrap_where(|element| element.kind() == ElementKind::Emphasis);
+
+    assert_eq!(to_string(&ssml), "<speak>unbelievable</speak>");
+}
+
+#[test]
+fn test_unwrap_where_adds_no_whitespace() {
+    let mut ssml = from_str("<speak>Hello <emphasis>world</emphasis>, friend</speak>").unwrap();
+    ssml.unwrap_where(|element| element.kind() == ElementKind::Emphasis);
+
+    assert_eq!(ssml.texts().collect::<Vec<_>>(), ["Helloworld, friend"]);
+}
@@ -0,0 +1,156 @@
+use std::{borrow::Cow, time::Duration};
+
+use serde_ssml::{
+    ElementKind, ErrorKind, Parser, SsmlElement, borrowed, from_fragment, to_fragment_string,
+};
+
+fn text(text: &str) -> SsmlElement {
+    SsmlElement::Text(text.to_string())
+}
+
+fn invalid_child(input: &str, context: ElementKind) -> (ElementKind, ElementKind) {
+    match from_fragment(input, context).unwrap_err()[0].kind() {
+        ErrorKind::InvalidChild { parent, child } => (*parent, *child),
+        kind => panic!("Expected an invalid child error, got {kind:?}"),
+    }
+}
+
+#[test]
+fn test_inline_fragment() {
+    let fragment = from_fragment(
+        r#"Hello <emphasis level="strong">big</emphasis> world<break time="500ms"/>"#,
+        ElementKind::Sentence,
+    )
+    .unwrap();
+
+    assert_eq!(
+        fragment,
+        vec![
+            text("Hello"),
+            SsmlElement::Emphasis {
+                level: "strong".to_string(),
+                children: vec![text("big")],
+            },
+            text("world"),
+            SsmlElement::Break {
+                time: Some(Duration::from_millis(500)),
+                strength: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_fragment_round_trip() {
+    let input = r#"<s>Tom &amp; Jerry</s><s><say-as interpret-as="cardinal">42</say-as></s>"#;
+    let fragment = from_fragment(input, ElementKind::Paragraph).unwrap();
+
+    assert_eq!(to_fragment_string(&fragment), input);
+    assert_eq!(
+        from_fragment(to_fragment_string(&fragment), ElementKind::Paragraph).unwrap(),
+        fragment
+    );
+}
+
+#[test]
+fn test_top_level_nodes_are_checked_against_context() {
+    assert_eq!(
+        invalid_child("<p>Hi</p>", ElementKind::Sentence),
+        (ElementKind::Sentence, ElementKind::Paragraph)
+    );
+    assert_eq!(
+        invalid_child("Hi", ElementKind::Break),
+        (ElementKind::Break, ElementKind::Text)
+    );
+    assert_eq!(
+        invalid_child(r#"<lexicon uri="a.pls"/>"#, ElementKind::Voice),
+        (ElementKind::Voice, ElementKind::LexiconUri)
+    );
+    assert!(from_fragment(r#"<lexicon uri="a.pls"/>"#, ElementKind::Speak).is_ok());
+}
+
+#[test]
+fn test_nested_nodes_are_checked_against_parent() {
+    assert_eq!(
+        invalid_child("<p><p>Hi</p></p>", ElementKind::Speak),
+        (ElementKind::Paragraph, ElementKind::Paragraph)
+    );
+    assert_eq!(
+        invalid_child(
+            r#"<say-as interpret-as="date"><break/></say-as>"#,
+            ElementKind::Sentence
+        ),
+        (ElementKind::SayAs, ElementKind::Break)
+    );
+    assert_eq!(
+        invalid_child("<s>Hi <speak>again</speak></s>", ElementKind::Paragraph),
+        (ElementKind::Sentence, ElementKind::Speak)
+    );
+
+    let errors = from_fragment("Hi <p>there</p>", ElementKind::Sentence).unwrap_err();
+    assert_eq!(errors[0].span().start, 3);
+    assert_eq!(
+        errors[0].to_string(),
+        "<p> is not allowed inside <s> at byte 3"
+    );
+}
+
+#[test]
+fn test_documents_are_not_validated() {
+    // Full documents keep accepting any nesting, as before fragment support
+    assert!(serde_ssml::from_str("<speak><s><p>Hi</p></s></speak>").is_ok());
+}
+
+#[test]
+fn test_fragment_ignores_require_root() {
+    let parser = Parser::new().require_root(true);
+
+    assert_eq!(
+        parser.parse_fragment("Hello", ElementKind::Speak).unwrap(),
+        vec![text("Hello")]
+    );
+    assert_eq!(
+        parser.parse_fragment("", ElementKind::Speak).unwrap(),
+        vec![]
+    );
+}
+
+#[test]
+fn test_unwrapped_elements_check_against_known_ancestor() {
+    let parser = Parser::new().unknown_elements(serde_ssml::UnknownElements::Unwrap);
+
+    assert_eq!(
+        parser
+            .parse_fragment("<x-vendor><s>Hi</s></x-vendor>", ElementKind::Paragraph)
+            .unwrap(),
+        vec![SsmlElement::Sentence {
+            children: vec![text("Hi")],
+        }]
+    );
+    assert!(
+        parser
+            .parse_fragment("<x-vendor><p>Hi</p></x-vendor>", ElementKind::Sentence)
+            .is_err()
+    );
+}
+
+#[test]
+fn test_borrowed_fragment() {
+    let input = r#"Hello <voice name="en-US-Jenny">world</voice>"#;
+    let fragment = borrowed::from_fragment(input, ElementKind::Paragraph).unwrap();
+
+    assert!(matches!(
+        &fragment[1],
+        borrowed::SsmlElement::Voice {
+            name: Cow::Borrowed("en-US-Jenny"),
+            ..
+        }
+    ));
+    assert_eq!(
+        fragment
+            .into_iter()
+            .map(borrowed::SsmlElement::into_owned)
+            .collect::<Vec<_>>(),
+        from_fragment(input, ElementKind::Paragraph).unwrap()
+    );
+}
@@ -0,0 +1,220 @@
+use serde_ssml::{ElementKind, NodePath, SSML, SsmlElement, from_str, to_string};
+
+const DOCUMENT: &str = r#"<speak><p><s>One</s><s>Two <emphasis>three</emphasis></s></p><break/><voice name="a">Four</voice></speak>"#;
+
+fn kinds<'a>(nodes: impl Iterator<Item = (usize, &'a SsmlElement)>) -> Vec<(usize, ElementKind)> {
+    nodes
+        .map(|(depth, element)| (depth, element.kind()))
+        .collect()
+}
+
+#[test]
+fn test_depth_first() {
+    use ElementKind::*;
+
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(
+        kinds(ssml.dfs()),
+        [
+            (0, Speak),
+            (1, Paragraph),
+            (2, Sentence),
+            (3, Text),
+            (2, Sentence),
+            (3, Text),
+            (3, Emphasis),
+            (4, Text),
+            (1, Break),
+            (1, Voice),
+            (2, Text),
+        ]
+    );
+    assert_eq!(ssml.descendants().count(), 11);
+}
+
+#[test]
+fn test_breadth_first() {
+    use ElementKind::*;
+
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(
+        kinds(ssml.bfs()),
+        [
+            (0, Speak),
+            (1, Paragraph),
+            (1, Break),
+            (1, Voice),
+            (2, Sentence),
+            (2, Sentence),
+            (2, Text),
+            (3, Text),
+            (3, Text),
+            (3, Emphasis),
+            (4, Text),
+        ]
+    );
+}
+
+#[test]
+fn test_paths() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let paths: Vec<Vec<usize>> = ssml
+        .dfs_paths()
+        .map(|(path, _)| path.indices().to_vec())
+        .collect();
+
+    assert_eq!(
+        paths,
+        [
+            vec![0],
+            vec![0, 0],
+            vec![0, 0, 0],
+            vec![0, 0, 0, 0],
+            vec![0, 0, 1],
+            vec![0, 0, 1, 0],
+            vec![0, 0, 1, 1],
+            vec![0, 0, 1, 1, 0],
+            vec![0, 1],
+            vec![0, 2],
+            vec![0, 2, 0],
+        ]
+    );
+}
+
+#[test]
+fn test_element_iterators_exclude_self() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let speak = &ssml.elements[0];
+    let paragraph = &speak.children()[0];
+
+    assert_eq!(
+        kinds(paragraph.dfs()),
+        [
+            (0, ElementKind::Sentence),
+            (1, ElementKind::Text),
+            (0, ElementKind::Sentence),
+            (1, ElementKind::Text),
+            (1, ElementKind::Emphasis),
+            (2, ElementKind::Text),
+        ]
+    );
+    assert_eq!(
+        paragraph.dfs_paths().last().map(|(path, _)| path),
+        Some(NodePath::from([1, 1, 0]))
+    );
+    assert_eq!(paragraph.bfs().count(), 6);
+    assert!(speak.children()[1].children().is_empty());
+    assert_eq!(speak.children()[1].descendants().count(), 0);
+}
+
+#[test]
+fn test_texts() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(
+        ssml.texts().collect::<Vec<_>>(),
+        ["One", "Two", "three", "Four"]
+    );
+    assert_eq!(
+        ssml.elements[0].children()[0].texts().collect::<Vec<_>>(),
+        ["One", "Two", "three"]
+    );
+    assert_eq!(
+        SsmlElement::Text("alone".to_string())
+            .texts()
+            .collect::<Vec<_>>(),
+        ["alone"]
+    );
+}
+
+#[test]
+fn test_texts_mut() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    for text in ssml.texts_mut() {
+        text.make_ascii_uppercase();
+    }
+    for text in ssml.elements[0].children_mut().unwrap()[2].texts_mut() {
+        text.push('!');
+    }
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><p><s>ONE</s><s>TWO<emphasis>THREE</emphasis></s></p><break/><voice name="a">FOUR!</voice></speak>"#
+    );
+}
+
+#[test]
+fn test_children_mut() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let speak = &mut ssml.elements[0];
+
+    speak.children_mut().unwrap().truncate(1);
+    assert_eq!(speak.children().len(), 1);
+    assert_eq!(
+        to_string(&ssml),
+        "<speak><p><s>One</s><s>Two<emphasis>three</emphasis></s></p></speak>"
+    );
+
+    let mut mark = SsmlElement::Mark {
+        name: "m".to_string(),
+    };
+    assert!(mark.children_mut().is_none());
+}
+
+#[test]
+fn test_ancestors() {
+    use ElementKind::*;
+
+    let ssml = from_str(
+        r#"<speak><voice name="a"><p><s>Two <emphasis>three</emphasis></s></p></voice></speak>"#,
+    )
+    .unwrap();
+    let kinds = |path: &[usize]| -> Vec<ElementKind> {
+        ssml.ancestors(&NodePath::from(path))
+            .map(SsmlElement::kind)
+            .collect()
+    };
+
+    assert_eq!(
+        kinds(&[0, 0, 0, 0, 1, 0]),
+        [Emphasis, Sentence, Paragraph, Voice, Speak]
+    );
+    assert_eq!(kinds(&[0, 0]), [Speak]);
+    assert!(kinds(&[0]).is_empty());
+    assert!(kinds(&[0, 0, 3]).is_empty());
+    assert!(kinds(&[]).is_empty());
+}
+
+#[test]
+fn test_siblings() {
+    use ElementKind::*;
+
+    let mut ssml =
+        from_str(r#"<speak><s>One</s><break/><mark name="m"/><s>Two</s></speak>"#).unwrap();
+    let siblings = |ssml: &SSML, path: &[usize]| -> Vec<(usize, ElementKind)> {
+        ssml.siblings(&NodePath::from(path))
+            .map(|(index, element)| (index, element.kind()))
+            .collect()
+    };
+
+    assert_eq!(
+        siblings(&ssml, &[0, 2]),
+        [(0, Sentence), (1, Break), (3, Sentence)]
+    );
+    assert!(siblings(&ssml, &[0]).is_empty());
+    assert!(siblings(&ssml, &[0, 4]).is_empty());
+    assert!(siblings(&ssml, &[0, 0, 0, 0]).is_empty());
+
+    for (_, sibling) in ssml.siblings_mut(&NodePath::from([0, 1])) {
+        if let SsmlElement::Sentence { children } = sibling {
+            children.clear();
+        }
+    }
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><s></s><break/><mark name="m"/><s></s></speak>"#
+    );
+    assert_eq!(ssml.siblings_mut(&NodePath::from([1, 0])).count(), 0);
+}
@@ -0,0 +1,125 @@
+use serde_ssml::{
+    DEFAULT_MAX_DEPTH, ErrorKind, Limit, ParseOptions, from_str, from_str_with_options,
+};
+
+fn nested_sentences(depth: usize) -> String {
+    format!("{}Deep{}", "<s>".repeat(depth), "</s>".repeat(depth))
+}
+
+fn limit_exceeded(input: &str, options: &ParseOptions) -> Limit {
+    let errors = from_str_with_options(input, options).unwrap_err();
+    match errors[0].kind() {
+        ErrorKind::LimitExceeded(limit) => *limit,
+        kind => panic!("Expected a limit error, got {kind:?}"),
+    }
+}
+
+#[test]
+fn test_hostile_nesting_is_rejected_by_default() {
+    let input = nested_sentences(100_000);
+
+    let errors = from_str(&input).unwrap_err();
+    assert_eq!(
+        errors[0].kind(),
+        &ErrorKind::LimitExceeded(Limit::Depth(DEFAULT_MAX_DEPTH))
+    );
+    assert_eq!(errors[0].span().start, DEFAULT_MAX_DEPTH * "<s>".len());
+}
+
+#[test]
+fn test_default_depth_is_accepted() {
+    assert!(from_str(nested_sentences(DEFAULT_MAX_DEPTH)).is_ok());
+}
+
+#[test]
+fn test_max_depth() {
+    let options = ParseOptions::new().max_depth(3);
+
+    assert!(from_str_with_options(nested_sentences(3), &options).is_ok());
+    assert_eq!(
+        limit_exceeded(&nested_sentences(4), &options),
+        Limit::Depth(3)
+    );
+    // Void elements count towards the depth of their parent plus one
+    assert_eq!(
+        limit_exceeded("<speak><p><s><break/></s></p></speak>", &options),
+        Limit::Depth(3)
+    );
+}
+
+#[test]
+fn test_max_elements() {
+    let options = ParseOptions::new().max_elements(3);
+
+    assert!(
+        from_str_with_options(
+            "<speak>a<mark name=\"1\"/>b<mark name=\"2\"/>c</speak>",
+            &options
+        )
+        .is_ok()
+    );
+    assert_eq!(
+        limit_exceeded("<speak><break/><break/><break/></speak>", &options),
+        Limit::Elements(3)
+    );
+}
+
+#[test]
+fn test_max_input_len() {
+    let options = ParseOptions::new().max_input_len(20);
+
+    assert!(from_str_with_options("<speak>Hello</speak>", &options).is_ok());
+    assert_eq!(
+        limit_exceeded("<speak>Hello, world</speak>", &options),
+        Limit::InputLength(20)
+    );
+}
+
+#[test]
+fn test_max_text_len() {
+    let options = ParseOptions::new().max_text_len(5);
+
+    assert!(from_str_with_options("<speak>  Hello  <break/>world</speak>", &options).is_ok());
+    assert_eq!(
+        limit_exceeded("<speak>Hello world</speak>", &options),
+        Limit::TextLength(5)
+    );
+}
+
+#[test]
+fn test_max_attributes() {
+    let options = ParseOptions::new().max_attributes(2);
+
+    assert!(
+        from_str_with_options(
+            r#"<speak version="1.1" xml:lang="en-US">Hi</speak>"#,
+            &options
+        )
+        .is_ok()
+    );
+    assert_eq!(
+        limit_exceeded(
+            r#"<speak><prosody rate="slow" pitch="low" volume="loud">Hi</prosody></speak>"#,
+            &options
+        ),
+        Limit::Attributes(2)
+    );
+    assert_eq!(
+        limit_exceeded(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="yes"?><speak/>"#,
+            &options
+        ),
+        Limit::Attributes(2)
+    );
+}
+
+#[test]
+fn test_limit_error_display() {
+    let errors =
+        from_str_with_options(nested_sentences(2), &ParseOptions::new().max_depth(1)).unwrap_err();
+
+    assert_eq!(
+        errors[0].to_string(),
+        "nesting depth exceeds the limit of 1 at byte 3"
+    );
+}
@@ -0,0 +1,105 @@
+use std::time::Duration;
+
+use serde_ssml::{BreakStrength, SsmlElement, from_str, ssml};
+
+#[test]
+fn test_macro_matches_parser() {
+    let ssml = ssml!(
+        <speak version="1.1" xml:lang="en-US">
+            <lexicon uri="https://example.com/lexicon.pls"/>
+            <voice name="Joanna">
+                <p>
+                    <s>Hello world</s>
+                    <s>
+                        <prosody rate="slow" pitch="low">Slowly</prosody>
+                        <emphasis level="strong">now</emphasis>
+                    </s>
+                </p>
+            </voice>
+            <break time="2s" strength="x-strong"/>
+            <mark name="end"/>
+            <lang xml:lang="fr-FR">
+                <phoneme alphabet="ipa" ph="bɔ̃ʒuʁ">"Bonjour"</phoneme>
+                <sub alias="World Wide Web">WWW</sub>
+            </lang>
+            <audio src="chime.wav"><desc>Chime</desc></audio>
+            <say-as interpret-as="date" format="mdy" detail="1">"10/18/2026"</say-as>
+        </speak>
+    );
+
+    let parsed = from_str(concat!(
+        r#"<speak version="1.1" xml:lang="en-US"><lexicon uri="https://example.com/lexicon.pls"/>"#,
+        r#"<voice name="Joanna"><p><s>Hello world</s><s><prosody rate="slow" pitch="low">Slowly</prosody>"#,
+        r#"<emphasis level="strong">now</emphasis></s></p></voice><break time="2s" strength="x-strong"/>"#,
+        r#"<mark name="end"/><lang xml:lang="fr-FR"><phoneme alphabet="ipa" ph="bɔ̃ʒuʁ">Bonjour</phoneme>"#,
+        r#"<sub alias="World Wide Web">WWW</sub></lang><audio src="chime.wav"><desc>Chime</desc></audio>"#,
+        r#"<say-as interpret-as="date" format="mdy" detail="1">10/18/2026</say-as></speak>"#,
+    ))
+    .unwrap();
+
+    assert_eq!(ssml, parsed);
+}
+
+#[test]
+fn test_macro_interpolation() {
+    let name = "Tom & <Jerry>";
+    let voice = String::from("Matthew");
+    let strength = BreakStrength::Weak;
+
+    let ssml = ssml!(
+        <speak>
+            <voice name={voice}>
+                <s>Hello {name} "!"</s>
+                <break strength={strength}/>
+            </voice>
+        </speak>
+    );
+
+    assert_eq!(
+        ssml.to_string(),
+        r#"<speak><voice name="Matthew"><s>Hello Tom &amp; &lt;Jerry&gt;!</s><break strength="weak"/></voice></speak>"#
+    );
+}
+
+#[test]
+fn test_macro_text() {
+    let empty = "";
+    let ssml = ssml!(<s>"Total: " {3} items, {empty} 42 "%" <break/> {2.5}</s>);
+
+    assert_eq!(
+        ssml.elements,
+        [SsmlElement::Sentence {
+            children: vec![
+                SsmlElement::Text("Total: 3 items, 42%".to_string()),
+                SsmlElement::Break {
+                    time: None,
+                    strength: None,
+                },
+                SsmlElement::Text("2.5".to_string()),
+            ],
+        }]
+    );
+}
+
+#[test]
+fn test_macro_typed_break_attributes() {
+    let pause = Duration::from_millis(1500);
+    let ssml = ssml!(<break time={pause} strength={BreakStrength::Strong}/>);
+
+    assert_eq!(
+        ssml.elements,
+        [SsmlElement::Break {
+            time: Some(pause),
+            strength: Some(BreakStrength::Strong),
+        }]
+    );
+}
+
+#[test]
+fn test_macro_literal_times_are_stricter_than_the_parser() {
+    // `ssml!(<break time="2m"/>)` does not compile, so such times are passed as a `Duration`
+    let parsed = from_str(r#"<break time="2m"/>"#).unwrap();
+    let pause = Duration::from_secs(120);
+
+    assert_eq!(ssml!(<break time={pause}/>), parsed);
+}
@@ -0,0 +1,117 @@
+use serde_ssml::{Conflict, NodePath, SsmlElement, from_str, merge, to_string};
+
+#[test]
+fn test_merge_non_overlapping() {
+    let base = from_str(concat!(
+        r#"<speak><voice name="Joanna"><s>Welcom to the show</s>"#,
+        r#"<prosody rate="slow">Goodbye</prosody></voice></speak>"#,
+    ))
+    .unwrap();
+    let ours = from_str(concat!(
+        r#"<speak><voice name="Joanna"><s>Welcome to the show</s><break time="1s"/>"#,
+        r#"<prosody rate="slow">Goodbye</prosody></voice></speak>"#,
+    ))
+    .unwrap();
+    let theirs = from_str(concat!(
+        r#"<speak xml:lang="en-US"><voice name="Joanna"><s>Welcom to the show</s>"#,
+        r#"<prosody rate="x-slow" pitch="low">Goodbye</prosody></voice><mark name="end"/></speak>"#,
+    ))
+    .unwrap();
+
+    let merged = merge(&base, &ours, &theirs).unwrap();
+    assert_eq!(
+        to_string(&merged),
+        concat!(
+            r#"<speak xml:lang="en-US"><voice name="Joanna"><s>Welcome to the show</s><break time="1000ms"/>"#,
+            r#"<prosody rate="x-slow" pitch="low">Goodbye</prosody></voice><mark name="end"/></speak>"#,
+        )
+    );
+
+    // Merging is symmetric for non-conflicting changes
+    assert_eq!(merge(&base, &theirs, &ours).unwrap(), merged);
+    // Identical changes are applied once
+    assert_eq!(merge(&base, &ours, &ours).unwrap(), ours);
+    assert_eq!(merge(&base, &base, &theirs).unwrap(), theirs);
+}
+
+#[test]
+fn test_merge_conflicts() {
+    let base = from_str(concat!(
+        r#"<speak><prosody rate="slow"><s>Hello</s></prosody>"#,
+        r#"<s>Old</s><audio src="a.wav"/></speak>"#,
+    ))
+    .unwrap();
+    let ours = from_str(concat!(
+        r#"<speak><prosody rate="fast"><s>Hello there</s></prosody>"#,
+        r#"<s>Old</s><audio src="b.wav"/></speak>"#,
+    ))
+    .unwrap();
+    let theirs = from_str(concat!(
+        r#"<speak><prosody rate="medium"><s>Hello you</s></prosody>"#,
+        r#"<s>Old</s></speak>"#,
+    ))
+    .unwrap();
+
+    let error = merge(&base, &ours, &theirs).unwrap_err();
+    assert_eq!(error.merged(), &ours);
+    assert_eq!(
+        error.conflicts(),
+        [
+            Conflict::Attribute {
+                path: NodePath::from([0, 0]),
+                name: "rate",
+                base: Some("slow".to_string()),
+                ours: Some("fast".to_string()),
+                theirs: Some("medium".to_string()),
+            },
+            Conflict::Text {
+                path: NodePath::from([0, 0, 0, 0]),
+                base: "Hello".to_string(),
+                ours: "Hello there".to_string(),
+                theirs: "Hello you".to_string(),
+            },
+            Conflict::Nodes {
+                path: NodePath::from([0, 2]),
+                base: vec![SsmlElement::Audio {
+                    src: "a.wav".to_string(),
+                    children: Vec::new(),
+                }],
+                ours: vec![SsmlElement::Audio {
+                    src: "b.wav".to_string(),
+                    children: Vec::new(),
+                }],
+                theirs: Vec::new(),
+            },
+        ]
+    );
+    assert_eq!(error.conflicts()[2].path(), &NodePath::from([0, 2]));
+    assert_eq!(
+        error.to_string(),
+        concat!(
+            "3 merge conflict(s)\n",
+            "/0/0 @rate: base \"slow\", ours \"fast\", theirs \"medium\"\n",
+            "/0/0/0/0: base \"Hello\", ours \"Hello there\", theirs \"Hello you\"\n",
+            "/0/2: base \"<audio src=\\\"a.wav\\\"></audio>\", ours \"<audio src=\\\"b.wav\\\"></audio>\", theirs \"\"",
+        )
+    );
+}
+
+#[test]
+fn test_merge_insertions() {
+    let base = from_str("<speak><s>One</s><s>Three</s></speak>").unwrap();
+    let ours = from_str("<speak><s>One</s><s>Two</s><s>Three</s></speak>").unwrap();
+    let theirs = from_str("<speak><s>One</s><s>Three</s><s>Four</s></speak>").unwrap();
+
+    assert_eq!(
+        to_string(&merge(&base, &ours, &theirs).unwrap()),
+        "<speak><s>One</s><s>Two</s><s>Three</s><s>Four</s></speak>"
+    );
+
+    // Different insertions at the same position conflict
+    let theirs = from_str("<speak><s>One</s><s>Deux</s><s>Three</s></speak>").unwrap();
+    let error = merge(&base, &ours, &theirs).unwrap_err();
+    assert!(matches!(
+        error.conflicts(),
+        [Conflict::Nodes { path, base, .. }] if *path == NodePath::from([0, 1]) && base.is_empty()
+    ));
+}
@@ -0,0 +1,120 @@
+use serde_ssml::{NormalizeOptions, NormalizeRule, SSML, SsmlElement, from_str, to_string};
+
+fn text(text: &str) -> SsmlElement {
+    SsmlElement::Text(text.to_string())
+}
+
+#[test]
+fn test_normalize_text() {
+    let mut ssml = SSML {
+        elements: vec![SsmlElement::Sentence {
+            children: vec![
+                text("Hello "),
+                text(""),
+                text("big "),
+                SsmlElement::Emphasis {
+                    level: "strong".to_string(),
+                    children: vec![text("")],
+                },
+                text("world"),
+            ],
+        }],
+    };
+    let before = to_string(&ssml);
+    let report = ssml.normalize();
+
+    assert_eq!(
+        ssml.elements,
+        [SsmlElement::Sentence {
+            children: vec![text("Hello big world")],
+        }]
+    );
+    assert_eq!(
+        to_string(&ssml),
+        before.replace("<emphasis level=\"strong\"></emphasis>", "")
+    );
+    assert_eq!(report.count(NormalizeRule::RemoveEmptyText), 2);
+    assert_eq!(report.count(NormalizeRule::RemoveEmptyContainers), 1);
+    assert_eq!(report.count(NormalizeRule::MergeText), 2);
+    assert_eq!(report.total(), 5);
+}
+
+#[test]
+fn test_normalize_structure() {
+    let mut ssml = from_str(concat!(
+        r#"<speak><p><p><s>One</s></p><p><s>Two</s></p></p>"#,
+        r#"<prosody><s>Plain</s></prosody>"#,
+        r#"<prosody rate="slow"><prosody volume="loud"><prosody rate="+10%">Nested</prosody></prosody></prosody>"#,
+        r#"<voice name="Joanna"><voice name="Joanna">Same</voice><voice name="Matthew">Other</voice></voice>"#,
+        r#"<audio src="chime.wav"></audio><sub alias="x"></sub></speak>"#,
+    ))
+    .unwrap();
+    let report = ssml.normalize();
+
+    assert_eq!(
+        to_string(&ssml),
+        concat!(
+            r#"<speak><p><s>One</s><s>Two</s></p><s>Plain</s>"#,
+            r#"<prosody rate="slow"><prosody rate="+10%" volume="loud">Nested</prosody></prosody>"#,
+            r#"<voice name="Joanna">Same<voice name="Matthew">Other</voice></voice>"#,
+            r#"<audio src="chime.wav"></audio><sub alias="x"></sub></speak>"#,
+        )
+    );
+    assert_eq!(report.count(NormalizeRule::FlattenNesting), 3);
+    assert_eq!(report.count(NormalizeRule::UnwrapEmptyProsody), 1);
+    assert_eq!(report.count(NormalizeRule::MergeProsody), 1);
+    assert_eq!(
+        report.to_string(),
+        "unwrapped empty prosody: 1, flattened nested elements: 3, merged nested prosody: 1"
+    );
+
+    // Already normalized documents are left unchanged
+    let report = ssml.normalize();
+    assert!(report.is_empty());
+    assert_eq!(report.to_string(), "no changes");
+}
+
+#[test]
+fn test_normalize_options() {
+    let input = r#"<speak><prosody><p><p>Hi</p></p></prosody><s></s></speak>"#;
+
+    let mut ssml = from_str(input).unwrap();
+    let report = ssml.normalize_with(&NormalizeOptions::none());
+    assert!(report.is_empty());
+    assert_eq!(to_string(&ssml), input);
+
+    let options = NormalizeOptions::none().rule(NormalizeRule::FlattenNesting, true);
+    assert!(options.is_enabled(NormalizeRule::FlattenNesting));
+    assert!(!options.is_enabled(NormalizeRule::MergeText));
+
+    let report = ssml.normalize_with(&options);
+    assert_eq!(report.total(), 1);
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><prosody><p>Hi</p></prosody><s></s></speak>"#
+    );
+}
+
+#[test]
+fn test_normalize_is_idempotent() {
+    let mut ssml = from_str("<speak><p><prosody><p>Hi</p></prosody></p></speak>").unwrap();
+    let report = ssml.normalize();
+
+    assert_eq!(to_string(&ssml), "<speak><p>Hi</p></speak>");
+    assert_eq!(report.count(NormalizeRule::UnwrapEmptyProsody), 1);
+    assert_eq!(report.count(NormalizeRule::FlattenNesting), 1);
+
+    let once = ssml.clone();
+    assert!(ssml.normalize().is_empty());
+    assert_eq!(ssml, once);
+}
+
+#[test]
+fn test_normalize_keeps_relative_prosody() {
+    let input =
+        r#"<speak><prosody rate="+10%"><prosody rate="+10%">Faster</prosody></prosody></speak>"#;
+    let mut ssml = from_str(input).unwrap();
+
+    assert!(ssml.normalize().is_empty());
+    assert_eq!(to_string(&ssml), input);
+}
@@ -0,0 +1,243 @@
+use std::time::Duration;
+
+use serde_ssml::{
+    ErrorKind, Limit, Namespaces, ParseOptions, Parser, SsmlElement, UnknownElements, Whitespace,
+    from_str,
+};
+
+fn speak_children(parser: &Parser, input: &str) -> Vec<SsmlElement> {
+    let ssml = parser.parse(input).unwrap();
+    match ssml.elements.into_iter().next() {
+        Some(SsmlElement::Speak { children, .. }) => children,
+        other => panic!("Expected Speak element, got {other:?}"),
+    }
+}
+
+fn error_kind(parser: &Parser, input: &str) -> ErrorKind {
+    parser.parse(input).unwrap_err()[0].kind().clone()
+}
+
+fn text(text: &str) -> SsmlElement {
+    SsmlElement::Text(text.to_string())
+}
+
+#[test]
+fn test_default_parser_matches_from_str() {
+    let input = r#"<?xml version="1.0"?>
+<speak version="1.1" xml:lang="en-US">
+    <p><s>Hello <emphasis level="strong">big</emphasis> world</s></p>
+    <break time="soon"/>
+</speak>"#;
+
+    assert_eq!(Parser::new().parse(input), from_str(input));
+}
+
+#[test]
+fn test_strict_rejects_invalid_attribute_values() {
+    let lenient = Parser::new();
+    let strict = Parser::new().strict(true);
+    let input = r#"<speak><break time="soon" strength="medium"/></speak>"#;
+
+    assert_eq!(
+        speak_children(&lenient, input),
+        vec![SsmlElement::Break {
+            time: None,
+            strength: Some(serde_ssml::BreakStrength::Medium),
+        }]
+    );
+    assert_eq!(
+        error_kind(&strict, input),
+        ErrorKind::InvalidAttribute {
+            element: "break".to_string(),
+            attribute: "time".to_string(),
+            value: "soon".to_string(),
+        }
+    );
+    assert!(matches!(
+        error_kind(&strict, r#"<speak><break strength="huge"/></speak>"#),
+        ErrorKind::InvalidAttribute { attribute, .. } if attribute == "strength"
+    ));
+}
+
+#[test]
+fn test_strict_rejects_missing_required_attributes() {
+    let strict = Parser::new().strict(true);
+
+    assert_eq!(
+        error_kind(&strict, "<speak><say-as>42</say-as></speak>"),
+        ErrorKind::MissingAttribute {
+            element: "say-as".to_string(),
+            attribute: "interpret-as".to_string(),
+        }
+    );
+    assert!(
+        Parser::new()
+            .parse("<speak><say-as>42</say-as></speak>")
+            .is_ok()
+    );
+    assert!(
+        strict
+            .parse(
+                r#"<speak><say-as interpret-as="cardinal">42</say-as><break time="1s"/></speak>"#
+            )
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_whitespace_policies() {
+    let input = "<speak>  Hello\n   big <emphasis> world </emphasis>\t<break/> </speak>";
+
+    assert_eq!(
+        speak_children(&Parser::new().whitespace(Whitespace::Trim), input),
+        vec![
+            text("Hello\n   big"),
+            SsmlElement::Emphasis {
+                level: String::new(),
+                children: vec![text("world")],
+            },
+            SsmlElement::Break {
+                time: None,
+                strength: None,
+            },
+        ]
+    );
+    assert_eq!(
+        speak_children(&Parser::new().whitespace(Whitespace::Preserve), input),
+        vec![
+            text("  Hello\n   big "),
+            SsmlElement::Emphasis {
+                level: String::new(),
+                children: vec![text(" world ")],
+            },
+            text("\t"),
+            SsmlElement::Break {
+                time: None,
+                strength: None,
+            },
+            text(" "),
+        ]
+    );
+    assert_eq!(
+        speak_children(&Parser::new().whitespace(Whitespace::Collapse), input),
+        vec![
+            text(" Hello big "),
+            SsmlElement::Emphasis {
+                level: String::new(),
+                children: vec![text(" world ")],
+            },
+            SsmlElement::Break {
+                time: None,
+                strength: None,
+            },
+        ]
+    );
+}
+
+#[test]
+fn test_unknown_elements() {
+    let input = r#"<speak>Hi <amazon:effect name="whispered">there <s>friend</s></amazon:effect><x-vendor/></speak>"#;
+
+    assert_eq!(
+        error_kind(&Parser::new(), input),
+        ErrorKind::UnknownElement("amazon:effect".to_string())
+    );
+    assert_eq!(
+        speak_children(
+            &Parser::new().unknown_elements(UnknownElements::Unwrap),
+            input
+        ),
+        vec![
+            text("Hi"),
+            text("there"),
+            SsmlElement::Sentence {
+                children: vec![text("friend")],
+            },
+        ]
+    );
+    assert_eq!(
+        speak_children(
+            &Parser::new().unknown_elements(UnknownElements::Skip),
+            input
+        ),
+        vec![text("Hi")]
+    );
+}
+
+#[test]
+fn test_require_root() {
+    let parser = Parser::new().require_root(true);
+
+    assert!(
+        parser
+            .parse("<?xml version=\"1.0\"?>\n<speak>Hi</speak>\n")
+            .is_ok()
+    );
+    assert_eq!(error_kind(&parser, "Hello"), ErrorKind::InvalidRoot);
+    assert_eq!(error_kind(&parser, ""), ErrorKind::InvalidRoot);
+    assert_eq!(
+        error_kind(&parser, "<speak>One</speak><speak>Two</speak>"),
+        ErrorKind::InvalidRoot
+    );
+    assert_eq!(error_kind(&parser, "<p>Hi</p>"), ErrorKind::InvalidRoot);
+    assert!(Parser::new().parse("<p>Hi</p>").is_ok());
+}
+
+#[test]
+fn test_namespaces() {
+    let prefixed = r#"<ssml:speak xmlns:ssml="http://www.w3.org/2001/10/synthesis"><ssml:s>Hi</ssml:s></ssml:speak>"#;
+
+    assert_eq!(
+        error_kind(&Parser::new(), prefixed),
+        ErrorKind::UnknownElement("ssml:speak".to_string())
+    );
+    assert_eq!(
+        speak_children(
+            &Parser::new().namespaces(Namespaces::StripPrefixes),
+            prefixed
+        ),
+        vec![SsmlElement::Sentence {
+            children: vec![text("Hi")],
+        }]
+    );
+
+    let require = Parser::new().namespaces(Namespaces::Require);
+    assert_eq!(
+        error_kind(&require, "<speak>Hi</speak>"),
+        ErrorKind::MissingNamespace
+    );
+    assert!(
+        require
+            .parse(r#"<speak xmlns="http://www.w3.org/2001/10/synthesis">Hi</speak>"#)
+            .is_ok()
+    );
+}
+
+#[test]
+fn test_limits() {
+    let parser = Parser::new().limits(ParseOptions::new().max_depth(1));
+
+    assert_eq!(
+        error_kind(&parser, "<speak><s>Hi</s></speak>"),
+        ErrorKind::LimitExceeded(Limit::Depth(1))
+    );
+}
+
+#[test]
+fn test_parser_is_reusable() {
+    let parser = Parser::new().strict(true);
+
+    for millis in [100, 200, 300] {
+        let children = speak_children(
+            &parser,
+            &format!(r#"<speak><break time="{millis}ms"/></speak>"#),
+        );
+        assert_eq!(
+            children,
+            vec![SsmlElement::Break {
+                time: Some(Duration::from_millis(millis)),
+                strength: None,
+            }]
+        );
+    }
+}
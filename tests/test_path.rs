@@ -0,0 +1,165 @@
+use serde_ssml::{NodePath, SsmlElement, TrackedSsml, from_str, to_string};
+
+const DOCUMENT: &str =
+    r#"<speak><p><s>One</s><s>Two</s></p><prosody rate="slow">Three</prosody></speak>"#;
+
+fn path<const N: usize>(indices: [usize; N]) -> NodePath {
+    NodePath::from(indices)
+}
+
+#[test]
+fn test_node_path() {
+    let path = path([0, 2, 1]);
+
+    assert_eq!(path.len(), 3);
+    assert_eq!(path.index(), Some(1));
+    assert_eq!(path.parent(), Some(NodePath::from([0, 2])));
+    assert_eq!(path.parent().unwrap().child(1), path);
+    assert!(NodePath::from([0]).is_ancestor_of(&path));
+    assert!(!path.is_ancestor_of(&path));
+    assert!(NodePath::root().is_empty());
+    assert_eq!(NodePath::root().parent(), None);
+
+    assert!(NodePath::from([0, 1]) < NodePath::from([0, 1, 0]));
+    assert!(NodePath::from([0, 1, 5]) < NodePath::from([0, 2]));
+
+    assert_eq!(NodePath::root().to_string(), "/");
+    assert_eq!("/".parse(), Ok(NodePath::root()));
+    assert_eq!("0/1".parse::<NodePath>(), Err(()));
+    assert_eq!("/0/x".parse::<NodePath>(), Err(()));
+
+    assert_eq!(serde_json::to_string(&path).unwrap(), "[0,2,1]");
+    assert_eq!(serde_json::from_str::<NodePath>("[0,2,1]").unwrap(), path);
+}
+
+#[test]
+fn test_get() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(
+        ssml.get(&path([0, 0, 1, 0])),
+        Some(&SsmlElement::Text("Two".to_string()))
+    );
+    assert_eq!(ssml.get(&path([0, 0, 2])), None);
+    assert_eq!(ssml.get(&path([0, 0, 1, 0, 0])), None);
+    assert_eq!(ssml.get(&NodePath::root()), None);
+
+    if let Some(SsmlElement::Prosody { rate, .. }) = ssml.get_mut(&path([0, 1])) {
+        *rate = "fast".to_string();
+    }
+    assert!(to_string(&ssml).contains(r#"<prosody rate="fast">"#));
+
+    assert_eq!(
+        ssml.parent_of(&path([0, 1, 0])).map(SsmlElement::kind),
+        Some(serde_ssml::ElementKind::Prosody)
+    );
+    assert_eq!(ssml.parent_of(&path([0])), None);
+    assert_eq!(ssml.parent_of(&path([0, 9, 0])), None);
+}
+
+#[test]
+fn test_insert_and_remove() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let mark = || SsmlElement::Mark {
+        name: "m".to_string(),
+    };
+
+    ssml.insert_at(&path([0, 0, 2]), mark()).unwrap();
+    ssml.insert_at(&path([1]), SsmlElement::Text("End".to_string()))
+        .unwrap();
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><p><s>One</s><s>Two</s><mark name="m"/></p><prosody rate="slow">Three</prosody></speak>End"#
+    );
+
+    assert!(ssml.insert_at(&path([0, 0, 4]), mark()).is_err());
+    assert!(ssml.insert_at(&path([0, 0, 2, 0]), mark()).is_err());
+    assert!(ssml.insert_at(&NodePath::root(), mark()).is_err());
+
+    assert_eq!(
+        ssml.remove_at(&path([0, 0, 0]))
+            .map(|element| element.to_string()),
+        Some("<s>One</s>".to_string())
+    );
+    assert_eq!(ssml.remove_at(&path([0, 0, 2])), None);
+    assert_eq!(
+        ssml.remove_at(&path([1])),
+        Some(SsmlElement::Text("End".to_string()))
+    );
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><p><s>Two</s><mark name="m"/></p><prosody rate="slow">Three</prosody></speak>"#
+    );
+}
+
+#[test]
+fn test_tracked_ids_survive_edits() {
+    let mut tracked = TrackedSsml::new(from_str(DOCUMENT).unwrap());
+    let two = tracked.id_at(&path([0, 0, 1])).unwrap();
+    let prosody = tracked.id_at(&path([0, 1])).unwrap();
+
+    // Document order numbering is deterministic
+    assert_eq!(
+        TrackedSsml::new(from_str(DOCUMENT).unwrap()).id_at(&path([0, 1])),
+        Some(prosody)
+    );
+    assert!(two < prosody);
+
+    let inserted = tracked
+        .insert_at(&path([0, 0, 0]), SsmlElement::Text("Zero".to_string()))
+        .unwrap();
+    assert!(inserted > prosody);
+    assert_eq!(tracked.path_of(two), Some(path([0, 0, 2])));
+
+    let _ = tracked.remove_at(&path([0, 0, 1])).unwrap();
+    assert_eq!(tracked.path_of(two), Some(path([0, 0, 1])));
+
+    tracked.modify(prosody, |element| {
+        if let SsmlElement::Prosody { rate, .. } = element {
+            *rate = "fast".to_string();
+        }
+    });
+    assert!(
+        tracked
+            .get(prosody)
+            .is_some_and(|element| element.to_string().contains("fast"))
+    );
+
+    let removed = tracked.remove(two).unwrap();
+    assert_eq!(removed.to_string(), "<s>Two</s>");
+    assert_eq!(tracked.path_of(two), None);
+    assert_eq!(tracked.get(two), None);
+    assert_eq!(tracked.modify(two, |_| ()), None);
+
+    assert_eq!(
+        to_string(&tracked.into_inner()),
+        r#"<speak><p>Zero</p><prosody rate="fast">Three</prosody></speak>"#
+    );
+}
+
+#[test]
+fn test_tracked_modify_reassigns_changed_children() {
+    let mut tracked = TrackedSsml::new(from_str(DOCUMENT).unwrap());
+    let paragraph = tracked.id_at(&path([0, 0])).unwrap();
+    let first = tracked.id_at(&path([0, 0, 0])).unwrap();
+    let text = tracked.id_at(&path([0, 0, 1, 0])).unwrap();
+
+    // Same shape: IDs are kept
+    tracked.modify(paragraph, |element| {
+        for text in element.texts_mut() {
+            text.make_ascii_uppercase();
+        }
+    });
+    assert_eq!(tracked.id_at(&path([0, 0, 1, 0])), Some(text));
+
+    // Changed shape: the children get new IDs
+    tracked.modify(paragraph, |element| {
+        element
+            .children_mut()
+            .unwrap()
+            .push(SsmlElement::Sentence { children: vec![] });
+    });
+    assert_eq!(tracked.id_at(&path([0, 0])), Some(paragraph));
+    assert_eq!(tracked.path_of(first), None);
+    assert!(tracked.id_at(&path([0, 0, 2])).is_some());
+}
@@ -0,0 +1,105 @@
+use serde_ssml::{SSML, SsmlElement, from_str, to_string, to_string_pretty};
+
+fn pretty(input: &str) -> String {
+    to_string_pretty(&from_str(input).unwrap())
+}
+
+#[test]
+fn test_block_elements_are_indented() {
+    let input = r#"<speak version="1.1" xml:lang="en-US"><voice name="en-US-Jenny"><prosody rate="slow"><p><s>One.</s><s>Two.</s></p></prosody></voice><lexicon uri="a.pls"/></speak>"#;
+
+    assert_eq!(
+        pretty(input),
+        r#"<speak version="1.1" xml:lang="en-US">
+  <voice name="en-US-Jenny">
+    <prosody rate="slow">
+      <p>
+        <s>One.</s>
+        <s>Two.</s>
+      </p>
+    </prosody>
+  </voice>
+  <lexicon uri="a.pls"/>
+</speak>"#
+    );
+}
+
+#[test]
+fn test_inline_elements_stay_on_the_text_line() {
+    let input = r#"<speak><s>Call <say-as interpret-as="telephone">555-0100</say-as><break strength="weak"/>now, <emphasis level="strong">today</emphasis>.</s></speak>"#;
+
+    assert_eq!(
+        pretty(input),
+        r#"<speak>
+  <s>Call<say-as interpret-as="telephone">555-0100</say-as><break strength="weak"/>now,<emphasis level="strong">today</emphasis>.</s>
+</speak>"#
+    );
+}
+
+#[test]
+fn test_mixed_content_is_never_split() {
+    // The voice shares a line with text, so nothing inside it may gain whitespace either
+    let input = "<speak><p>Un<voice name=\"a\"><s>believ</s></voice>able</p></speak>";
+
+    assert_eq!(
+        pretty(input),
+        "<speak>\n  <p>Un<voice name=\"a\"><s>believ</s></voice>able</p>\n</speak>"
+    );
+
+    // Adjacent inline elements would be read as one word, so their parent stays on one line
+    let input = "<speak><s><emphasis>Un</emphasis><emphasis>believable</emphasis></s></speak>";
+    assert_eq!(
+        pretty(input),
+        "<speak>\n  <s><emphasis>Un</emphasis><emphasis>believable</emphasis></s>\n</speak>"
+    );
+}
+
+#[test]
+fn test_pretty_output_parses_back() {
+    let inputs = [
+        r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en-US">
+            <p><s>Hello <emphasis>there</emphasis>.</s><break time="300ms"/><s>Bye.</s></p>
+            <audio src="chime.wav"><desc>A chime</desc>Ding</audio>
+            <lang xml:lang="fr-FR"><s>Bonjour</s></lang>
+        </speak>"#,
+        "<speak><p></p><s><mark name=\"m\"/></s></speak>",
+        "<speak>One</speak><speak>Two</speak>",
+        "Just text",
+    ];
+
+    for input in inputs {
+        let ssml = from_str(input).unwrap();
+        assert_eq!(from_str(to_string_pretty(&ssml)).unwrap(), ssml, "{input}");
+    }
+}
+
+#[test]
+fn test_top_level_elements() {
+    assert_eq!(
+        pretty("<speak>One</speak><speak>Two</speak>"),
+        "<speak>One</speak>\n<speak>Two</speak>"
+    );
+    assert_eq!(to_string_pretty(&SSML::default()), "");
+
+    let ssml = SSML {
+        elements: vec![
+            SsmlElement::Text("Hello".to_string()),
+            SsmlElement::Break {
+                time: None,
+                strength: None,
+            },
+        ],
+    };
+    assert_eq!(to_string_pretty(&ssml), to_string(&ssml));
+}
+
+#[test]
+fn test_compact_output_is_unchanged() {
+    let input =
+        r#"<speak version="1.1"><p><s>Hi <sub alias="World Wide Web">WWW</sub></s></p></speak>"#;
+
+    assert_eq!(
+        to_string(&from_str(input).unwrap()),
+        r#"<speak version="1.1"><p><s>Hi<sub alias="World Wide Web">WWW</sub></s></p></speak>"#
+    );
+}
@@ -0,0 +1,166 @@
+use serde_ssml::{ElementKind, SSML, Selector, SsmlElement, from_str, to_string};
+
+const DOCUMENT: &str = r#"<speak xml:lang="en-US"><voice name="en-US-Jenny"><prosody rate="slow"><emphasis level="strong">One</emphasis><s><emphasis>Two</emphasis></s></prosody><p><s>A</s><s>B <say-as interpret-as="date" format="ymd">2024-01-01</say-as></s><s>C</s><s>D</s></p></voice><voice name="en-GB-Ryan"><emphasis>Three</emphasis><break time="500ms"/></voice><say-as interpret-as="date">today</say-as></speak>"#;
+
+fn texts(ssml: &SSML, selector: &str) -> Vec<String> {
+    ssml.select(selector)
+        .unwrap()
+        .into_iter()
+        .map(|element| element.texts().collect::<Vec<_>>().join(" "))
+        .collect()
+}
+
+#[test]
+fn test_tags_and_combinators() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(texts(&ssml, "emphasis"), ["One", "Two", "Three"]);
+    assert_eq!(
+        texts(&ssml, "voice[name='en-US-Jenny'] prosody > emphasis"),
+        ["One"]
+    );
+    assert_eq!(
+        texts(&ssml, "voice[name='en-US-Jenny'] prosody emphasis"),
+        ["One", "Two"]
+    );
+    assert_eq!(texts(&ssml, "speak > voice > emphasis"), ["Three"]);
+    assert_eq!(ssml.select("*").unwrap().len(), 16);
+    assert_eq!(ssml.select("speak > *").unwrap().len(), 3);
+}
+
+#[test]
+fn test_attribute_predicates() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(ssml.select("[xml:lang]").unwrap().len(), 1);
+    assert_eq!(ssml.select("emphasis[level]").unwrap().len(), 1);
+    assert_eq!(ssml.select(r#"voice[name="en-GB-Ryan"]"#).unwrap().len(), 1);
+    assert_eq!(ssml.select("voice[name^=en-]").unwrap().len(), 2);
+    assert_eq!(ssml.select("voice[name$=Ryan]").unwrap().len(), 1);
+    assert_eq!(ssml.select("voice[name*=US]").unwrap().len(), 1);
+    assert_eq!(ssml.select("break[time=500ms]").unwrap().len(), 1);
+    assert_eq!(
+        ssml.select("say-as[interpret-as=date][format=ymd]")
+            .unwrap()
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_structural_pseudo_classes() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    assert_eq!(texts(&ssml, "p > s:first-child"), ["A"]);
+    assert_eq!(texts(&ssml, "p > s:last-child"), ["D"]);
+    assert_eq!(texts(&ssml, "p > s:nth-child(2)"), ["B 2024-01-01"]);
+    assert_eq!(texts(&ssml, "p > s:nth-child(odd)"), ["A", "C"]);
+    assert_eq!(texts(&ssml, "p > s:nth-child(2n)"), ["B 2024-01-01", "D"]);
+    assert_eq!(texts(&ssml, "p > s:nth-child(-n+2)"), ["A", "B 2024-01-01"]);
+    assert_eq!(
+        texts(&ssml, "voice > :last-child"),
+        ["A B 2024-01-01 C D", ""]
+    );
+}
+
+#[test]
+fn test_has() {
+    let ssml = from_str(DOCUMENT).unwrap();
+
+    let voices = ssml.select("voice:has(say-as)").unwrap();
+    assert_eq!(voices.len(), 1);
+    assert!(matches!(voices[0], SsmlElement::Voice { name, .. } if name == "en-US-Jenny"));
+
+    assert_eq!(ssml.select("voice:has(> emphasis)").unwrap().len(), 1);
+    assert_eq!(ssml.select("voice:has(> s)").unwrap().len(), 0);
+    assert_eq!(ssml.select("prosody:has(s > emphasis)").unwrap().len(), 1);
+    assert_eq!(ssml.select("s:has(say-as, emphasis)").unwrap().len(), 2);
+    assert_eq!(ssml.select(":has(> p > s)").unwrap().len(), 1);
+}
+
+#[test]
+fn test_selector_list() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let kinds: Vec<ElementKind> = ssml
+        .select("break, p")
+        .unwrap()
+        .into_iter()
+        .map(SsmlElement::kind)
+        .collect();
+
+    assert_eq!(kinds, [ElementKind::Paragraph, ElementKind::Break]);
+}
+
+#[test]
+fn test_qa_assertion() {
+    // Every date must be spoken inside a sentence
+    let ssml = from_str(DOCUMENT).unwrap();
+    let dates = ssml.select("say-as[interpret-as=date]").unwrap();
+    let in_sentences = ssml.select("s say-as[interpret-as=date]").unwrap();
+
+    assert_eq!(dates.len(), 2);
+    assert_eq!(in_sentences.len(), 1);
+}
+
+#[test]
+fn test_select_mut() {
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let count = ssml
+        .select_mut("voice", |element| {
+            if let SsmlElement::Voice { name, .. } = element {
+                name.make_ascii_lowercase();
+            }
+        })
+        .unwrap();
+    assert_eq!(count, 2);
+    assert_eq!(ssml.select("voice[name^=en-us]").unwrap().len(), 1);
+
+    // Replacing an ancestor after its descendants keeps every match valid
+    let mut order = Vec::new();
+    let count = ssml
+        .select_mut("prosody, prosody emphasis", |element| {
+            order.push(element.kind());
+            if let SsmlElement::Prosody { children, .. } = element {
+                children.clear();
+            }
+        })
+        .unwrap();
+    assert_eq!(count, 3);
+    assert_eq!(
+        order,
+        [
+            ElementKind::Emphasis,
+            ElementKind::Emphasis,
+            ElementKind::Prosody
+        ]
+    );
+    assert!(to_string(&ssml).contains(r#"<prosody rate="slow"></prosody>"#));
+}
+
+#[test]
+fn test_errors() {
+    for (selector, position) in [
+        ("", 0),
+        ("sentence", 0),
+        ("voice >", 7),
+        ("voice[name", 10),
+        ("voice[name=]", 11),
+        ("voice[name='x]", 14),
+        ("s:nth-child(x)", 12),
+        ("s:hover", 2),
+        ("s:has(> )", 8),
+        ("s)", 1),
+    ] {
+        let error = Selector::parse(selector).unwrap_err();
+        assert_eq!(error.position(), position, "{selector}: {error}");
+    }
+
+    assert_eq!(
+        from_str("<speak/>")
+            .unwrap()
+            .select("s say")
+            .unwrap_err()
+            .to_string(),
+        "invalid selector: expected an SSML element name at position 2"
+    );
+}
@@ -0,0 +1,278 @@
+use serde::{Deserialize, Serialize};
+use serde_ssml::{DeError, SeError, de, se};
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "speak")]
+struct Prompt {
+    #[serde(rename = "@version")]
+    version: String,
+    #[serde(rename = "@xml:lang", skip_serializing_if = "Option::is_none", default)]
+    lang: Option<String>,
+    #[serde(rename = "voice")]
+    voice: Voice,
+    #[serde(rename = "mark", default)]
+    marks: Vec<Mark>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Voice {
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "p")]
+    paragraphs: Vec<Paragraph>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Paragraph {
+    #[serde(rename = "s")]
+    sentences: Vec<String>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Mark {
+    #[serde(rename = "@name")]
+    name: String,
+}
+
+fn prompt() -> Prompt {
+    Prompt {
+        version: "1.1".to_string(),
+        lang: None,
+        voice: Voice {
+            name: "en-US-Jenny".to_string(),
+            paragraphs: vec![
+                Paragraph {
+                    sentences: vec!["Hello.".to_string(), "Tom & Jerry.".to_string()],
+                },
+                Paragraph {
+                    sentences: vec!["Bye.".to_string()],
+                },
+            ],
+        },
+        marks: vec![Mark {
+            name: "end".to_string(),
+        }],
+    }
+}
+
+const PROMPT: &str = r#"<speak version="1.1"><voice name="en-US-Jenny"><p><s>Hello.</s><s>Tom &amp; Jerry.</s></p><p><s>Bye.</s></p></voice><mark name="end"/></speak>"#;
+
+#[test]
+fn test_struct_round_trip() {
+    assert_eq!(se::to_string(&prompt()).unwrap(), PROMPT);
+    assert_eq!(de::from_str::<Prompt>(PROMPT).unwrap(), prompt());
+}
+
+#[test]
+fn test_optional_fields() {
+    let mut prompt = prompt();
+    prompt.lang = Some("en-GB".to_string());
+    prompt.marks.clear();
+
+    let markup = se::to_string(&prompt).unwrap();
+    assert!(markup.starts_with(r#"<speak version="1.1" xml:lang="en-GB">"#));
+    assert!(!markup.contains("<mark"));
+    assert_eq!(de::from_str::<Prompt>(&markup).unwrap(), prompt);
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "s")]
+struct Sentence {
+    #[serde(rename = "$value")]
+    content: Vec<Inline>,
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+enum Inline {
+    #[serde(rename = "$text")]
+    Text(String),
+    #[serde(rename = "emphasis")]
+    Emphasis {
+        #[serde(rename = "@level")]
+        level: String,
+        #[serde(rename = "$text")]
+        text: String,
+    },
+    #[serde(rename = "break")]
+    Break {
+        #[serde(rename = "@time")]
+        time: String,
+    },
+    #[serde(rename = "say-as")]
+    SayAs {
+        #[serde(rename = "@interpret-as")]
+        interpret_as: String,
+        #[serde(rename = "$text")]
+        value: u32,
+    },
+}
+
+#[test]
+fn test_mixed_content() {
+    let sentence = Sentence {
+        content: vec![
+            Inline::Text("You have".to_string()),
+            Inline::SayAs {
+                interpret_as: "cardinal".to_string(),
+                value: 3,
+            },
+            Inline::Emphasis {
+                level: "strong".to_string(),
+                text: "new".to_string(),
+            },
+            Inline::Break {
+                time: "250ms".to_string(),
+            },
+            Inline::Text("messages".to_string()),
+        ],
+    };
+    let markup = r#"<s>You have<say-as interpret-as="cardinal">3</say-as><emphasis level="strong">new</emphasis><break time="250ms"/>messages</s>"#;
+
+    assert_eq!(se::to_string(&sentence).unwrap(), markup);
+    assert_eq!(de::from_str::<Sentence>(markup).unwrap(), sentence);
+}
+
+#[test]
+fn test_top_level_sequence() {
+    let sentences = vec![
+        Sentence {
+            content: vec![Inline::Text("One".to_string())],
+        },
+        Sentence {
+            content: vec![Inline::Text("Two".to_string())],
+        },
+    ];
+    let markup = se::to_string(&sentences).unwrap();
+
+    assert_eq!(markup, "<s>One</s><s>Two</s>");
+    assert_eq!(de::from_str::<Vec<Sentence>>(&markup).unwrap(), sentences);
+}
+
+#[test]
+fn test_numbers_and_unit_elements() {
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    #[serde(rename = "prosody")]
+    struct Prosody {
+        #[serde(rename = "@rate")]
+        rate: f32,
+        #[serde(rename = "say-as")]
+        count: Count,
+        #[serde(rename = "break")]
+        pause: (),
+    }
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Count {
+        #[serde(rename = "@interpret-as")]
+        interpret_as: String,
+        #[serde(rename = "$text")]
+        value: i64,
+    }
+
+    let prosody = Prosody {
+        rate: 0.5,
+        count: Count {
+            interpret_as: "cardinal".to_string(),
+            value: -42,
+        },
+        pause: (),
+    };
+    let markup = se::to_string(&prosody).unwrap();
+
+    assert_eq!(
+        markup,
+        r#"<prosody rate="0.5"><say-as interpret-as="cardinal">-42</say-as><break/></prosody>"#
+    );
+    assert_eq!(de::from_str::<Prosody>(&markup).unwrap(), prosody);
+}
+
+#[test]
+fn test_serialize_errors() {
+    #[derive(Serialize)]
+    #[serde(rename = "amazon:effect")]
+    struct Effect {}
+
+    #[derive(Serialize)]
+    #[serde(rename = "voice")]
+    struct Voice {
+        #[serde(rename = "@gender")]
+        gender: &'static str,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename = "break")]
+    struct Break {
+        #[serde(rename = "@time")]
+        time: &'static str,
+        #[serde(rename = "$text")]
+        text: &'static str,
+    }
+
+    assert_eq!(
+        se::to_string(&Effect {}),
+        Err(SeError::UnknownElement("amazon:effect".to_string()))
+    );
+    assert_eq!(
+        se::to_string(&Voice { gender: "female" }),
+        Err(SeError::UnknownAttribute {
+            element: "voice".to_string(),
+            attribute: "gender".to_string(),
+        })
+    );
+    assert!(matches!(
+        se::to_string(&Break { time: "soon", text: "" }),
+        Err(SeError::InvalidAttribute { attribute, .. }) if attribute == "time"
+    ));
+    assert_eq!(
+        se::to_string(&Break {
+            time: "1s",
+            text: "Hi"
+        }),
+        Err(SeError::UnexpectedContent("break".to_string()))
+    );
+    assert_eq!(
+        se::to_string(&std::collections::BTreeMap::from([("s", "Hi")])),
+        Err(SeError::Unsupported("a map without element name"))
+    );
+}
+
+#[test]
+fn test_deserialize_errors() {
+    assert!(matches!(
+        de::from_str::<Prompt>("<speak>"),
+        Err(DeError::Parse(_))
+    ));
+    assert!(matches!(
+        de::from_str::<Mark>("<mark/><mark/>"),
+        Err(DeError::Custom(message)) if message == "expected a single element, found 2 nodes"
+    ));
+    assert!(matches!(
+        de::from_str::<Prompt>(r#"<speak version="1.1"/>"#),
+        Err(DeError::Custom(message)) if message.contains("voice")
+    ));
+
+    #[derive(Debug, Deserialize)]
+    #[allow(dead_code)]
+    struct Count {
+        #[serde(rename = "$text")]
+        value: u8,
+    }
+    assert!(de::from_str::<Count>("<s>many</s>").is_err());
+}
+
+#[test]
+fn test_deserialize_borrowed() {
+    #[derive(Deserialize)]
+    struct Voice<'a> {
+        #[serde(rename = "@name", borrow)]
+        name: &'a str,
+        #[serde(rename = "$text", borrow)]
+        text: &'a str,
+    }
+
+    let ssml = serde_ssml::from_str(r#"<voice name="en-US-Jenny">Hi</voice>"#).unwrap();
+    let voice: Voice<'_> = de::from_ssml(&ssml).unwrap();
+
+    assert_eq!(voice.name, "en-US-Jenny");
+    assert_eq!(voice.text, "Hi");
+}
@@ -0,0 +1,108 @@
+use serde_ssml::{
+    AttributeOrder, EmptyElements, Quote, SSML, SerializeOptions, SsmlElement, from_str, to_string,
+    to_string_with_options,
+};
+
+fn serialize(input: &str, options: &SerializeOptions) -> String {
+    to_string_with_options(&from_str(input).unwrap(), options)
+}
+
+#[test]
+fn test_default_options_match_to_string() {
+    let input = r#"<speak version="1.1" xml:lang="en-US"><p><s>Hi<break time="1s"/><mark name="m"/></s></p></speak>"#;
+    let ssml = from_str(input).unwrap();
+
+    assert_eq!(
+        to_string_with_options(&ssml, &SerializeOptions::new()),
+        to_string(&ssml)
+    );
+}
+
+#[test]
+fn test_xml_declaration() {
+    let options = SerializeOptions::new().xml_declaration(true);
+
+    assert_eq!(
+        serialize("<speak>Hi</speak>", &options),
+        r#"<?xml version="1.0"?><speak>Hi</speak>"#
+    );
+    assert_eq!(
+        serialize("<speak><p><s>Hi</s></p></speak>", &options.indent(2)),
+        "<?xml version=\"1.0\"?>\n<speak>\n  <p>\n    <s>Hi</s>\n  </p>\n</speak>"
+    );
+}
+
+#[test]
+fn test_default_namespace() {
+    let options = SerializeOptions::new().default_namespace(true);
+
+    assert_eq!(
+        serialize(r#"<speak xml:lang="en-US">Hi</speak>"#, &options),
+        r#"<speak version="1.1" xmlns="http://www.w3.org/2001/10/synthesis" xml:lang="en-US">Hi</speak>"#
+    );
+    // Existing values are kept
+    assert_eq!(
+        serialize(
+            r#"<speak version="1.0" xmlns="urn:custom">Hi</speak>"#,
+            &options
+        ),
+        r#"<speak version="1.0" xmlns="urn:custom">Hi</speak>"#
+    );
+}
+
+#[test]
+fn test_single_quotes() {
+    let options = SerializeOptions::new().quote(Quote::Single);
+    let output = serialize(
+        r#"<speak><sub alias="it's &quot;fine&quot;">IIF</sub></speak>"#,
+        &options,
+    );
+
+    assert_eq!(
+        output,
+        r#"<speak><sub alias='it&apos;s "fine"'>IIF</sub></speak>"#
+    );
+    assert_eq!(
+        from_str(&output).unwrap(),
+        from_str(r#"<speak><sub alias="it's &quot;fine&quot;">IIF</sub></speak>"#).unwrap()
+    );
+}
+
+#[test]
+fn test_expanded_empty_elements() {
+    let options = SerializeOptions::new().empty_elements(EmptyElements::Expanded);
+    let input =
+        r#"<speak><lexicon uri="a.pls"/>Hi<break strength="weak"/><mark name="m"/><p></p></speak>"#;
+    let output = serialize(input, &options);
+
+    assert_eq!(
+        output,
+        r#"<speak><lexicon uri="a.pls"></lexicon>Hi<break strength="weak"></break><mark name="m"></mark><p></p></speak>"#
+    );
+    assert_eq!(from_str(&output).unwrap(), from_str(input).unwrap());
+}
+
+#[test]
+fn test_alphabetical_attribute_order() {
+    let options = SerializeOptions::new().attribute_order(AttributeOrder::Alphabetical);
+
+    assert_eq!(
+        serialize(
+            r#"<speak xml:lang="en-US" version="1.1"><prosody volume="loud" rate="slow" pitch="high">Hi</prosody></speak>"#,
+            &options
+        ),
+        r#"<speak version="1.1" xml:lang="en-US"><prosody pitch="high" rate="slow" volume="loud">Hi</prosody></speak>"#
+    );
+}
+
+#[test]
+fn test_empty_audio_src_is_omitted() {
+    let ssml = SSML {
+        elements: vec![SsmlElement::Audio {
+            src: String::new(),
+            children: vec![SsmlElement::Text("Fallback".to_string())],
+        }],
+    };
+
+    assert_eq!(to_string(&ssml), "<audio>Fallback</audio>");
+}
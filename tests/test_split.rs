@@ -0,0 +1,116 @@
+use serde_ssml::{SSML, SplitLimits, SsmlElement, from_str, split, to_string};
+
+fn strings(chunks: &[SSML]) -> Vec<String> {
+    chunks.iter().map(to_string).collect()
+}
+
+fn marks(ssml: &SSML) -> Vec<String> {
+    ssml.dfs()
+        .filter_map(|(_, node)| match node {
+            SsmlElement::Mark { name } => Some(name.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_split_fits() {
+    let ssml = from_str("<speak><p><s>Hello</s></p></speak>").unwrap();
+    assert_eq!(
+        split(&ssml, &SplitLimits::polly()),
+        std::slice::from_ref(&ssml)
+    );
+    assert_eq!(split(&ssml, &SplitLimits::new()), [ssml]);
+    assert!(split(&SSML::default(), &SplitLimits::google()).is_empty());
+}
+
+#[test]
+fn test_split_paragraphs_and_sentences() {
+    let ssml = from_str(concat!(
+        r#"<speak xml:lang="en-US"><lexicon uri="a.pls"/><voice name="Joanna">"#,
+        "<p><s>One one.</s><s>Two two.</s></p>",
+        r#"<p><s>Three.</s><mark name="m"/><s>Four four four.</s></p>"#,
+        "</voice></speak>",
+    ))
+    .unwrap();
+
+    let chunks = split(&ssml, &SplitLimits::new().max_billable_chars(16));
+    assert_eq!(
+        strings(&chunks),
+        [
+            concat!(
+                r#"<speak xml:lang="en-US"><lexicon uri="a.pls"/><voice name="Joanna">"#,
+                "<p><s>One one.</s><s>Two two.</s></p></voice></speak>",
+            ),
+            concat!(
+                r#"<speak xml:lang="en-US"><lexicon uri="a.pls"/><voice name="Joanna">"#,
+                r#"<p><s>Three.</s><mark name="m"/></p></voice></speak>"#,
+            ),
+            concat!(
+                r#"<speak xml:lang="en-US"><lexicon uri="a.pls"/><voice name="Joanna">"#,
+                "<p><s>Four four four.</s></p></voice></speak>",
+            ),
+        ]
+    );
+
+    let rejoined: Vec<String> = chunks.iter().flat_map(marks).collect();
+    assert_eq!(rejoined, marks(&ssml));
+}
+
+#[test]
+fn test_split_text() {
+    let ssml = from_str(concat!(
+        r#"<speak><prosody rate="slow"><lang xml:lang="fr-FR">"#,
+        "Une phrase. Deux phrases ici! Trois",
+        "</lang></prosody></speak>",
+    ))
+    .unwrap();
+    let context = r#"<speak><prosody rate="slow"><lang xml:lang="fr-FR">"#;
+
+    let chunks = split(&ssml, &SplitLimits::new().max_billable_chars(24));
+    assert_eq!(
+        strings(&chunks),
+        [
+            format!("{context}Une phrase.</lang></prosody></speak>"),
+            format!("{context}Deux phrases ici! Trois</lang></prosody></speak>"),
+        ]
+    );
+
+    // Sentences longer than the limit are split between words
+    let chunks = split(&ssml, &SplitLimits::new().max_billable_chars(8));
+    assert_eq!(
+        strings(&chunks),
+        [
+            format!("{context}Une</lang></prosody></speak>"),
+            format!("{context}phrase.</lang></prosody></speak>"),
+            format!("{context}Deux</lang></prosody></speak>"),
+            format!("{context}phrases</lang></prosody></speak>"),
+            format!("{context}ici!</lang></prosody></speak>"),
+            format!("{context}Trois</lang></prosody></speak>"),
+        ]
+    );
+}
+
+#[test]
+fn test_split_markup_limits() {
+    let ssml = from_str(concat!(
+        "<speak><s>Alpha</s><s>Beta</s>",
+        r#"<say-as interpret-as="characters">ABCDEFGHIJ</say-as></speak>"#,
+    ))
+    .unwrap();
+
+    let limits = SplitLimits::new().max_bytes(40);
+    let chunks = split(&ssml, &limits);
+    assert_eq!(
+        strings(&chunks),
+        [
+            "<speak><s>Alpha</s><s>Beta</s></speak>",
+            r#"<speak><say-as interpret-as="characters">ABCDEFGHIJ</say-as></speak>"#,
+        ]
+    );
+
+    // Characters are counted instead of bytes
+    let ssml = from_str("<speak><s>ééééé</s><s>ééééé</s></speak>").unwrap();
+    assert_eq!(split(&ssml, &SplitLimits::new().max_chars(40)).len(), 1);
+    assert_eq!(split(&ssml, &SplitLimits::new().max_bytes(40)).len(), 2);
+}
@@ -789,31 +789,31 @@ fn test_document_with_text_outside_elements() {
     assert!(parsed.is_ok());
     assert_eq!(parsed.as_ref().unwrap().elements.len(), 1);
 
-    if let Ok(ssml) = parsed {
-        if let SsmlElement::Speak { children, .. } = &ssml.elements[0] {
-            // Should have 3 children: text, paragraph, text
-            assert_eq!(children.len(), 3);
-
-            // Check for the text elements
-            let text_elements: Vec<_> = children
-                .iter()
-                .filter_map(|child| {
-                    if let SsmlElement::Text(text) = child {
-                        if !text.trim().is_empty() {
-                            Some(text)
-                        } else {
-                            None
-                        }
+    if let Ok(ssml) = parsed
+        && let SsmlElement::Speak { children, .. } = &ssml.elements[0]
+    {
+        // Should have 3 children: text, paragraph, text
+        assert_eq!(children.len(), 3);
+
+        // Check for the text elements
+        let text_elements: Vec<_> = children
+            .iter()
+            .filter_map(|child| {
+                if let SsmlElement::Text(text) = child {
+                    if !text.trim().is_empty() {
+                        Some(text)
                     } else {
                         None
                     }
-                })
-                .collect();
+                } else {
+                    None
+                }
+            })
+            .collect();
 
-            assert_eq!(text_elements.len(), 2);
-            assert_eq!(text_elements[0], "Text before element");
-            assert_eq!(text_elements[1], "Text after element");
-        }
+        assert_eq!(text_elements.len(), 2);
+        assert_eq!(text_elements[0], "Text before element");
+        assert_eq!(text_elements[1], "Text after element");
     }
 }
 
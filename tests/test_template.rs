@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+
+use serde_ssml::{PlaceholderKind, Template, TemplateError, from_str};
+
+#[test]
+fn test_template_render() {
+    let template = Template::parse(concat!(
+        r#"<speak xml:lang="{lang}"><voice name="{voice}">"#,
+        r#"<s>Hello {first} {last}, you owe <say-as interpret-as="currency">{amount}</say-as>.</s>"#,
+        r#"<audio src="https://example.com/{clip}.wav"/>"#,
+        r#"<s>Braces: {{literal}}</s></voice></speak>"#,
+    ))
+    .unwrap();
+
+    let placeholders: Vec<_> = template
+        .placeholders()
+        .iter()
+        .map(|placeholder| (placeholder.name(), placeholder.kind()))
+        .collect();
+    assert_eq!(
+        placeholders,
+        [
+            ("lang", PlaceholderKind::Attribute),
+            ("voice", PlaceholderKind::Attribute),
+            ("first", PlaceholderKind::Text),
+            ("last", PlaceholderKind::Text),
+            ("amount", PlaceholderKind::Text),
+            ("clip", PlaceholderKind::Attribute),
+        ]
+    );
+
+    let values = HashMap::from([
+        ("lang", "en-US".to_string()),
+        ("voice", r#"Jo"anna"#.to_string()),
+        ("first", "Tom".to_string()),
+        ("last", "<Jerry>".to_string()),
+        ("amount", "$5".to_string()),
+        ("clip", "a&b".to_string()),
+        ("unused", "ignored".to_string()),
+    ]);
+    let ssml = template.render(&values).unwrap();
+
+    assert_eq!(
+        ssml,
+        from_str(concat!(
+            r#"<speak xml:lang="en-US"><voice name="Jo&quot;anna">"#,
+            r#"<s>Hello Tom &lt;Jerry&gt;, you owe <say-as interpret-as="currency">$5</say-as>.</s>"#,
+            r#"<audio src="https://example.com/a&amp;b.wav"/>"#,
+            r#"<s>Braces: {literal}</s></voice></speak>"#,
+        ))
+        .unwrap()
+    );
+
+    // Rendering leaves the template untouched
+    let values = HashMap::from([
+        ("lang", "de-DE"),
+        ("voice", "Vicki"),
+        ("first", "A"),
+        ("last", "B"),
+        ("amount", "1"),
+        ("clip", "c"),
+    ]);
+    let ssml = template.render(&values).unwrap();
+    assert!(
+        ssml.to_string()
+            .starts_with(r#"<speak xml:lang="de-DE"><voice name="Vicki">"#)
+    );
+}
+
+#[test]
+fn test_template_missing_value() {
+    let template = Template::parse("<speak>{greeting} {name}</speak>").unwrap();
+    let error = template
+        .render(&HashMap::from([("greeting", "Hi")]))
+        .unwrap_err();
+
+    assert_eq!(error.name(), "name");
+    assert_eq!(error.to_string(), "missing value for placeholder {name}");
+}
+
+#[test]
+fn test_template_errors() {
+    assert!(matches!(
+        Template::parse("<speak>Unclosed"),
+        Err(TemplateError::Parse(_))
+    ));
+    assert_eq!(
+        Template::parse("<speak>Hello {name</speak>").unwrap_err(),
+        TemplateError::InvalidPlaceholder("Hello {name".to_string())
+    );
+    assert_eq!(
+        Template::parse("<speak>Hello {first name}</speak>").unwrap_err(),
+        TemplateError::InvalidPlaceholder("Hello {first name}".to_string())
+    );
+    assert_eq!(
+        Template::parse("<speak>Stray } brace</speak>").unwrap_err(),
+        TemplateError::InvalidPlaceholder("Stray } brace".to_string())
+    );
+    assert_eq!(
+        Template::parse(r#"<speak><break time="{pause}"/></speak>"#).unwrap_err(),
+        TemplateError::UnsupportedPosition {
+            element: "break".to_string(),
+            attribute: "time".to_string(),
+        }
+    );
+    assert_eq!(
+        Template::parse(r#"<speak><say-as>x</say-as><break time="{t}"/></speak>"#).unwrap_err(),
+        TemplateError::UnsupportedPosition {
+            element: "break".to_string(),
+            attribute: "time".to_string(),
+        }
+    );
+
+    let error =
+        Template::parse(r#"<speak><voice name="{name}">{name}</voice></speak>"#).unwrap_err();
+    assert_eq!(
+        error,
+        TemplateError::ConflictingPlaceholder("name".to_string())
+    );
+    assert_eq!(
+        error.to_string(),
+        "placeholder {name} is used both in text and in an attribute"
+    );
+}
@@ -0,0 +1,168 @@
+use std::{ops::ControlFlow, time::Duration};
+
+use serde_ssml::{
+    SsmlElement, from_str, to_string,
+    visit::{self, Fold, Visit, VisitMut},
+};
+
+const DOCUMENT: &str = concat!(
+    r#"<speak><p><s>Hello <emphasis>big</emphasis> world</s></p><prosody rate="slow">"#,
+    r#"<s>Slow <break time="1s"/> words</s></prosody><mark name="end"/><s>After</s>"#,
+    r#"</speak>"#,
+);
+
+#[derive(Default)]
+struct Texts<'ast> {
+    texts: Vec<&'ast str>,
+    skip_prosody: bool,
+    stop_at_mark: bool,
+}
+
+impl<'ast> Visit<'ast> for Texts<'ast> {
+    fn visit_prosody(&mut self, element: &'ast SsmlElement) -> ControlFlow<()> {
+        if self.skip_prosody {
+            ControlFlow::Continue(())
+        } else {
+            visit::visit_prosody(self, element)
+        }
+    }
+
+    fn visit_mark(&mut self, _element: &'ast SsmlElement) -> ControlFlow<()> {
+        if self.stop_at_mark {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    }
+
+    fn visit_text(&mut self, text: &'ast str) -> ControlFlow<()> {
+        self.texts.push(text);
+        ControlFlow::Continue(())
+    }
+}
+
+#[test]
+fn test_visit_in_document_order() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let mut visitor = Texts::default();
+
+    assert_eq!(visitor.visit_ssml(&ssml), ControlFlow::Continue(()));
+    assert_eq!(
+        visitor.texts,
+        ["Hello", "big", "world", "Slow", "words", "After"]
+    );
+}
+
+#[test]
+fn test_visit_skip_subtree() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let mut visitor = Texts {
+        skip_prosody: true,
+        ..Texts::default()
+    };
+
+    let _ = visitor.visit_ssml(&ssml);
+    assert_eq!(visitor.texts, ["Hello", "big", "world", "After"]);
+}
+
+#[test]
+fn test_visit_stop_early() {
+    let ssml = from_str(DOCUMENT).unwrap();
+    let mut visitor = Texts {
+        stop_at_mark: true,
+        ..Texts::default()
+    };
+
+    assert_eq!(visitor.visit_ssml(&ssml), ControlFlow::Break(()));
+    assert_eq!(visitor.texts, ["Hello", "big", "world", "Slow", "words"]);
+}
+
+#[test]
+fn test_visit_mut() {
+    struct Shout;
+
+    impl VisitMut for Shout {
+        fn visit_emphasis_mut(&mut self, _element: &mut SsmlElement) -> ControlFlow<()> {
+            ControlFlow::Continue(())
+        }
+
+        fn visit_break_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+            if let SsmlElement::Break { time, .. } = element {
+                *time = Some(Duration::from_millis(250));
+            }
+            ControlFlow::Continue(())
+        }
+
+        fn visit_text_mut(&mut self, text: &mut String) -> ControlFlow<()> {
+            *text = text.to_uppercase();
+            ControlFlow::Continue(())
+        }
+    }
+
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let _ = Shout.visit_ssml_mut(&mut ssml);
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><p><s>HELLO<emphasis>big</emphasis>WORLD</s></p><prosody rate="slow"><s>SLOW<break time="250ms"/>WORDS</s></prosody><mark name="end"/><s>AFTER</s></speak>"#
+    );
+}
+
+#[test]
+fn test_visit_mut_replace_element() {
+    struct MarksToBreaks;
+
+    impl VisitMut for MarksToBreaks {
+        fn visit_element_mut(&mut self, element: &mut SsmlElement) -> ControlFlow<()> {
+            if let SsmlElement::Mark { .. } = element {
+                *element = SsmlElement::Break {
+                    time: None,
+                    strength: None,
+                };
+            }
+            visit::visit_element_mut(self, element)
+        }
+    }
+
+    let mut ssml = from_str(DOCUMENT).unwrap();
+    let _ = MarksToBreaks.visit_ssml_mut(&mut ssml);
+
+    assert!(to_string(&ssml).contains("</prosody><break/><s>After</s>"));
+}
+
+#[test]
+fn test_fold() {
+    struct Unwrap;
+
+    impl Fold for Unwrap {
+        fn fold_emphasis(&mut self, element: SsmlElement) -> SsmlElement {
+            match element {
+                SsmlElement::Emphasis { mut children, .. } if children.len() == 1 => {
+                    self.fold_element(children.remove(0))
+                }
+                element => visit::fold_emphasis(self, element),
+            }
+        }
+
+        fn fold_prosody(&mut self, element: SsmlElement) -> SsmlElement {
+            let SsmlElement::Prosody { children, .. } = visit::fold_prosody(self, element) else {
+                unreachable!();
+            };
+            SsmlElement::Lang {
+                xml_lang: "en-US".to_string(),
+                children,
+            }
+        }
+
+        fn fold_text(&mut self, text: String) -> String {
+            text.replace('o', "0")
+        }
+    }
+
+    let ssml = Unwrap.fold_ssml(from_str(DOCUMENT).unwrap());
+
+    assert_eq!(
+        to_string(&ssml),
+        r#"<speak><p><s>Hell0bigw0rld</s></p><lang xml:lang="en-US"><s>Sl0w<break time="1000ms"/>w0rds</s></lang><mark name="end"/><s>After</s></speak>"#
+    );
+}